@@ -0,0 +1,404 @@
+// src-tauri/src/commands/binding_resolver.rs
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use lazy_static::lazy_static;
+use tauri::{AppHandle, Emitter};
+use tracing::{debug, error, info};
+
+use crate::commands::events::BackendEvent;
+use crate::commands::live_data::{court_data, latest_data_for_connection};
+use crate::commands::window_updates::is_window_paused;
+use crate::commands::rest_polling::value_at_path;
+use crate::error::AppError;
+
+// How often subscribed windows are checked for changed values
+const RESOLVE_INTERVAL_MS: u64 = 250;
+const LOW_PRIORITY_INTERVAL_MS: u64 = 5000;
+
+// High and normal bindings resolve every tick; low ones every few seconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BindingPriority {
+    High,
+    #[default]
+    Normal,
+    Low,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingFormat {
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    // Shown when the path is missing, null or an empty string
+    pub fallback: Option<String>,
+}
+
+// Mirrors the frontend's LiveDataComponentBinding, plus where to look for the data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvableBinding {
+    pub component_id: String,
+    pub connection_id: String,
+    pub data_path: String,
+    // Read this court's entry instead of the connection's latest data
+    pub court_name: Option<String>,
+    // Scoreboard window showing the component
+    pub window_id: Option<String>,
+    pub format: Option<BindingFormat>,
+    #[serde(default)]
+    pub priority: BindingPriority,
+    // Milliseconds between refreshes; overrides the priority's interval
+    pub update_interval: Option<u64>,
+}
+
+impl ResolvableBinding {
+    // Never faster than the watcher ticks
+    pub fn refresh_interval(&self) -> Duration {
+        let interval_ms = self.update_interval.unwrap_or(match self.priority {
+            BindingPriority::High | BindingPriority::Normal => RESOLVE_INTERVAL_MS,
+            BindingPriority::Low => LOW_PRIORITY_INTERVAL_MS,
+        });
+        Duration::from_millis(interval_ms.max(RESOLVE_INTERVAL_MS))
+    }
+}
+
+// Whether a binding last refreshed at `last_refresh` should be resolved this tick.
+// Half a tick of slack keeps an interval that's a multiple of the tick from slipping
+// a whole tick when the ticker fires slightly early.
+pub fn binding_due(interval: Duration, last_refresh: Option<Instant>, now: Instant) -> bool {
+    let slack = Duration::from_millis(RESOLVE_INTERVAL_MS / 2);
+    last_refresh.is_none_or(|last| now.saturating_duration_since(last) + slack >= interval)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BindingFailureReason {
+    // The court or connection has no data at all
+    NoData,
+    // There is data, but nothing (or null, or "") at the binding's path
+    MissingPath,
+}
+
+// A binding that wouldn't show live data right now
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingValidationFailure {
+    pub component_id: String,
+    pub window_id: Option<String>,
+    pub court_name: Option<String>,
+    pub data_path: String,
+    pub reason: BindingFailureReason,
+    // The component still shows its fallback text rather than going blank
+    pub has_fallback: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BindingsResolvedEvent {
+    pub window_id: String,
+    pub values: HashMap<String, serde_json::Value>,
+}
+
+lazy_static! {
+    static ref COMPONENT_BINDINGS: Arc<Mutex<Vec<ResolvableBinding>>> = Arc::new(Mutex::new(Vec::new()));
+    // Subscribed window -> values last sent to it
+    static ref BINDING_SUBSCRIPTIONS: Arc<Mutex<HashMap<String, HashMap<String, serde_json::Value>>>> = Arc::new(Mutex::new(HashMap::new()));
+    static ref BINDING_WATCHER: Arc<Mutex<Option<JoinHandle<()>>>> = Arc::new(Mutex::new(None));
+}
+
+pub fn format_binding_value(value: Option<&serde_json::Value>, format: Option<&BindingFormat>) -> serde_json::Value {
+    let text = match value {
+        Some(serde_json::Value::String(s)) if !s.is_empty() => Some(s.clone()),
+        Some(serde_json::Value::String(_)) | Some(serde_json::Value::Null) | None => None,
+        Some(other) => Some(other.to_string()),
+    };
+
+    match (text, format) {
+        (Some(text), Some(format)) => serde_json::Value::String(format!(
+            "{}{}{}",
+            format.prefix.as_deref().unwrap_or(""),
+            text,
+            format.suffix.as_deref().unwrap_or("")
+        )),
+        (Some(text), None) => serde_json::Value::String(text),
+        (None, Some(BindingFormat { fallback: Some(fallback), .. })) => serde_json::Value::String(fallback.clone()),
+        (None, _) => serde_json::Value::Null,
+    }
+}
+
+type BindingSourceKey = (String, Option<String>);
+
+fn source_key(binding: &ResolvableBinding) -> BindingSourceKey {
+    (binding.connection_id.clone(), binding.court_name.clone())
+}
+
+// Several components usually read the same source; fetch each one once
+async fn binding_sources(bindings: &[ResolvableBinding]) -> HashMap<BindingSourceKey, Option<serde_json::Value>> {
+    let mut sources = HashMap::new();
+    for binding in bindings {
+        if let Entry::Vacant(slot) = sources.entry(source_key(binding)) {
+            let data = match &binding.court_name {
+                Some(court) => court_data(court).await,
+                None => latest_data_for_connection(&binding.connection_id).await,
+            };
+            slot.insert(data);
+        }
+    }
+    sources
+}
+
+async fn resolve_bindings(bindings: &[ResolvableBinding]) -> HashMap<String, serde_json::Value> {
+    let sources = binding_sources(bindings).await;
+    let mut values = HashMap::new();
+
+    for binding in bindings {
+        let value = sources.get(&source_key(binding))
+            .and_then(|data| data.as_ref())
+            .and_then(|data| value_at_path(data, &binding.data_path));
+        values.insert(binding.component_id.clone(), format_binding_value(value, binding.format.as_ref()));
+    }
+
+    values
+}
+
+// Why `binding` wouldn't resolve against `data`, or None when it does. Uses the
+// same path lookup and emptiness rules as format_binding_value.
+pub fn binding_failure(binding: &ResolvableBinding, data: Option<&serde_json::Value>) -> Option<BindingFailureReason> {
+    let Some(data) = data else {
+        return Some(BindingFailureReason::NoData);
+    };
+    let value = value_at_path(data, &binding.data_path);
+    match format_binding_value(value, None) {
+        serde_json::Value::Null => Some(BindingFailureReason::MissingPath),
+        _ => None,
+    }
+}
+
+fn bindings_for(bindings: &[ResolvableBinding], window_id: Option<&str>, component_ids: Option<&[String]>) -> Vec<ResolvableBinding> {
+    let component_ids: Option<HashSet<&str>> = component_ids.map(|ids| ids.iter().map(|id| id.as_str()).collect());
+
+    bindings.iter()
+        .filter(|binding| window_id.map(|w| binding.window_id.as_deref() == Some(w)).unwrap_or(true))
+        .filter(|binding| component_ids.as_ref().map(|ids| ids.contains(binding.component_id.as_str())).unwrap_or(true))
+        .cloned()
+        .collect()
+}
+
+fn spawn_watcher(app: AppHandle) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_millis(RESOLVE_INTERVAL_MS));
+        // (window, component) -> when its value was last resolved
+        let mut last_refresh: HashMap<(String, String), Instant> = HashMap::new();
+        loop {
+            ticker.tick().await;
+
+            let bindings = COMPONENT_BINDINGS.lock().await.clone();
+            let window_ids: Vec<String> = BINDING_SUBSCRIPTIONS.lock().await.keys().cloned().collect();
+            if window_ids.is_empty() {
+                continue;
+            }
+
+            let now = Instant::now();
+            last_refresh.retain(|(window_id, _), _| window_ids.contains(window_id));
+            for window_id in window_ids {
+                // Held windows are caught up by `resend_window_bindings` when resumed
+                if is_window_paused(&window_id) {
+                    continue;
+                }
+                let sent = BINDING_SUBSCRIPTIONS.lock().await.get(&window_id).cloned().unwrap_or_default();
+                // Components the window hasn't been sent yet are due regardless of interval
+                let due: Vec<ResolvableBinding> = bindings_for(&bindings, Some(&window_id), None)
+                    .into_iter()
+                    .filter(|binding| !sent.contains_key(&binding.component_id) || binding_due(
+                        binding.refresh_interval(),
+                        last_refresh.get(&(window_id.clone(), binding.component_id.clone())).copied(),
+                        now,
+                    ))
+                    .collect();
+                if due.is_empty() {
+                    continue;
+                }
+                for binding in &due {
+                    last_refresh.insert((window_id.clone(), binding.component_id.clone()), now);
+                }
+                let resolved = resolve_bindings(&due).await;
+
+                let mut subscriptions = BINDING_SUBSCRIPTIONS.lock().await;
+                let Some(last_sent) = subscriptions.get_mut(&window_id) else {
+                    continue;
+                };
+                // Components not due this tick keep the value last sent
+                let mut values = last_sent.clone();
+                values.extend(resolved);
+                if *last_sent == values {
+                    continue;
+                }
+                *last_sent = values.clone();
+                drop(subscriptions);
+
+                let event = BindingsResolvedEvent { window_id: window_id.clone(), values };
+                if let Err(e) = app.emit_to(window_id.as_str(), BackendEvent::BindingsResolved.as_str(), &event) {
+                    error!("Failed to emit {} to {}: {}", BackendEvent::BindingsResolved, window_id, e);
+                }
+            }
+        }
+    })
+}
+
+// Forget what a window was last sent, so the next tick sends it every binding
+pub(crate) async fn resend_window_bindings(window_id: &str) {
+    if let Some(sent) = BINDING_SUBSCRIPTIONS.lock().await.get_mut(window_id) {
+        sent.clear();
+    }
+}
+
+#[tauri::command]
+pub async fn set_component_bindings(bindings: Vec<ResolvableBinding>) -> Result<(), AppError> {
+    info!("🔗 Registered {} component binding(s)", bindings.len());
+    *COMPONENT_BINDINGS.lock().await = bindings;
+    Ok(())
+}
+
+// Change how often one component's bound value refreshes, without re-registering every binding
+#[tauri::command]
+pub async fn set_component_binding_priority(
+    component_id: String,
+    priority: BindingPriority,
+    update_interval: Option<u64>,
+) -> Result<(), AppError> {
+    let mut bindings = COMPONENT_BINDINGS.lock().await;
+    let mut found = false;
+    for binding in bindings.iter_mut().filter(|b| b.component_id == component_id) {
+        binding.priority = priority;
+        binding.update_interval = update_interval;
+        found = true;
+    }
+    if !found {
+        return Err(AppError::not_found(format!("Binding for component {}", component_id)));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn resolve_component_bindings(
+    window_id: Option<String>,
+    component_ids: Option<Vec<String>>,
+) -> Result<HashMap<String, serde_json::Value>, AppError> {
+    let bindings = COMPONENT_BINDINGS.lock().await.clone();
+    let selected = bindings_for(&bindings, window_id.as_deref(), component_ids.as_deref());
+    Ok(resolve_bindings(&selected).await)
+}
+
+// Pre-show check: every binding on `connection_id` that doesn't resolve against
+// the data held right now. An empty list means every bound component has a value.
+#[tauri::command]
+pub async fn validate_all_bindings(connection_id: String) -> Result<Vec<BindingValidationFailure>, AppError> {
+    let bindings: Vec<ResolvableBinding> = COMPONENT_BINDINGS.lock().await.iter()
+        .filter(|binding| binding.connection_id == connection_id)
+        .cloned()
+        .collect();
+    let sources = binding_sources(&bindings).await;
+
+    let failures: Vec<BindingValidationFailure> = bindings.iter()
+        .filter_map(|binding| {
+            let data = sources.get(&source_key(binding)).and_then(|data| data.as_ref());
+            binding_failure(binding, data).map(|reason| BindingValidationFailure {
+                component_id: binding.component_id.clone(),
+                window_id: binding.window_id.clone(),
+                court_name: binding.court_name.clone(),
+                data_path: binding.data_path.clone(),
+                reason,
+                has_fallback: binding.format.as_ref().is_some_and(|format| format.fallback.is_some()),
+            })
+        })
+        .collect();
+
+    info!("🔗 Validated {} binding(s) on {}: {} failed", bindings.len(), connection_id, failures.len());
+    Ok(failures)
+}
+
+#[tauri::command]
+pub async fn subscribe_binding_updates(app: AppHandle, window_id: String) -> Result<(), AppError> {
+    // Empty map so the first resolution is always sent
+    BINDING_SUBSCRIPTIONS.lock().await.insert(window_id.clone(), HashMap::new());
+
+    let mut watcher = BINDING_WATCHER.lock().await;
+    if watcher.as_ref().map(|handle| handle.is_finished()).unwrap_or(true) {
+        *watcher = Some(spawn_watcher(app));
+    }
+
+    debug!("🔗 Window {} subscribed to binding updates", window_id);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn unsubscribe_binding_updates(window_id: String) -> Result<(), AppError> {
+    let mut subscriptions = BINDING_SUBSCRIPTIONS.lock().await;
+    subscriptions.remove(&window_id);
+
+    if subscriptions.is_empty() {
+        if let Some(handle) = BINDING_WATCHER.lock().await.take() {
+            handle.abort();
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binding(priority: BindingPriority, update_interval: Option<u64>) -> ResolvableBinding {
+        ResolvableBinding {
+            component_id: "score".to_string(),
+            connection_id: "conn".to_string(),
+            data_path: "score.home".to_string(),
+            court_name: None,
+            window_id: None,
+            format: None,
+            priority,
+            update_interval,
+        }
+    }
+
+    #[test]
+    fn refresh_interval_follows_priority_and_override() {
+        assert_eq!(binding(BindingPriority::High, None).refresh_interval(), Duration::from_millis(RESOLVE_INTERVAL_MS));
+        assert_eq!(binding(BindingPriority::Low, None).refresh_interval(), Duration::from_millis(LOW_PRIORITY_INTERVAL_MS));
+        assert_eq!(binding(BindingPriority::Low, Some(1000)).refresh_interval(), Duration::from_millis(1000));
+        // Never faster than the watcher ticks
+        assert_eq!(binding(BindingPriority::High, Some(10)).refresh_interval(), Duration::from_millis(RESOLVE_INTERVAL_MS));
+    }
+
+    #[test]
+    fn low_priority_bindings_skip_ticks_until_due() {
+        let start = Instant::now();
+        let tick = Duration::from_millis(RESOLVE_INTERVAL_MS);
+        let high = binding(BindingPriority::High, None).refresh_interval();
+        let low = binding(BindingPriority::Low, None).refresh_interval();
+
+        assert!(binding_due(low, None, start));
+        assert!(binding_due(high, Some(start), start + tick));
+        assert!(!binding_due(low, Some(start), start + tick));
+        assert!(!binding_due(low, Some(start), start + low - tick));
+        // A tick that fires slightly early still counts
+        assert!(binding_due(low, Some(start), start + low - Duration::from_millis(10)));
+    }
+
+
+    #[test]
+    fn validation_flags_only_the_binding_that_does_not_resolve() {
+        let data = serde_json::json!({ "score": { "home": 3, "away": null } });
+        let resolving = binding(BindingPriority::Normal, None);
+        let failing = ResolvableBinding { data_path: "score.away".to_string(), ..resolving.clone() };
+
+        assert_eq!(binding_failure(&resolving, Some(&data)), None);
+        assert_eq!(binding_failure(&failing, Some(&data)), Some(BindingFailureReason::MissingPath));
+        assert_eq!(binding_failure(&resolving, None), Some(BindingFailureReason::NoData));
+    }
+}
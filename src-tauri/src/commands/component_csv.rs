@@ -0,0 +1,276 @@
+// src-tauri/src/commands/component_csv.rs
+//
+// Component positions and text as CSV, so operators can bulk-edit a board in
+// a spreadsheet. Only position, size and the primary text/value are applied
+// back on import; `type` is exported for reference and ignored.
+use serde::{Deserialize, Serialize};
+use std::fs;
+use tauri::AppHandle;
+use tracing::{info, warn};
+
+use crate::commands::storage::read_scoreboard_config;
+use crate::error::AppError;
+
+const CSV_HEADER: [&str; 7] = ["id", "type", "x", "y", "width", "height", "text"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentCsvImport {
+    pub updated: Vec<String>,
+    // IDs in the CSV that aren't on the board; those rows are skipped
+    pub unknown_ids: Vec<String>,
+}
+
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// RFC 4180 style: quoted fields may contain commas, newlines and doubled quotes
+pub fn parse_csv(content: &str) -> Result<Vec<Vec<String>>, AppError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(AppError::validation("csv", "Unterminated quoted field"));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    rows.retain(|row| row.iter().any(|field| !field.trim().is_empty()));
+    Ok(rows)
+}
+
+fn number_field(value: Option<&serde_json::Value>) -> String {
+    value.and_then(|v| v.as_f64()).map(|n| n.to_string()).unwrap_or_default()
+}
+
+fn primary_text(data: Option<&serde_json::Value>) -> String {
+    match data.and_then(|d| d.get("text").or_else(|| d.get("value"))) {
+        Some(serde_json::Value::String(text)) => text.clone(),
+        Some(serde_json::Value::Number(n)) => n.to_string(),
+        _ => String::new(),
+    }
+}
+
+pub fn components_to_csv(data: &serde_json::Value) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push('\n');
+
+    let components = data.get("components").and_then(|c| c.as_array());
+    for component in components.into_iter().flatten() {
+        let position = component.get("position");
+        let size = component.get("size");
+        let row = [
+            component.get("id").and_then(|id| id.as_str()).unwrap_or("").to_string(),
+            component.get("type").and_then(|t| t.as_str()).unwrap_or("").to_string(),
+            number_field(position.and_then(|p| p.get("x"))),
+            number_field(position.and_then(|p| p.get("y"))),
+            number_field(size.and_then(|s| s.get("width"))),
+            number_field(size.and_then(|s| s.get("height"))),
+            primary_text(component.get("data")),
+        ];
+        csv.push_str(&row.iter().map(|field| escape_field(field)).collect::<Vec<_>>().join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn cell(row: &[String], index: Option<usize>) -> Option<&str> {
+    index.and_then(|i| row.get(i)).map(|s| s.as_str())
+}
+
+fn parse_number(column: &str, value: &str) -> Result<Option<f64>, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value.parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite())
+        .map(Some)
+        .ok_or_else(|| AppError::validation(column, format!("Invalid {} value: {}", column, value)))
+}
+
+// Whole numbers stay integers so unchanged boards don't pick up `.0` everywhere
+fn json_number(value: f64) -> serde_json::Value {
+    if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        serde_json::json!(value as i64)
+    } else {
+        serde_json::json!(value)
+    }
+}
+
+fn set_number(target: &mut serde_json::Value, object: &str, key: &str, value: Option<f64>) {
+    let (Some(value), Some(component)) = (value, target.as_object_mut()) else {
+        return;
+    };
+    let object = component.entry(object).or_insert_with(|| serde_json::json!({}));
+    if let Some(object) = object.as_object_mut() {
+        object.insert(key.to_string(), json_number(value));
+    }
+}
+
+fn set_primary_text(component: &mut serde_json::Value, text: &str) {
+    let Some(component) = component.as_object_mut() else {
+        return;
+    };
+    let data = component.entry("data").or_insert_with(|| serde_json::json!({}));
+    let Some(data) = data.as_object_mut() else {
+        return;
+    };
+    // Write back to whichever field the export read from, keeping numeric values numeric
+    match data.get("value") {
+        Some(serde_json::Value::Number(_)) if !data.contains_key("text") => {
+            let value = text.trim().parse::<f64>().map(json_number)
+                .unwrap_or_else(|_| serde_json::json!(text));
+            data.insert("value".to_string(), value);
+        }
+        Some(_) if !data.contains_key("text") => {
+            data.insert("value".to_string(), serde_json::json!(text));
+        }
+        _ => {
+            data.insert("text".to_string(), serde_json::json!(text));
+        }
+    }
+}
+
+// Empty cells leave the existing value alone, so text can't be cleared from a
+// CSV. The whole CSV is validated before anything is applied.
+pub fn apply_components_csv(data: &mut serde_json::Value, csv: &str) -> Result<ComponentCsvImport, AppError> {
+    let rows = parse_csv(csv)?;
+    let (header, rows) = rows.split_first().ok_or_else(|| AppError::validation("csv", "CSV is empty"))?;
+    let column = |name: &str| header.iter().position(|h| h.trim().eq_ignore_ascii_case(name));
+    let id_column = column("id").ok_or_else(|| AppError::validation("csv", "CSV has no id column"))?;
+    let [x_column, y_column, width_column, height_column, text_column] =
+        ["x", "y", "width", "height", "text"].map(column);
+
+    let mut edits = Vec::new();
+    for (line, row) in rows.iter().enumerate() {
+        let number = |name: &str, index: Option<usize>| parse_number(name, cell(row, index).unwrap_or(""))
+            .map_err(|e| AppError::validation("csv", format!("Row {}: {}", line + 2, e)));
+        let (x, y) = (number("x", x_column)?, number("y", y_column)?);
+        let (width, height) = (number("width", width_column)?, number("height", height_column)?);
+        if width.is_some_and(|w| w <= 0.0) || height.is_some_and(|h| h <= 0.0) {
+            return Err(AppError::validation("csv", format!("Row {}: width and height must be positive", line + 2)));
+        }
+        let id = cell(row, Some(id_column)).unwrap_or("").trim().to_string();
+        edits.push((id, x, y, width, height, cell(row, text_column).map(|t| t.to_string())));
+    }
+
+    let components = data.get_mut("components")
+        .and_then(|c| c.as_array_mut())
+        .ok_or_else(|| AppError::validation("components", "Scoreboard has no components"))?;
+
+    let mut result = ComponentCsvImport::default();
+    for (id, x, y, width, height, text) in edits {
+        let Some(component) = components.iter_mut()
+            .find(|c| c.get("id").and_then(|v| v.as_str()) == Some(id.as_str()))
+        else {
+            warn!("⚠️ Ignoring CSV row for unknown component: {}", id);
+            result.unknown_ids.push(id);
+            continue;
+        };
+
+        set_number(component, "position", "x", x);
+        set_number(component, "position", "y", y);
+        set_number(component, "size", "width", width);
+        set_number(component, "size", "height", height);
+        if let Some(text) = text.filter(|t| !t.is_empty()) {
+            set_primary_text(component, &text);
+        }
+        result.updated.push(id);
+    }
+
+    Ok(result)
+}
+
+#[tauri::command]
+pub async fn export_components_csv(app: AppHandle, filename: String) -> Result<String, AppError> {
+    let (_, config) = read_scoreboard_config(&app, &filename)?;
+    Ok(components_to_csv(&config.data))
+}
+
+#[tauri::command]
+pub async fn import_components_csv(
+    app: AppHandle,
+    filename: String,
+    csv: String,
+) -> Result<ComponentCsvImport, AppError> {
+    let (scoreboard_path, mut config) = read_scoreboard_config(&app, &filename)?;
+    let result = apply_components_csv(&mut config.data, &csv)?;
+    config.updated_at = chrono::Utc::now().to_rfc3339();
+
+    let json_data = serde_json::to_string_pretty(&config)?;
+    fs::write(&scoreboard_path, json_data)?;
+
+    info!("📄 Applied CSV to {} component(s) in {}", result.updated.len(), filename);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edited_csv_round_trips_onto_the_board() {
+        let mut board = serde_json::json!({
+            "components": [
+                { "id": "home", "type": "text", "position": { "x": 10, "y": 20 }, "size": { "width": 100, "height": 40 }, "data": { "text": "Home, \"A\"" } },
+                { "id": "score", "type": "score", "position": { "x": 0, "y": 0 }, "size": { "width": 50, "height": 50 }, "data": { "value": 3 } }
+            ]
+        });
+        let original = board.clone();
+
+        let csv = components_to_csv(&board);
+        let result = apply_components_csv(&mut board, &csv).unwrap();
+        assert_eq!(result.updated, vec!["home", "score"]);
+        assert_eq!(board, original);
+
+        let edited = csv.replace("score,score,0,0,50,50,3", "score,score,15,5,60,50,7")
+            + "ghost,text,1,1,1,1,x\n";
+        let result = apply_components_csv(&mut board, &edited).unwrap();
+        assert_eq!(result.unknown_ids, vec!["ghost"]);
+        assert_eq!(board["components"][1]["position"], serde_json::json!({ "x": 15, "y": 5 }));
+        assert_eq!(board["components"][1]["size"]["width"], 60);
+        assert_eq!(board["components"][1]["data"]["value"], 7);
+        assert_eq!(board["components"][0], original["components"][0]);
+    }
+
+    #[test]
+    fn invalid_rows_leave_the_board_untouched() {
+        let mut board = serde_json::json!({
+            "components": [{ "id": "home", "position": { "x": 1, "y": 1 }, "size": { "width": 10, "height": 10 } }]
+        });
+        let original = board.clone();
+
+        assert!(apply_components_csv(&mut board, "id,x,y,width,height\nhome,5,5,10,10\nhome,x,5,10,10\n").is_err());
+        assert!(apply_components_csv(&mut board, "id,x,y,width,height\nhome,5,5,0,10\n").is_err());
+        assert_eq!(board, original);
+    }
+}
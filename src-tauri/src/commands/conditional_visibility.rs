@@ -0,0 +1,253 @@
+// src-tauri/src/commands/conditional_visibility.rs
+//
+// Components that only show in certain game situations, e.g. an overtime
+// banner once `game.period` passes regulation. Each rule pairs a component id
+// with a `show_when` condition over the current GameState (`game.` fields)
+// and, optionally, one court's live data (`court.` fields). Rules are
+// re-evaluated after every game-state change, and every second for rules that
+// read court data; displays get `component_visibility_changed` whenever an
+// effective value flips. A rule's manual `visible`, when set, wins over its
+// condition, so operators can still force a component on or off.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tokio::sync::Mutex;
+use tracing::error;
+
+use crate::commands::events::BackendEvent;
+use crate::commands::live_data::court_data;
+use crate::commands::scoreboard::ScoreboardState;
+use crate::commands::window_updates::emit_to_unpaused;
+use crate::error::AppError;
+
+const COURT_RULE_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+// Fields are dotted paths into `{ game, court }`, e.g. "game.period" or
+// "court.score.sets.0.player1". A missing field makes a comparison false.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum VisibilityCondition {
+    Compare { field: String, cmp: Comparison, value: serde_json::Value },
+    Exists { field: String },
+    All { conditions: Vec<VisibilityCondition> },
+    Any { conditions: Vec<VisibilityCondition> },
+    Not { condition: Box<VisibilityCondition> },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentVisibilityRule {
+    pub component_id: String,
+    pub show_when: VisibilityCondition,
+    // Court whose live data `court.` fields read from
+    #[serde(default)]
+    pub court: Option<String>,
+    // Manual override; None leaves it to `show_when`
+    #[serde(default)]
+    pub visible: Option<bool>,
+}
+
+// Payload of `component_visibility_changed`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentVisibilityEvent {
+    // Effective visibility of every ruled component
+    pub visibility: BTreeMap<String, bool>,
+    // Components that flipped, or lost their rule, since the last event
+    pub changed: Vec<String>,
+}
+
+lazy_static! {
+    static ref VISIBILITY_RULES: Arc<Mutex<Vec<ComponentVisibilityRule>>> = Arc::new(Mutex::new(Vec::new()));
+    static ref EFFECTIVE_VISIBILITY: Arc<Mutex<BTreeMap<String, bool>>> = Arc::new(Mutex::new(BTreeMap::new()));
+}
+
+fn lookup<'a>(context: &'a serde_json::Value, field: &str) -> Option<&'a serde_json::Value> {
+    let pointer = format!("/{}", field.trim().replace('.', "/"));
+    context.pointer(&pointer).filter(|value| !value.is_null())
+}
+
+fn compare(actual: &serde_json::Value, cmp: Comparison, expected: &serde_json::Value) -> bool {
+    // Numbers compare by value, so a period of 4 equals 4.0
+    if let (Some(a), Some(b)) = (actual.as_f64(), expected.as_f64()) {
+        return match cmp {
+            Comparison::Eq => a == b,
+            Comparison::Ne => a != b,
+            Comparison::Gt => a > b,
+            Comparison::Gte => a >= b,
+            Comparison::Lt => a < b,
+            Comparison::Lte => a <= b,
+        };
+    }
+    match cmp {
+        Comparison::Eq => actual == expected,
+        Comparison::Ne => actual != expected,
+        // Ordering only means something for numbers
+        _ => false,
+    }
+}
+
+pub fn evaluate_condition(condition: &VisibilityCondition, context: &serde_json::Value) -> bool {
+    match condition {
+        VisibilityCondition::Compare { field, cmp, value } => {
+            lookup(context, field).is_some_and(|actual| compare(actual, *cmp, value))
+        }
+        VisibilityCondition::Exists { field } => lookup(context, field).is_some(),
+        VisibilityCondition::All { conditions } => conditions.iter().all(|c| evaluate_condition(c, context)),
+        VisibilityCondition::Any { conditions } => conditions.iter().any(|c| evaluate_condition(c, context)),
+        VisibilityCondition::Not { condition } => !evaluate_condition(condition, context),
+    }
+}
+
+impl ComponentVisibilityRule {
+    // The manual override when set, otherwise the condition against `context`
+    pub fn effective_visibility(&self, context: &serde_json::Value) -> bool {
+        self.visible.unwrap_or_else(|| evaluate_condition(&self.show_when, context))
+    }
+}
+
+async fn refresh_visibility(app: &AppHandle) -> BTreeMap<String, bool> {
+    let game_state = app.state::<ScoreboardState>().game_state.lock().ok().and_then(|game| game.clone());
+    let game = serde_json::to_value(&game_state).unwrap_or(serde_json::Value::Null);
+    let rules = VISIBILITY_RULES.lock().await.clone();
+
+    let mut visibility = BTreeMap::new();
+    for rule in &rules {
+        let court = match &rule.court {
+            Some(court) => court_data(court).await.unwrap_or(serde_json::Value::Null),
+            None => serde_json::Value::Null,
+        };
+        let context = serde_json::json!({ "game": game, "court": court });
+        visibility.insert(rule.component_id.clone(), rule.effective_visibility(&context));
+    }
+
+    let mut last = EFFECTIVE_VISIBILITY.lock().await;
+    let changed: Vec<String> = visibility.iter()
+        .filter(|(id, visible)| last.get(*id) != Some(*visible))
+        .map(|(id, _)| id.clone())
+        .chain(last.keys().filter(|id| !visibility.contains_key(*id)).cloned())
+        .collect();
+    *last = visibility.clone();
+    drop(last);
+
+    if !changed.is_empty() {
+        let event = ComponentVisibilityEvent { visibility: visibility.clone(), changed };
+        if let Err(e) = emit_to_unpaused(app, BackendEvent::ComponentVisibilityChanged, &event) {
+            error!("Failed to emit {}: {}", BackendEvent::ComponentVisibilityChanged, e);
+        }
+    }
+    visibility
+}
+
+// For callers holding the game-state lock: the refresh reads the latest state
+// itself, so refreshes that run out of order still settle on the right answer
+pub fn schedule_visibility_refresh(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        refresh_visibility(&app).await;
+    });
+}
+
+// Court data arrives without a game-state change, so rules reading it are polled
+pub fn start_visibility_resolver(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(COURT_RULE_INTERVAL);
+        loop {
+            interval.tick().await;
+            let has_court_rules = VISIBILITY_RULES.lock().await.iter().any(|rule| rule.court.is_some());
+            if has_court_rules {
+                refresh_visibility(&app).await;
+            }
+        }
+    });
+}
+
+// Replaces every rule; returns the effective visibility straight away
+#[tauri::command]
+pub async fn set_component_visibility_rules(
+    app: AppHandle,
+    rules: Vec<ComponentVisibilityRule>,
+) -> Result<BTreeMap<String, bool>, AppError> {
+    let mut seen = std::collections::HashSet::new();
+    for rule in &rules {
+        if rule.component_id.trim().is_empty() {
+            return Err(AppError::validation("component_id", "Every rule needs a component id"));
+        }
+        if !seen.insert(rule.component_id.as_str()) {
+            return Err(AppError::validation("component_id", format!("Component '{}' has more than one rule", rule.component_id)));
+        }
+    }
+    *VISIBILITY_RULES.lock().await = rules;
+    Ok(refresh_visibility(&app).await)
+}
+
+// Force a ruled component on or off, or pass None to hand it back to its rule
+#[tauri::command]
+pub async fn set_component_visibility_override(
+    app: AppHandle,
+    component_id: String,
+    visible: Option<bool>,
+) -> Result<BTreeMap<String, bool>, AppError> {
+    {
+        let mut rules = VISIBILITY_RULES.lock().await;
+        let rule = rules.iter_mut().find(|rule| rule.component_id == component_id)
+            .ok_or_else(|| AppError::not_found(format!("Visibility rule for '{}'", component_id)))?;
+        rule.visible = visible;
+    }
+    Ok(refresh_visibility(&app).await)
+}
+
+#[tauri::command]
+pub async fn get_component_visibility() -> Result<BTreeMap<String, bool>, AppError> {
+    Ok(EFFECTIVE_VISIBILITY.lock().await.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overtime_rule() -> ComponentVisibilityRule {
+        serde_json::from_value(serde_json::json!({
+            "componentId": "overtime-banner",
+            "showWhen": { "op": "compare", "field": "game.period", "cmp": "gt", "value": 4 }
+        })).unwrap()
+    }
+
+    fn context(period: u32) -> serde_json::Value {
+        serde_json::json!({ "game": { "period": period }, "court": null })
+    }
+
+    #[test]
+    fn period_rule_shows_banner_only_in_overtime() {
+        let rule = overtime_rule();
+        assert!(!rule.effective_visibility(&context(1)));
+        assert!(!rule.effective_visibility(&context(4)));
+        assert!(rule.effective_visibility(&context(5)));
+        assert!(!rule.effective_visibility(&serde_json::json!({ "game": null, "court": null })));
+    }
+
+    #[test]
+    fn manual_visible_overrides_the_rule() {
+        let rule = ComponentVisibilityRule { visible: Some(true), ..overtime_rule() };
+        assert!(rule.effective_visibility(&context(1)));
+
+        let rule = ComponentVisibilityRule { visible: Some(false), ..overtime_rule() };
+        assert!(!rule.effective_visibility(&context(5)));
+    }
+}
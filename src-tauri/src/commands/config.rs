@@ -0,0 +1,261 @@
+// src-tauri/src/commands/config.rs
+//
+// Defaults that used to be constants spread across modules, loaded from
+// app_data/config.json at startup. The file is created with the defaults (and
+// a description of each key) on first run. Modules read the current values
+// through `app_config()`, since several of them run without an AppHandle.
+use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::Duration;
+use lazy_static::lazy_static;
+use tauri::AppHandle;
+use tracing::{info, warn};
+
+use crate::commands::court_data_sync::set_court_data_flush_interval;
+use crate::commands::data_dir::{data_dir, is_portable};
+use crate::commands::live_data::{deep_merge, CourtNameRules};
+use crate::commands::storage::ImportConflictStrategy;
+use crate::error::AppError;
+
+const CONFIG_FILE: &str = "config.json";
+
+// Served by the vMix data server; {host} is this machine's LAN address
+const DEFAULT_LIVE_VIEW_URL_TEMPLATE: &str = "http://{host}:{port}/vmix/court/{court}.json";
+
+// Keys whose new value only takes effect after a restart
+const RESTART_REQUIRED: &[&str] = &["courtAutosaveIntervalSecs"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AppConfig {
+    pub court_stale_timeout_secs: u64,
+    pub court_autosave_interval_secs: u64,
+    pub court_sync_flush_interval_ms: u64,
+    pub max_image_bytes: u64,
+    pub max_video_bytes: u64,
+    pub live_view_url_template: String,
+    pub zip_import_max_entries: u64,
+    pub zip_import_max_total_bytes: u64,
+    pub zip_import_max_entry_bytes: u64,
+    // Folder scanned for scoreboard ZIPs to import; empty when not set
+    pub import_watch_folder: String,
+    pub import_watch_enabled: bool,
+    pub import_watch_conflict: ImportConflictStrategy,
+    // Free space below which low_disk_space is raised; 0 turns the warning off
+    pub low_disk_space_threshold_bytes: u64,
+    // WCAG contrast ratio text must reach against its background
+    pub min_text_contrast_ratio: f64,
+    pub court_name_rules: CourtNameRules,
+    // Set at launch (portable.flag or --portable), never read from the file
+    #[serde(skip_deserializing)]
+    pub portable: bool,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            court_stale_timeout_secs: 300,
+            court_autosave_interval_secs: 30,
+            court_sync_flush_interval_ms: 5000,
+            max_image_bytes: 25 * 1024 * 1024,
+            max_video_bytes: 500 * 1024 * 1024,
+            live_view_url_template: DEFAULT_LIVE_VIEW_URL_TEMPLATE.to_string(),
+            zip_import_max_entries: 1000,
+            zip_import_max_total_bytes: 512 * 1024 * 1024,
+            zip_import_max_entry_bytes: 50 * 1024 * 1024,
+            import_watch_folder: String::new(),
+            import_watch_enabled: false,
+            import_watch_conflict: ImportConflictStrategy::Rename,
+            low_disk_space_threshold_bytes: 2 * 1024 * 1024 * 1024,
+            min_text_contrast_ratio: 4.5,
+            court_name_rules: CourtNameRules::default(),
+            portable: is_portable(),
+        }
+    }
+}
+
+impl AppConfig {
+    pub fn court_stale_timeout(&self) -> Duration {
+        Duration::from_secs(self.court_stale_timeout_secs)
+    }
+
+    pub fn validate(&self) -> Result<(), AppError> {
+        if !self.live_view_url_template.contains("{court}") {
+            return Err(AppError::validation("liveViewUrlTemplate", "Live view URL template must contain {court}"));
+        }
+
+        if self.import_watch_enabled && self.import_watch_folder.trim().is_empty() {
+            return Err(AppError::validation("importWatchFolder", "Set a watch folder before enabling automatic import"));
+        }
+
+        if !(1.0..=21.0).contains(&self.min_text_contrast_ratio) {
+            return Err(AppError::validation("minTextContrastRatio", "Minimum text contrast ratio must be between 1 and 21"));
+        }
+
+        let positive = [
+            ("courtStaleTimeoutSecs", self.court_stale_timeout_secs),
+            ("courtAutosaveIntervalSecs", self.court_autosave_interval_secs),
+            ("courtSyncFlushIntervalMs", self.court_sync_flush_interval_ms),
+            ("maxImageBytes", self.max_image_bytes),
+            ("maxVideoBytes", self.max_video_bytes),
+            ("zipImportMaxEntries", self.zip_import_max_entries),
+            ("zipImportMaxTotalBytes", self.zip_import_max_total_bytes),
+            ("zipImportMaxEntryBytes", self.zip_import_max_entry_bytes),
+        ];
+        for (field, value) in positive {
+            if value == 0 {
+                return Err(AppError::validation(field, format!("{} must be greater than zero", field)));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn key_descriptions() -> serde_json::Value {
+    serde_json::json!({
+        "courtStaleTimeoutSecs": "Seconds without data before a court counts as stale",
+        "courtAutosaveIntervalSecs": "Seconds between saves of the court cache (restart required)",
+        "courtSyncFlushIntervalMs": "Minimum milliseconds between court sync writes",
+        "maxImageBytes": "Largest image upload accepted, in bytes",
+        "maxVideoBytes": "Largest video upload accepted, in bytes",
+        "liveViewUrlTemplate": "URL encoded in court QR codes; {court}, {host} and {port} are filled in",
+        "zipImportMaxEntries": "Most files a scoreboard ZIP may contain",
+        "zipImportMaxTotalBytes": "Largest total uncompressed size of a scoreboard ZIP, in bytes",
+        "zipImportMaxEntryBytes": "Largest uncompressed size of a single file in a scoreboard ZIP, in bytes",
+        "importWatchFolder": "Folder checked for new scoreboard ZIPs to import automatically",
+        "importWatchEnabled": "Whether ZIPs dropped into the watch folder are imported",
+        "importWatchConflict": "When an imported board's name is taken: rename, replace or skip",
+        "minTextContrastRatio": "WCAG contrast ratio text needs against its background (4.5 is AA for normal text)",
+        "courtNameRules": "Which differences in court names from feeds and boards are ignored: ignoreCase, collapseWhitespace, splitNumbers (Court1 = Court 01 = Court 1) and expandAbbreviations (Ct 1 = Court 1)",
+        "lowDiskSpaceThresholdBytes": "Free disk space below which a low-space warning is shown, in bytes (0 disables it)",
+        "portable": "Read-only: whether data is stored next to the executable (portable.flag or --portable)",
+    })
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigUpdate {
+    pub config: AppConfig,
+    // Changed keys that are already in effect
+    pub applied: Vec<String>,
+    pub requires_restart: Vec<String>,
+}
+
+lazy_static! {
+    static ref APP_CONFIG: RwLock<AppConfig> = RwLock::new(AppConfig::default());
+}
+
+pub fn app_config() -> AppConfig {
+    APP_CONFIG.read().map(|config| config.clone()).unwrap_or_default()
+}
+
+fn config_path(app: &AppHandle) -> Result<PathBuf, AppError> {
+    Ok(data_dir(app)?.join(CONFIG_FILE))
+}
+
+fn save_config(app: &AppHandle, config: &AppConfig) -> Result<(), AppError> {
+    let path = config_path(app)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    // JSON has no comments, so the descriptions go in a key the loader ignores
+    let mut json = serde_json::to_value(config)?;
+    if let Some(object) = json.as_object_mut() {
+        object.insert("_descriptions".to_string(), key_descriptions());
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(&json)?)?;
+    Ok(())
+}
+
+// Must run before anything that reads the config at startup
+pub fn load_app_config(app: &AppHandle) {
+    let path = match config_path(app) {
+        Ok(path) => path,
+        Err(e) => {
+            warn!("Using default configuration: {}", e);
+            return;
+        }
+    };
+
+    let config = match std::fs::read_to_string(&path) {
+        Ok(json) => match serde_json::from_str::<AppConfig>(&json) {
+            Ok(config) => match config.validate() {
+                Ok(()) => config,
+                Err(e) => {
+                    warn!("Ignoring invalid {}: {}", CONFIG_FILE, e);
+                    AppConfig::default()
+                }
+            },
+            Err(e) => {
+                warn!("Ignoring unreadable {}: {}", CONFIG_FILE, e);
+                AppConfig::default()
+            }
+        },
+        Err(_) => {
+            let config = AppConfig::default();
+            match save_config(app, &config) {
+                Ok(()) => info!("⚙️ Created {:?} with default settings", path),
+                Err(e) => warn!("Failed to write default configuration: {}", e),
+            }
+            config
+        }
+    };
+
+    if let Ok(mut stored) = APP_CONFIG.write() {
+        *stored = config;
+    }
+}
+
+// camelCase keys whose values differ between the two configs
+pub fn changed_keys(old: &AppConfig, new: &AppConfig) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(new))) =
+        (serde_json::to_value(old), serde_json::to_value(new))
+    else {
+        return Vec::new();
+    };
+    new.iter()
+        .filter(|(key, value)| old.get(*key) != Some(value))
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
+#[tauri::command]
+pub async fn get_app_config() -> Result<AppConfig, AppError> {
+    Ok(app_config())
+}
+
+// `patch` holds the keys to change, e.g. `{ "courtStaleTimeoutSecs": 120 }`
+#[tauri::command]
+pub async fn update_app_config(app: AppHandle, patch: serde_json::Value) -> Result<ConfigUpdate, AppError> {
+    if !patch.is_object() {
+        return Err(AppError::validation("patch", "Config patch must be an object"));
+    }
+
+    let current = app_config();
+    let mut merged = serde_json::to_value(&current)?;
+    deep_merge(&mut merged, patch);
+    let config: AppConfig = serde_json::from_value(merged)?;
+    config.validate()?;
+
+    let changed = changed_keys(&current, &config);
+    if changed.is_empty() {
+        return Ok(ConfigUpdate { config, applied: Vec::new(), requires_restart: Vec::new() });
+    }
+
+    save_config(&app, &config)?;
+    *APP_CONFIG.write()? = config.clone();
+
+    // Most values are read on use; the court sync keeps its own copy of the flush interval
+    if changed.iter().any(|key| key == "courtSyncFlushIntervalMs") {
+        set_court_data_flush_interval(config.court_sync_flush_interval_ms).await
+            .map_err(AppError::conflict)?;
+    }
+
+    let (requires_restart, applied): (Vec<String>, Vec<String>) = changed.into_iter()
+        .partition(|key| RESTART_REQUIRED.contains(&key.as_str()));
+    info!("⚙️ Configuration updated: {:?} (restart needed for {:?})", applied, requires_restart);
+    Ok(ConfigUpdate { config, applied, requires_restart })
+}
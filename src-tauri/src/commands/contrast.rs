@@ -0,0 +1,205 @@
+// src-tauri/src/commands/contrast.rs
+//
+// WCAG contrast checks for text on a board, so combinations like a white
+// score on a yellow panel get caught before they go up on a bright projector.
+// A text component's background is worked out from the component styles: its
+// own backgroundColor, over whatever filled components sit beneath its centre,
+// over the board background. Background images are not taken into account.
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::commands::config::app_config;
+use crate::commands::storage::read_scoreboard_config;
+use crate::error::AppError;
+
+// What the SVG export draws when a component has no text color
+const DEFAULT_TEXT_COLOR: &str = "#ffffff";
+const DEFAULT_BOARD_COLOR: &str = "#000000";
+// Component types that never draw text
+const NON_TEXT_TYPES: &[&str] = &["background", "logo", "video"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContrastCheck {
+    pub ratio: f64,
+    pub threshold: f64,
+    pub passes: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LowContrastComponent {
+    pub component_id: String,
+    pub component_type: String,
+    pub foreground: String,
+    // The color the text actually sits on, after blending the layers beneath it
+    pub background: String,
+    pub ratio: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContrastReport {
+    pub threshold: f64,
+    // Worst first
+    pub components: Vec<LowContrastComponent>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Rgba {
+    r: f64,
+    g: f64,
+    b: f64,
+    a: f64,
+}
+
+impl Rgba {
+    // `self` drawn over an opaque color
+    fn over(self, below: Rgba) -> Rgba {
+        let blend = |top: f64, bottom: f64| top * self.a + bottom * (1.0 - self.a);
+        Rgba { r: blend(self.r, below.r), g: blend(self.g, below.g), b: blend(self.b, below.b), a: 1.0 }
+    }
+
+    fn relative_luminance(self) -> f64 {
+        let linear = |channel: f64| {
+            let c = channel / 255.0;
+            if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    fn to_hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r.round() as u8, self.g.round() as u8, self.b.round() as u8)
+    }
+}
+
+// #rgb, #rgba, #rrggbb, #rrggbbaa, rgb()/rgba(), black, white and transparent
+fn parse_color(value: &str) -> Option<Rgba> {
+    let value = value.trim().to_ascii_lowercase();
+    match value.as_str() {
+        "transparent" => return Some(Rgba { r: 0.0, g: 0.0, b: 0.0, a: 0.0 }),
+        "black" => return Some(Rgba { r: 0.0, g: 0.0, b: 0.0, a: 1.0 }),
+        "white" => return Some(Rgba { r: 255.0, g: 255.0, b: 255.0, a: 1.0 }),
+        _ => {}
+    }
+
+    if let Some(hex) = value.strip_prefix('#') {
+        let digits: Vec<u8> = hex.chars()
+            .map(|c| c.to_digit(16).map(|d| d as u8))
+            .collect::<Option<_>>()?;
+        let channels: Vec<f64> = match digits.len() {
+            3 | 4 => digits.iter().map(|d| (d * 17) as f64).collect(),
+            6 | 8 => digits.chunks(2).map(|pair| (pair[0] * 16 + pair[1]) as f64).collect(),
+            _ => return None,
+        };
+        let a = channels.get(3).map_or(1.0, |a| a / 255.0);
+        return Some(Rgba { r: channels[0], g: channels[1], b: channels[2], a });
+    }
+
+    let inner = value.strip_prefix("rgba(").or_else(|| value.strip_prefix("rgb("))?.strip_suffix(')')?;
+    let parts: Vec<f64> = inner.split(',')
+        .map(|part| part.trim().parse::<f64>().ok())
+        .collect::<Option<_>>()?;
+    let color = match parts.as_slice() {
+        [r, g, b] => Rgba { r: *r, g: *g, b: *b, a: 1.0 },
+        [r, g, b, a] => Rgba { r: *r, g: *g, b: *b, a: a.clamp(0.0, 1.0) },
+        _ => return None,
+    };
+    [color.r, color.g, color.b].iter().all(|c| (0.0..=255.0).contains(c)).then_some(color)
+}
+
+fn contrast_ratio(foreground: Rgba, background: Rgba) -> f64 {
+    let (a, b) = (foreground.relative_luminance(), background.relative_luminance());
+    let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+    ((lighter + 0.05) / (darker + 0.05) * 100.0).round() / 100.0
+}
+
+fn number(value: Option<&serde_json::Value>) -> f64 {
+    value.and_then(|v| v.as_f64()).unwrap_or(0.0)
+}
+
+fn style_color(component: &serde_json::Value, key: &str) -> Option<Rgba> {
+    component.get("style").and_then(|s| s.get(key)).and_then(|c| c.as_str()).and_then(parse_color)
+}
+
+fn contains_point(component: &serde_json::Value, x: f64, y: f64) -> bool {
+    let (left, top) = (number(component.pointer("/position/x")), number(component.pointer("/position/y")));
+    let (width, height) = (number(component.pointer("/size/width")), number(component.pointer("/size/height")));
+    x >= left && x <= left + width && y >= top && y <= top + height
+}
+
+fn board_color(data: &serde_json::Value) -> Rgba {
+    let base = parse_color(DEFAULT_BOARD_COLOR).unwrap_or(Rgba { r: 0.0, g: 0.0, b: 0.0, a: 1.0 });
+    let Some(color) = data.pointer("/background/color").and_then(|c| c.as_str()).and_then(parse_color) else {
+        return base;
+    };
+    let opacity = data.pointer("/background/opacity").and_then(|o| o.as_f64()).unwrap_or(1.0);
+    Rgba { a: color.a * opacity.clamp(0.0, 1.0), ..color }.over(base)
+}
+
+pub fn find_low_contrast(data: &serde_json::Value, threshold: f64) -> Vec<LowContrastComponent> {
+    let mut components: Vec<&serde_json::Value> = data.get("components")
+        .and_then(|c| c.as_array())
+        .map(|c| c.iter().collect())
+        .unwrap_or_default();
+    // Same draw order as the renderer: hidden ones skipped, then by z-index
+    components.retain(|c| c.get("visible").and_then(|v| v.as_bool()).unwrap_or(true));
+    components.sort_by_key(|c| c.get("zIndex").and_then(|z| z.as_i64()).unwrap_or(0));
+
+    let board = board_color(data);
+    let mut low = Vec::new();
+    for (index, component) in components.iter().enumerate() {
+        let component_type = component.get("type").and_then(|t| t.as_str()).unwrap_or("");
+        if NON_TEXT_TYPES.contains(&component_type) {
+            continue;
+        }
+
+        let x = number(component.pointer("/position/x")) + number(component.pointer("/size/width")) / 2.0;
+        let y = number(component.pointer("/position/y")) + number(component.pointer("/size/height")) / 2.0;
+        let background = components[..=index].iter()
+            .filter(|below| contains_point(below, x, y))
+            .filter_map(|below| style_color(below, "backgroundColor"))
+            .fold(board, |beneath, color| color.over(beneath));
+
+        let foreground = style_color(component, "textColor")
+            .or_else(|| parse_color(DEFAULT_TEXT_COLOR))
+            .map(|color| color.over(background))
+            .unwrap_or(background);
+
+        let ratio = contrast_ratio(foreground, background);
+        if ratio < threshold {
+            low.push(LowContrastComponent {
+                component_id: component.get("id").and_then(|id| id.as_str()).unwrap_or("").to_string(),
+                component_type: component_type.to_string(),
+                foreground: foreground.to_hex(),
+                background: background.to_hex(),
+                ratio,
+            });
+        }
+    }
+
+    low.sort_by(|a, b| a.ratio.total_cmp(&b.ratio));
+    low
+}
+
+#[tauri::command]
+pub async fn check_text_contrast(foreground: String, background: String) -> Result<ContrastCheck, AppError> {
+    let fg = parse_color(&foreground)
+        .ok_or_else(|| AppError::validation("foreground", format!("'{}' is not a color", foreground)))?;
+    let bg = parse_color(&background)
+        .ok_or_else(|| AppError::validation("background", format!("'{}' is not a color", background)))?;
+
+    // Translucent colors are judged as they'd appear on the board's default black
+    let bg = bg.over(parse_color(DEFAULT_BOARD_COLOR).unwrap_or(bg));
+    let ratio = contrast_ratio(fg.over(bg), bg);
+    let threshold = app_config().min_text_contrast_ratio;
+    Ok(ContrastCheck { ratio, threshold, passes: ratio >= threshold })
+}
+
+#[tauri::command]
+pub async fn find_low_contrast_components(app: AppHandle, filename: String) -> Result<ContrastReport, AppError> {
+    let (_, config) = read_scoreboard_config(&app, &filename)?;
+    let threshold = app_config().min_text_contrast_ratio;
+    let components = find_low_contrast(&config.data, threshold);
+    Ok(ContrastReport { threshold, components })
+}
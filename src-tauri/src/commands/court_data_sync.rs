@@ -1,6 +1,6 @@
 // src-tauri/src/commands/court_data_sync.rs
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
@@ -10,6 +10,13 @@ use tokio::time::interval;
 use chrono::{DateTime, Utc, Duration as ChronoDuration};
 use thiserror::Error;
 use lazy_static::lazy_static;
+use tauri::{AppHandle, Emitter};
+use tracing::{debug, error, info, warn};
+
+use crate::commands::config::app_config;
+use crate::commands::disk_space::ensure_free_space;
+use crate::commands::events::BackendEvent;
+use crate::error::AppError;
 
 #[derive(Error, Debug)]
 pub enum CourtSyncError {
@@ -19,6 +26,9 @@ pub enum CourtSyncError {
     #[error("JSON serialization error: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("{0}")]
+    Storage(#[from] AppError),
+
     #[error("Sync already running")]
     AlreadyRunning,
 
@@ -26,6 +36,9 @@ pub enum CourtSyncError {
     NotRunning,
 }
 
+// Sync attempts kept for diagnostics; at the default 2s interval this is the last few minutes
+const MAX_SYNC_HISTORY: usize = 100;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CourtDataEntry {
     pub data: serde_json::Value,
@@ -38,6 +51,8 @@ pub struct CourtDataManager {
     data: HashMap<String, CourtDataEntry>,
     storage_path: PathBuf,
     has_changes: bool,
+    flush_interval: Duration,
+    last_flush: Option<std::time::Instant>,
 }
 
 impl CourtDataManager {
@@ -46,9 +61,32 @@ impl CourtDataManager {
             data: HashMap::new(),
             storage_path,
             has_changes: false,
+            // Minimum time between writes of court_data.json; changes in between are batched
+            flush_interval: Duration::from_millis(app_config().court_sync_flush_interval_ms),
+            last_flush: None,
         }
     }
 
+    pub fn set_flush_interval(&mut self, interval: Duration) {
+        self.flush_interval = interval;
+    }
+
+    // Whether a dirty manager may write now, or must wait for the interval to pass
+    pub fn flush_due(&self, now: std::time::Instant) -> bool {
+        self.has_changes && self.last_flush
+            .map(|last| now.duration_since(last) >= self.flush_interval)
+            .unwrap_or(true)
+    }
+
+    // Write pending changes if the flush interval has elapsed since the last write
+    pub async fn flush_if_due(&mut self) -> Result<bool, CourtSyncError> {
+        if !self.flush_due(std::time::Instant::now()) {
+            return Ok(false);
+        }
+        self.persist_to_file().await?;
+        Ok(true)
+    }
+
     pub async fn store_court_data(&mut self, court_data: HashMap<String, serde_json::Value>) -> Result<(), CourtSyncError> {
         let now = Utc::now();
 
@@ -62,13 +100,13 @@ impl CourtDataManager {
         }
 
         self.has_changes = true;
-        self.persist_to_file().await?;
+        self.flush_if_due().await?;
         Ok(())
     }
 
     pub async fn cleanup_expired_data(&mut self) -> Result<(), CourtSyncError> {
         let now = Utc::now();
-        let max_age = Duration::from_secs(300); // 5 minutes
+        let max_age = app_config().court_stale_timeout();
 
         let expired_courts: Vec<String> = self.data
             .iter()
@@ -79,24 +117,31 @@ impl CourtDataManager {
             .collect();
 
         if !expired_courts.is_empty() {
-            println!("🧹 Cleaning up {} expired court data entries (older than 5 minutes)", expired_courts.len());
+            info!("🧹 Cleaning up {} expired court data entries (older than 5 minutes)", expired_courts.len());
             for court in expired_courts {
                 self.data.remove(&court);
                 self.has_changes = true;
             }
-            self.persist_to_file().await?;
+            self.flush_if_due().await?;
         }
 
         Ok(())
     }
 
-    pub async fn persist_to_file(&self) -> Result<(), CourtSyncError> {
+    // Write immediately, ignoring the flush interval; used on stop and shutdown
+    pub async fn persist_to_file(&mut self) -> Result<(), CourtSyncError> {
         if !self.has_changes {
             return Ok(());
         }
 
         let json_data = serde_json::to_string_pretty(&self.data)?;
+        // Keeps the previous file intact rather than truncating it on a full disk
+        if let Some(dir) = self.storage_path.parent() {
+            ensure_free_space(dir, json_data.len() as u64)?;
+        }
         tokio::fs::write(&self.storage_path, json_data).await?;
+        self.has_changes = false;
+        self.last_flush = Some(std::time::Instant::now());
         Ok(())
     }
 
@@ -121,7 +166,7 @@ lazy_static! {
         match CourtDataSync::new() {
             Ok(sync) => sync,
             Err(e) => {
-                eprintln!("Failed to create CourtDataSync: {:?}", e);
+                error!("Failed to create CourtDataSync: {:?}", e);
                 std::process::exit(1);
             }
         }
@@ -129,9 +174,9 @@ lazy_static! {
 }
 
 #[tauri::command]
-pub async fn start_court_data_sync(interval_ms: u64) -> Result<String, String> {
+pub async fn start_court_data_sync(app: AppHandle, interval_ms: u64) -> Result<String, String> {
     let sync = COURT_DATA_SYNC.lock().await;
-    sync.start_sync(interval_ms).await
+    sync.start_sync(interval_ms, Some(app)).await
         .map_err(|e| format!("Failed to start sync: {:?}", e))?;
     Ok("Court data sync started".to_string())
 }
@@ -158,12 +203,45 @@ pub async fn get_court_sync_status() -> Result<CourtSyncStatus, String> {
     Ok(sync.get_status().await)
 }
 
+// Status plus recent sync attempts, for the diagnostics bundle
+pub async fn court_sync_diagnostics() -> (CourtSyncStatus, Vec<CourtSyncHistoryEntry>) {
+    let sync = COURT_DATA_SYNC.lock().await;
+    (sync.get_status().await, sync.recent_history().await)
+}
+
 #[tauri::command]
 pub async fn is_court_sync_running() -> Result<bool, String> {
     let sync = COURT_DATA_SYNC.lock().await;
     Ok(sync.is_running().await)
 }
 
+#[tauri::command]
+pub async fn set_court_sync_watchdog(max_consecutive_errors: Option<u64>) -> Result<String, String> {
+    let sync = COURT_DATA_SYNC.lock().await;
+    sync.set_watchdog(max_consecutive_errors).await;
+
+    match max_consecutive_errors.filter(|n| *n > 0) {
+        Some(n) => Ok(format!("Court sync watchdog will restart after {} consecutive errors", n)),
+        None => Ok("Court sync watchdog disabled".to_string()),
+    }
+}
+
+#[tauri::command]
+pub async fn set_court_data_flush_interval(interval_ms: u64) -> Result<String, String> {
+    let sync = COURT_DATA_SYNC.lock().await;
+    sync.set_flush_interval(Duration::from_millis(interval_ms)).await;
+    Ok(format!("Court data will be written at most every {}ms", interval_ms))
+}
+
+// Write any batched court data before the process exits
+pub fn flush_court_data_sync_on_exit() {
+    let result = tauri::async_runtime::block_on(async {
+        COURT_DATA_SYNC.lock().await.flush().await
+    });
+    if let Err(e) = result {
+        error!("Failed to flush court data sync on exit: {}", e);
+    }
+}
 
 #[derive(Debug)]
 pub struct CourtSyncState {
@@ -173,6 +251,18 @@ pub struct CourtSyncState {
     pub active_courts: Vec<String>,
     pub sync_task: Option<JoinHandle<()>>,
     pub error_count: u64,
+    pub consecutive_errors: u64,
+    pub watchdog_threshold: Option<u64>,
+    pub restart_count: u64,
+    pub last_error: Option<String>,
+    pub history: VecDeque<CourtSyncHistoryEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CourtSyncHistoryEntry {
+    pub at: DateTime<Utc>,
+    // None for a successful sync
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -183,6 +273,18 @@ pub struct CourtSyncStatus {
     pub active_courts: Vec<String>,
     pub stored_courts: Vec<String>,
     pub error_count: u64,
+    pub consecutive_errors: u64,
+    pub watchdog_threshold: Option<u64>,
+    pub restart_count: u64,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CourtSyncRestartedEvent {
+    pub consecutive_errors: u64,
+    pub total_errors: u64,
+    pub restart_count: u64,
+    pub last_error: Option<String>,
 }
 
 impl Default for CourtSyncState {
@@ -194,10 +296,51 @@ impl Default for CourtSyncState {
             active_courts: Vec::new(),
             sync_task: None,
             error_count: 0,
+            consecutive_errors: 0,
+            watchdog_threshold: None,
+            restart_count: 0,
+            last_error: None,
+            history: VecDeque::new(),
         }
     }
 }
 
+impl CourtSyncState {
+    // Record a failed sync; returns true when the watchdog threshold has been reached
+    pub fn record_error(&mut self, error: String) -> bool {
+        self.error_count += 1;
+        self.consecutive_errors += 1;
+        self.push_history(Some(error.clone()));
+        self.last_error = Some(error);
+
+        match self.watchdog_threshold {
+            Some(threshold) if threshold > 0 => self.consecutive_errors >= threshold,
+            _ => false,
+        }
+    }
+
+    pub fn record_success(&mut self) {
+        self.consecutive_errors = 0;
+        self.push_history(None);
+    }
+
+    fn push_history(&mut self, error: Option<String>) {
+        if self.history.len() == MAX_SYNC_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(CourtSyncHistoryEntry { at: Utc::now(), error });
+    }
+
+    // Reset per-run state before the watchdog spins up a fresh task;
+    // total error and restart counters are kept for diagnostics
+    fn reset_for_restart(&mut self) {
+        self.consecutive_errors = 0;
+        self.last_sync = None;
+        self.active_courts.clear();
+        self.restart_count += 1;
+    }
+}
+
 pub struct CourtDataSync {
     state: Arc<Mutex<CourtSyncState>>,
     data_manager: Arc<Mutex<CourtDataManager>>,
@@ -220,7 +363,7 @@ impl CourtDataSync {
         Ok(path)
     }
 
-    pub async fn start_sync(&self, interval_ms: u64) -> Result<(), CourtSyncError> {
+    pub async fn start_sync(&self, interval_ms: u64, app_handle: Option<AppHandle>) -> Result<(), CourtSyncError> {
         let mut state = self.state.lock().await;
 
         if state.is_running {
@@ -230,34 +373,82 @@ impl CourtDataSync {
         state.is_running = true;
         state.interval_ms = interval_ms;
 
-        let state_clone = Arc::clone(&self.state);
-        let data_manager_clone = Arc::clone(&self.data_manager);
+        let handle = Self::spawn_sync_task(
+            Arc::clone(&self.state),
+            Arc::clone(&self.data_manager),
+            interval_ms,
+            app_handle,
+        );
 
-        let handle = tokio::spawn(async move {
+        state.sync_task = Some(handle);
+        info!("🚀 Started court data sync service (interval: {}ms)", interval_ms);
+        Ok(())
+    }
+
+    fn spawn_sync_task(
+        state: Arc<Mutex<CourtSyncState>>,
+        data_manager: Arc<Mutex<CourtDataManager>>,
+        interval_ms: u64,
+        app_handle: Option<AppHandle>,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
             let mut interval_timer = interval(Duration::from_millis(interval_ms));
             interval_timer.tick().await; // First tick is immediate
 
             loop {
                 interval_timer.tick().await;
 
-                let state = state_clone.lock().await;
-                if !state.is_running {
+                let current_state = state.lock().await;
+                if !current_state.is_running {
                     break;
                 }
 
-                drop(state); // Release lock before sync
-
-                if let Err(e) = Self::perform_sync(&state_clone, &data_manager_clone).await {
-                    eprintln!("Sync error: {:?}", e);
-                    let mut state = state_clone.lock().await;
-                    state.error_count += 1;
+                drop(current_state); // Release lock before sync
+
+                match Self::perform_sync(&state, &data_manager).await {
+                    Ok(()) => {
+                        state.lock().await.record_success();
+                    }
+                    Err(e) => {
+                        error!("Sync error: {:?}", e);
+                        let mut current_state = state.lock().await;
+                        if !current_state.record_error(e.to_string()) {
+                            continue;
+                        }
+
+                        // Watchdog: replace this task with a fresh one and let this loop end
+                        let event = CourtSyncRestartedEvent {
+                            consecutive_errors: current_state.consecutive_errors,
+                            total_errors: current_state.error_count,
+                            restart_count: current_state.restart_count + 1,
+                            last_error: current_state.last_error.clone(),
+                        };
+                        warn!("🐕 Court sync hit {} consecutive errors, restarting sync task", event.consecutive_errors);
+
+                        current_state.reset_for_restart();
+                        current_state.sync_task = Some(Self::spawn_sync_task(
+                            Arc::clone(&state),
+                            Arc::clone(&data_manager),
+                            interval_ms,
+                            app_handle.clone(),
+                        ));
+
+                        if let Some(app) = &app_handle {
+                            if let Err(e) = app.emit(BackendEvent::CourtSyncRestarted.as_str(), &event) {
+                                error!("Failed to emit {}: {}", BackendEvent::CourtSyncRestarted, e);
+                            }
+                        }
+                        break;
+                    }
                 }
             }
-        });
+        })
+    }
 
-        state.sync_task = Some(handle);
-        println!("🚀 Started court data sync service (interval: {}ms)", interval_ms);
-        Ok(())
+    pub async fn set_watchdog(&self, max_consecutive_errors: Option<u64>) {
+        let mut state = self.state.lock().await;
+        state.watchdog_threshold = max_consecutive_errors.filter(|n| *n > 0);
+        state.consecutive_errors = 0;
     }
 
     pub async fn stop_sync(&self) -> Result<(), CourtSyncError> {
@@ -273,7 +464,10 @@ impl CourtDataSync {
             handle.abort();
         }
 
-        println!("🛑 Stopped court data sync service");
+        // Don't leave batched changes unwritten once the sync stops
+        self.data_manager.lock().await.persist_to_file().await?;
+
+        info!("🛑 Stopped court data sync service");
         Ok(())
     }
 
@@ -297,7 +491,7 @@ impl CourtDataSync {
             // Store the data
             let mut manager = data_manager.lock().await;
             manager.store_court_data(court_data).await?;
-            println!("🔄 Synced active court data: {:?}", active_courts);
+            debug!("🔄 Synced active court data: {:?}", active_courts);
 
             // Update last sync time
             let mut state = state.lock().await;
@@ -310,7 +504,7 @@ impl CourtDataSync {
             // Cleanup expired data (older than 5 minutes)
             manager.cleanup_expired_data().await?;
         } else {
-            println!("🔄 No active court data to sync");
+            debug!("🔄 No active court data to sync");
         }
 
         Ok(())
@@ -339,7 +533,7 @@ impl CourtDataSync {
                 }
             }
             Err(e) => {
-                println!("Failed to fetch court data: {:?}", e);
+                warn!("Failed to fetch court data: {:?}", e);
                 Ok(HashMap::new())
             }
         }
@@ -358,14 +552,14 @@ impl CourtDataSync {
             .collect();
 
         if !courts_to_remove.is_empty() {
-            println!("🧹 Cleaning up data for {} undisplayed courts: {:?}", courts_to_remove.len(), courts_to_remove);
+            info!("🧹 Cleaning up data for {} undisplayed courts: {:?}", courts_to_remove.len(), courts_to_remove);
             for court_name in &courts_to_remove {
                 manager.remove_court_data(court_name);
             }
-            manager.persist_to_file().await?;
-            println!("🧹 Removed data for {} undisplayed courts", courts_to_remove.len());
+            manager.flush_if_due().await?;
+            info!("🧹 Removed data for {} undisplayed courts", courts_to_remove.len());
         } else {
-            println!("✅ No undisplayed courts to clean up");
+            debug!("✅ No undisplayed courts to clean up");
         }
 
         Ok(())
@@ -382,11 +576,104 @@ impl CourtDataSync {
             active_courts: state.active_courts.clone(),
             stored_courts: manager.get_court_names(),
             error_count: state.error_count,
+            consecutive_errors: state.consecutive_errors,
+            watchdog_threshold: state.watchdog_threshold,
+            restart_count: state.restart_count,
+            last_error: state.last_error.clone(),
         }
     }
 
+    // Oldest first
+    pub async fn recent_history(&self) -> Vec<CourtSyncHistoryEntry> {
+        self.state.lock().await.history.iter().cloned().collect()
+    }
+
+    pub async fn set_flush_interval(&self, interval: Duration) {
+        self.data_manager.lock().await.set_flush_interval(interval);
+    }
+
+    pub async fn flush(&self) -> Result<(), CourtSyncError> {
+        self.data_manager.lock().await.persist_to_file().await
+    }
+
     pub async fn is_running(&self) -> bool {
         let state = self.state.lock().await;
         state.is_running
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watchdog_trips_after_threshold_consecutive_errors() {
+        let mut state = CourtSyncState { watchdog_threshold: Some(3), ..Default::default() };
+
+        assert!(!state.record_error("first".to_string()));
+        assert!(!state.record_error("second".to_string()));
+        assert!(state.record_error("third".to_string()));
+        assert_eq!(state.consecutive_errors, 3);
+        assert_eq!(state.last_error.as_deref(), Some("third"));
+
+        state.reset_for_restart();
+        assert_eq!(state.consecutive_errors, 0);
+        assert_eq!(state.restart_count, 1);
+        assert_eq!(state.error_count, 3);
+    }
+
+    #[test]
+    fn success_resets_consecutive_errors() {
+        let mut state = CourtSyncState { watchdog_threshold: Some(3), ..Default::default() };
+
+        assert!(!state.record_error("first".to_string()));
+        assert!(!state.record_error("second".to_string()));
+        state.record_success();
+        assert_eq!(state.consecutive_errors, 0);
+
+        assert!(!state.record_error("third".to_string()));
+        assert!(!state.record_error("fourth".to_string()));
+        assert_eq!(state.error_count, 4);
+        assert_eq!(state.history.len(), 5);
+    }
+
+    #[test]
+    fn watchdog_disabled_never_trips() {
+        let mut state = CourtSyncState::default();
+        for i in 0..10 {
+            assert!(!state.record_error(format!("error {}", i)));
+        }
+    }
+
+
+    #[tokio::test]
+    async fn changes_within_flush_interval_are_batched() {
+        let path = std::env::temp_dir().join(format!("court_data_{}.json", uuid::Uuid::new_v4()));
+        let mut manager = CourtDataManager::new(path.clone());
+        manager.set_flush_interval(Duration::from_secs(60));
+
+        let mut first = HashMap::new();
+        first.insert("Court 1".to_string(), serde_json::json!({ "score": 1 }));
+        manager.store_court_data(first).await.unwrap();
+        assert!(path.exists());
+        assert!(!manager.has_changes);
+
+        let mut second = HashMap::new();
+        second.insert("Court 2".to_string(), serde_json::json!({ "score": 2 }));
+        manager.store_court_data(second).await.unwrap();
+        assert!(manager.has_changes);
+        let on_disk: HashMap<String, CourtDataEntry> =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(!on_disk.contains_key("Court 2"));
+
+        assert!(!manager.flush_due(std::time::Instant::now()));
+        assert!(manager.flush_due(std::time::Instant::now() + Duration::from_secs(61)));
+
+        manager.persist_to_file().await.unwrap();
+        let on_disk: HashMap<String, CourtDataEntry> =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert!(on_disk.contains_key("Court 2"));
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
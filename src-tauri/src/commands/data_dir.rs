@@ -0,0 +1,420 @@
+// src-tauri/src/commands/data_dir.rs
+//
+// Where the app keeps its files. Normally that's the platform app data
+// directory; in portable mode (a `portable.flag` file next to the executable,
+// or the `--portable` switch) it's `<exe_dir>/data`, so the app can run from a
+// USB stick without writing anything to the host machine. Every module gets
+// its base directory from `data_dir()` rather than asking Tauri directly.
+//
+// The location can also be moved elsewhere (e.g. to a bigger D: drive) with
+// `set_data_directory`. That writes a pointer file into the platform directory
+// which `data_dir()` reads once per launch, so the switch takes effect on the
+// next start and every module sees the same location for the whole session.
+// The old copy stays on disk until `purge_old_data_directory` is called.
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Emitter, Manager};
+use tracing::{error, info, warn};
+
+use crate::commands::disk_space::{disk_space_status, ensure_free_space};
+use crate::commands::events::BackendEvent;
+use crate::commands::media_library::write_atomic;
+use crate::error::AppError;
+
+const PORTABLE_FLAG_FILE: &str = "portable.flag";
+const PORTABLE_SWITCH: &str = "--portable";
+const PORTABLE_DATA_DIR: &str = "data";
+const LOCATION_POINTER_FILE: &str = "data_location.json";
+// Logs are reopened at the new location on restart, so the old ones stay behind
+const LOGS_DIR: &str = "logs";
+
+// Created up front in portable mode, so a fresh stick has the full layout
+const DATA_SUBDIRS: &[&str] = &["scoreboards", "images", "videos", "live_data", "logs"];
+
+lazy_static! {
+    static ref PORTABLE_DIR: Option<PathBuf> = portable_dir(std::env::args(), std::env::current_exe().ok().as_deref());
+}
+
+// The pointer file as read at launch; None when the default location is in use
+static LOCATION_OVERRIDE: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DataLocationPointer {
+    path: PathBuf,
+    // Where the data lived before the last move, kept until it is purged
+    #[serde(default)]
+    previous: Option<PathBuf>,
+    // Whether the previous location's data was copied across, so purging it loses nothing
+    #[serde(default)]
+    copied: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataDirectoryProgressEvent {
+    pub file: String,
+    pub copied_files: usize,
+    pub total_files: usize,
+    pub copied_bytes: u64,
+    pub total_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataDirectoryChange {
+    pub previous_path: String,
+    pub new_path: String,
+    pub copied_files: usize,
+    pub copied_bytes: u64,
+    pub restart_required: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaStorageStats {
+    pub data_directory: String,
+    pub default_data_directory: String,
+    pub portable: bool,
+    // Set after set_data_directory until the app restarts
+    pub pending_data_directory: Option<String>,
+    // Old location still on disk, waiting for purge_old_data_directory
+    pub previous_data_directory: Option<String>,
+    pub scoreboards_bytes: u64,
+    pub images_bytes: u64,
+    pub videos_bytes: u64,
+    pub live_data_bytes: u64,
+    pub total_bytes: u64,
+    pub available_bytes: Option<u64>,
+    pub low_disk_space_threshold_bytes: u64,
+    pub low_disk_space: bool,
+}
+
+fn portable_dir<I: IntoIterator<Item = String>>(args: I, exe_path: Option<&Path>) -> Option<PathBuf> {
+    let exe_dir = exe_path?.parent()?;
+    let requested = args.into_iter().skip(1).any(|arg| arg == PORTABLE_SWITCH)
+        || exe_dir.join(PORTABLE_FLAG_FILE).is_file();
+    requested.then(|| exe_dir.join(PORTABLE_DATA_DIR))
+}
+
+pub fn is_portable() -> bool {
+    PORTABLE_DIR.is_some()
+}
+
+fn default_data_dir(app: &AppHandle) -> Result<PathBuf, AppError> {
+    Ok(app.path().app_data_dir()?)
+}
+
+fn read_pointer(default_dir: &Path) -> Option<DataLocationPointer> {
+    let json = std::fs::read_to_string(default_dir.join(LOCATION_POINTER_FILE)).ok()?;
+    match serde_json::from_str(&json) {
+        Ok(pointer) => Some(pointer),
+        Err(e) => {
+            warn!("Ignoring unreadable {}: {}", LOCATION_POINTER_FILE, e);
+            None
+        }
+    }
+}
+
+async fn write_pointer(default_dir: &Path, pointer: &DataLocationPointer) -> Result<(), AppError> {
+    tokio::fs::create_dir_all(default_dir).await?;
+    write_atomic(&default_dir.join(LOCATION_POINTER_FILE), serde_json::to_string_pretty(pointer)?.as_bytes()).await
+}
+
+pub fn data_dir(app: &AppHandle) -> Result<PathBuf, AppError> {
+    if let Some(dir) = PORTABLE_DIR.as_ref() {
+        return Ok(dir.clone());
+    }
+    let default_dir = default_data_dir(app)?;
+    let location = LOCATION_OVERRIDE.get_or_init(|| {
+        let path = read_pointer(&default_dir).map(|pointer| pointer.path);
+        if let Some(path) = &path {
+            info!("💾 Using relocated data directory {:?}", path);
+        }
+        path
+    });
+    Ok(location.clone().unwrap_or(default_dir))
+}
+
+// Must run before anything writes to the data directory
+pub fn init_data_dir() -> Result<(), AppError> {
+    let Some(dir) = PORTABLE_DIR.as_ref() else {
+        return Ok(());
+    };
+    for subdir in DATA_SUBDIRS {
+        std::fs::create_dir_all(dir.join(subdir))
+            .map_err(|e| AppError::io(format!("Failed to create portable data directory {:?}: {}", dir, e)))?;
+    }
+    info!("💾 Portable mode: storing data in {:?}", dir);
+    Ok(())
+}
+
+// Top-level entries that belong to the app's data: everything except logs,
+// the pointer file and dotfiles (plugin state such as the window layout)
+fn data_entries(dir: &Path) -> Result<Vec<PathBuf>, AppError> {
+    let mut entries = Vec::new();
+    if !dir.exists() {
+        return Ok(entries);
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name == LOGS_DIR || name == LOCATION_POINTER_FILE || name.starts_with('.') {
+            continue;
+        }
+        entries.push(entry.path());
+    }
+    entries.sort();
+    Ok(entries)
+}
+
+// Every file under `path` (or `path` itself), with its size
+fn collect_files(path: &Path, files: &mut Vec<(PathBuf, u64)>) -> Result<(), AppError> {
+    let metadata = std::fs::symlink_metadata(path)?;
+    if metadata.is_dir() {
+        for entry in std::fs::read_dir(path)? {
+            collect_files(&entry?.path(), files)?;
+        }
+    } else if metadata.is_file() {
+        files.push((path.to_path_buf(), metadata.len()));
+    }
+    Ok(())
+}
+
+fn directory_size(path: &Path) -> u64 {
+    let mut files = Vec::new();
+    if path.exists() && collect_files(path, &mut files).is_err() {
+        warn!("Could not measure {:?}", path);
+    }
+    files.iter().map(|(_, size)| size).sum()
+}
+
+fn file_hash(path: &Path) -> Result<Vec<u8>, AppError> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher.finalize().to_vec())
+}
+
+fn validate_target(target: &Path, current: &Path, required_bytes: u64) -> Result<(), AppError> {
+    if !target.is_absolute() {
+        return Err(AppError::validation("new_path", "Data directory must be an absolute path"));
+    }
+    if target == current {
+        return Err(AppError::validation("new_path", "That is already the data directory"));
+    }
+    if target.starts_with(current) || current.starts_with(target) {
+        return Err(AppError::validation("new_path", "The new data directory cannot be inside the current one, or contain it"));
+    }
+    if target.exists() {
+        if !target.is_dir() {
+            return Err(AppError::validation("new_path", format!("{:?} is not a folder", target)));
+        }
+        // The old location is deleted wholesale on purge, so it must only ever hold our data
+        if std::fs::read_dir(target)?.next().is_some() {
+            return Err(AppError::validation("new_path", format!("{:?} is not empty", target)));
+        }
+    }
+
+    std::fs::create_dir_all(target)
+        .map_err(|e| AppError::validation("new_path", format!("Cannot create {:?}: {}", target, e)))?;
+    let probe = target.join(format!(".write-test-{}", uuid::Uuid::new_v4()));
+    std::fs::write(&probe, b"ok")
+        .map_err(|e| AppError::validation("new_path", format!("{:?} is not writable: {}", target, e)))?;
+    let _ = std::fs::remove_file(&probe);
+
+    ensure_free_space(target, required_bytes)
+}
+
+// Copy every file, emitting progress as it goes, then re-read both sides and
+// compare hashes so a bad copy is caught before anything points at it
+fn copy_data(app: &AppHandle, source: &Path, target: &Path, files: &[(PathBuf, u64)]) -> Result<u64, AppError> {
+    let total_bytes: u64 = files.iter().map(|(_, size)| size).sum();
+    let mut copied_bytes = 0;
+
+    for (index, (file, size)) in files.iter().enumerate() {
+        let relative = file.strip_prefix(source)
+            .map_err(|_| AppError::io(format!("{:?} is outside {:?}", file, source)))?;
+        let destination = target.join(relative);
+        if let Some(parent) = destination.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(file, &destination)
+            .map_err(|e| AppError::io(format!("Failed to copy {:?}: {}", relative, e)))?;
+        copied_bytes += size;
+
+        let event = DataDirectoryProgressEvent {
+            file: relative.to_string_lossy().to_string(),
+            copied_files: index + 1,
+            total_files: files.len(),
+            copied_bytes,
+            total_bytes,
+        };
+        if let Err(e) = app.emit(BackendEvent::DataDirectoryProgress.as_str(), &event) {
+            error!("Failed to emit {}: {}", BackendEvent::DataDirectoryProgress, e);
+        }
+    }
+
+    let mut copied = Vec::new();
+    collect_files(target, &mut copied)?;
+    if copied.len() != files.len() {
+        return Err(AppError::io(format!("Copied {} files but expected {}", copied.len(), files.len())));
+    }
+    for (file, _) in files {
+        let relative = file.strip_prefix(source).unwrap_or(file);
+        if file_hash(file)? != file_hash(&target.join(relative))? {
+            return Err(AppError::io(format!("Copy of {:?} does not match the original", relative)));
+        }
+    }
+    Ok(copied_bytes)
+}
+
+#[tauri::command]
+pub async fn set_data_directory(app: AppHandle, new_path: String, move_existing: bool) -> Result<DataDirectoryChange, AppError> {
+    if is_portable() {
+        return Err(AppError::conflict("The data directory cannot be moved in portable mode"));
+    }
+    let target = PathBuf::from(new_path.trim());
+    let current = data_dir(&app)?;
+    let default_dir = default_data_dir(&app)?;
+
+    let blocking_app = app.clone();
+    let (blocking_target, blocking_current) = (target.clone(), current.clone());
+    let (copied_files, copied_bytes) = tokio::task::spawn_blocking(move || -> Result<(usize, u64), AppError> {
+        let mut files = Vec::new();
+        if move_existing {
+            for entry in data_entries(&blocking_current)? {
+                collect_files(&entry, &mut files)?;
+            }
+        }
+        let required: u64 = files.iter().map(|(_, size)| size).sum();
+        validate_target(&blocking_target, &blocking_current, required)?;
+
+        match copy_data(&blocking_app, &blocking_current, &blocking_target, &files) {
+            Ok(bytes) => Ok((files.len(), bytes)),
+            Err(e) => {
+                // Leave the target as empty as we found it so the user can retry
+                if let Ok(entries) = std::fs::read_dir(&blocking_target) {
+                    for entry in entries.flatten() {
+                        let path = entry.path();
+                        let _ = if path.is_dir() { std::fs::remove_dir_all(&path) } else { std::fs::remove_file(&path) };
+                    }
+                }
+                Err(e)
+            }
+        }
+    }).await??;
+
+    let pointer = DataLocationPointer {
+        path: target.clone(),
+        previous: Some(current.clone()),
+        copied: move_existing,
+    };
+    write_pointer(&default_dir, &pointer).await?;
+
+    info!("💾 Data directory will move from {:?} to {:?} on restart ({} files copied)", current, target, copied_files);
+    Ok(DataDirectoryChange {
+        previous_path: current.to_string_lossy().to_string(),
+        new_path: target.to_string_lossy().to_string(),
+        copied_files,
+        copied_bytes,
+        restart_required: true,
+    })
+}
+
+// Delete the copy left behind by set_data_directory, once the app is running from the new one
+#[tauri::command]
+pub async fn purge_old_data_directory(app: AppHandle) -> Result<u64, AppError> {
+    let default_dir = default_data_dir(&app)?;
+    let current = data_dir(&app)?;
+    let mut pointer = read_pointer(&default_dir)
+        .ok_or_else(|| AppError::not_found("Previous data directory"))?;
+    let previous = pointer.previous.clone()
+        .ok_or_else(|| AppError::not_found("Previous data directory"))?;
+
+    if pointer.path != current {
+        return Err(AppError::conflict("Restart the app to finish moving the data directory before purging the old one"));
+    }
+    if !pointer.copied {
+        return Err(AppError::conflict(format!(
+            "{:?} was not copied to the new location; delete it by hand if it is no longer needed", previous
+        )));
+    }
+    if previous == current {
+        return Err(AppError::conflict("The previous data directory is the one in use"));
+    }
+
+    let removed = tokio::task::spawn_blocking(move || -> Result<u64, AppError> {
+        let mut removed = 0;
+        for entry in data_entries(&previous)? {
+            removed += directory_size(&entry);
+            if entry.is_dir() {
+                std::fs::remove_dir_all(&entry)?;
+            } else {
+                std::fs::remove_file(&entry)?;
+            }
+        }
+        info!("💾 Purged old data directory {:?} ({} bytes)", previous, removed);
+        Ok(removed)
+    }).await??;
+
+    pointer.previous = None;
+    pointer.copied = false;
+    write_pointer(&default_dir, &pointer).await?;
+    Ok(removed)
+}
+
+#[tauri::command]
+pub async fn get_media_storage_stats(app: AppHandle) -> Result<MediaStorageStats, AppError> {
+    let current = data_dir(&app)?;
+    let default_dir = default_data_dir(&app)?;
+    let pointer = if is_portable() { None } else { read_pointer(&default_dir) };
+
+    let pending = pointer.as_ref()
+        .map(|pointer| pointer.path.clone())
+        .filter(|path| *path != current);
+    // Before the restart the old location is still the current one
+    let previous = pointer.as_ref()
+        .filter(|pointer| pointer.path == current)
+        .and_then(|pointer| pointer.previous.clone());
+
+    let sizes_dir = current.clone();
+    let (scoreboards_bytes, images_bytes, videos_bytes, live_data_bytes, total_bytes) = tokio::task::spawn_blocking(move || {
+        let total = data_entries(&sizes_dir).unwrap_or_default().iter().map(|entry| directory_size(entry)).sum();
+        (
+            directory_size(&sizes_dir.join("scoreboards")),
+            directory_size(&sizes_dir.join("images")),
+            directory_size(&sizes_dir.join("videos")),
+            directory_size(&sizes_dir.join("live_data")),
+            total,
+        )
+    }).await?;
+
+    let disk = disk_space_status(&app)?;
+    Ok(MediaStorageStats {
+        data_directory: current.to_string_lossy().to_string(),
+        default_data_directory: default_dir.to_string_lossy().to_string(),
+        portable: is_portable(),
+        pending_data_directory: pending.map(|path| path.to_string_lossy().to_string()),
+        previous_data_directory: previous.map(|path| path.to_string_lossy().to_string()),
+        scoreboards_bytes,
+        images_bytes,
+        videos_bytes,
+        live_data_bytes,
+        total_bytes,
+        available_bytes: disk.available_bytes,
+        low_disk_space_threshold_bytes: disk.threshold_bytes,
+        low_disk_space: disk.low,
+    })
+}
@@ -0,0 +1,270 @@
+// src-tauri/src/commands/deep_link.rs
+//
+// tempuz:// links sent to operators, e.g. `tempuz://display/Center%20Court/1`.
+// Links are parsed into actions, optionally held for confirmation in the
+// designer, then run through the same load/display logic as the UI.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use lazy_static::lazy_static;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_deep_link::DeepLinkExt;
+use tracing::{error, info, warn};
+
+use crate::commands::events::BackendEvent;
+use crate::commands::launch::{find_scoreboard, open_scoreboard, LaunchOptions};
+use crate::commands::live_data::court_data;
+use crate::commands::storage::ScoreboardConfig;
+use crate::commands::data_dir::data_dir;
+use crate::error::AppError;
+
+const DEEP_LINK_SCHEME: &str = "tempuz";
+const SETTINGS_FILE: &str = "deep_link_settings.json";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum DeepLinkAction {
+    OpenScoreboard { name: String },
+    Display { name: String, monitor: u32 },
+    Court { court_name: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeepLinkSettings {
+    // Hold links until the operator confirms them in the designer
+    pub require_confirmation: bool,
+}
+
+impl Default for DeepLinkSettings {
+    fn default() -> Self {
+        Self { require_confirmation: true }
+    }
+}
+
+// Sent when a link is waiting for confirmation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeepLinkRequest {
+    pub request_id: String,
+    pub url: String,
+    pub action: DeepLinkAction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeepLinkResult {
+    pub url: String,
+    pub action: DeepLinkAction,
+    // Board for the designer to load (open_scoreboard)
+    pub scoreboard: Option<ScoreboardConfig>,
+    // Display window that was created (display)
+    pub window_id: Option<String>,
+    // Latest known data for the court (court)
+    pub court_data: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeepLinkError {
+    pub url: String,
+    pub error: String,
+}
+
+lazy_static! {
+    static ref PENDING_DEEP_LINKS: Arc<Mutex<HashMap<String, DeepLinkRequest>>> = Arc::new(Mutex::new(HashMap::new()));
+}
+
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = (bytes[i] == b'%')
+            .then(|| segment.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+pub fn parse_deep_link(link: &str) -> Result<DeepLinkAction, AppError> {
+    let url = url::Url::parse(link).map_err(|e| AppError::validation("url", format!("Malformed link: {}", e)))?;
+    if url.scheme() != DEEP_LINK_SCHEME {
+        return Err(AppError::validation("url", format!("Unsupported scheme: {}", url.scheme())));
+    }
+
+    let host = url.host_str().unwrap_or("");
+    let segments: Vec<String> = url.path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).map(percent_decode).collect())
+        .unwrap_or_default();
+
+    match (host, segments.as_slice()) {
+        ("open-scoreboard", [name]) => Ok(DeepLinkAction::OpenScoreboard { name: name.clone() }),
+        ("display", [name, monitor]) => {
+            let monitor = monitor.parse::<u32>()
+                .map_err(|_| AppError::validation("url", format!("Invalid monitor number: {}", monitor)))?;
+            Ok(DeepLinkAction::Display { name: name.clone(), monitor })
+        }
+        ("court", [court_name]) => Ok(DeepLinkAction::Court { court_name: court_name.clone() }),
+        ("open-scoreboard" | "display" | "court", _) => Err(AppError::validation("url", format!("Wrong number of arguments for {}", host))),
+        _ => Err(AppError::validation("url", format!("Unknown link action: {}", host))),
+    }
+}
+
+fn settings_path(app: &AppHandle) -> Result<std::path::PathBuf, AppError> {
+    let app_data_dir = data_dir(app)?;
+    Ok(app_data_dir.join(SETTINGS_FILE))
+}
+
+fn load_settings(app: &AppHandle) -> DeepLinkSettings {
+    settings_path(app).ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+// Also used by the single-instance plugin when the app is launched again
+pub fn focus_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let result = window.show().and_then(|_| window.unminimize()).and_then(|_| window.set_focus());
+        if let Err(e) = result {
+            warn!("Failed to focus main window: {}", e);
+        }
+    }
+}
+
+fn emit_error(app: &AppHandle, url: &str, error: AppError) {
+    warn!("Deep link {} failed: {}", url, error);
+    let event = DeepLinkError { url: url.to_string(), error: error.to_string() };
+    if let Err(e) = app.emit_to("main", BackendEvent::DeepLinkFailed.as_str(), &event) {
+        error!("Failed to emit {}: {}", BackendEvent::DeepLinkFailed, e);
+    }
+}
+
+async fn run_action(app: &AppHandle, url: &str, action: DeepLinkAction) -> Result<DeepLinkResult, AppError> {
+    let mut result = DeepLinkResult {
+        url: url.to_string(),
+        action: action.clone(),
+        scoreboard: None,
+        window_id: None,
+        court_data: None,
+    };
+
+    match action {
+        // Loading into the designer is done by the frontend; hand it the board
+        DeepLinkAction::OpenScoreboard { name } => {
+            result.scoreboard = Some(find_scoreboard(app, &name).await?);
+        }
+        DeepLinkAction::Display { name, monitor } => {
+            let options = LaunchOptions { monitor: Some(monitor), fullscreen: true, ..Default::default() };
+            result.window_id = Some(open_scoreboard(app, &options, &name).await?);
+        }
+        DeepLinkAction::Court { court_name } => {
+            result.court_data = court_data(&court_name).await;
+        }
+    }
+
+    Ok(result)
+}
+
+async fn execute(app: &AppHandle, url: &str, action: DeepLinkAction) {
+    match run_action(app, url, action).await {
+        Ok(result) => {
+            info!("🔗 Handled deep link {}", url);
+            if let Err(e) = app.emit_to("main", BackendEvent::DeepLinkHandled.as_str(), &result) {
+                error!("Failed to emit {}: {}", BackendEvent::DeepLinkHandled, e);
+            }
+        }
+        Err(e) => emit_error(app, url, e),
+    }
+}
+
+async fn handle_deep_link(app: AppHandle, url: String) {
+    focus_main_window(&app);
+
+    let action = match parse_deep_link(&url) {
+        Ok(action) => action,
+        Err(e) => return emit_error(&app, &url, e),
+    };
+
+    if !load_settings(&app).require_confirmation {
+        return execute(&app, &url, action).await;
+    }
+
+    let request = DeepLinkRequest {
+        request_id: uuid::Uuid::new_v4().to_string(),
+        url,
+        action,
+    };
+    PENDING_DEEP_LINKS.lock().await.insert(request.request_id.clone(), request.clone());
+    if let Err(e) = app.emit_to("main", BackendEvent::DeepLinkReceived.as_str(), &request) {
+        error!("Failed to emit {}: {}", BackendEvent::DeepLinkReceived, e);
+    }
+}
+
+// Links opened while the app is running, plus the one it was launched with.
+// A second instance started by the OS forwards its link here through the
+// single-instance plugin.
+pub fn register_deep_links(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
+    // Linux and Windows only pick up the scheme from the installer; register it for dev builds
+    #[cfg(all(debug_assertions, any(windows, target_os = "linux")))]
+    app.deep_link().register_all()?;
+
+    let handler_app = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+            tauri::async_runtime::spawn(handle_deep_link(handler_app.clone(), url.to_string()));
+        }
+    });
+
+    if let Some(urls) = app.deep_link().get_current()? {
+        for url in urls {
+            tauri::async_runtime::spawn(handle_deep_link(app.clone(), url.to_string()));
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn confirm_deep_link(app: AppHandle, request_id: String) -> Result<(), AppError> {
+    let request = PENDING_DEEP_LINKS.lock().await.remove(&request_id)
+        .ok_or_else(|| AppError::not_found(format!("Pending link {}", request_id)))?;
+    execute(&app, &request.url, request.action).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn dismiss_deep_link(request_id: String) -> Result<(), AppError> {
+    PENDING_DEEP_LINKS.lock().await.remove(&request_id);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_deep_link_settings(app: AppHandle) -> Result<DeepLinkSettings, AppError> {
+    Ok(load_settings(&app))
+}
+
+#[tauri::command]
+pub async fn set_deep_link_settings(app: AppHandle, settings: DeepLinkSettings) -> Result<(), AppError> {
+    let path = settings_path(&app)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| AppError::io(format!("Failed to create settings directory: {}", e)))?;
+    }
+    let json = serde_json::to_string_pretty(&settings)?;
+    std::fs::write(&path, json)
+        .map_err(|e| AppError::io(format!("Failed to write deep link settings: {}", e)))?;
+    Ok(())
+}
@@ -0,0 +1,351 @@
+// src-tauri/src/commands/diagnostics.rs
+//
+// One ZIP a venue can send to support when something goes wrong on show day:
+// recent logs, the state files, config, live data connections, court sync and
+// WebSocket status, the window layout and a system summary. Media files are
+// never included. Anything that looks like a credential is replaced before it
+// is written, and `RedactLevel::Names` also swaps court and player names for
+// placeholders that stay consistent across every file in the bundle.
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tracing::info;
+use zip::write::FileOptions;
+use zip::ZipWriter;
+
+use crate::commands::config::app_config;
+use crate::commands::court_data_sync::court_sync_diagnostics;
+use crate::commands::data_dir::{data_dir, is_portable};
+use crate::commands::disk_space::{disk_space_status, ensure_free_space};
+use crate::commands::live_data::{known_court_and_player_names, websocket_diagnostics};
+use crate::commands::logging::recent_log_files;
+use crate::commands::monitor::{current_monitors, monitor_for_window_rect, MonitorSimulationStore};
+use crate::error::AppError;
+
+const STATE_FILES: [&str; 6] = ["app_state.json", "canvas_state.json", "image_state.json",
+                                "video_state.json", "live_data_state.json", "scoreboard_state.json"];
+const LOG_FILES_INCLUDED: usize = 3;
+const REDACTED: &str = "[redacted]";
+// Key names (lowercased, without '_' and '-') whose values are always replaced
+const SECRET_KEY_SUFFIXES: &[&str] = &["token", "apikey", "password", "secret", "authorization"];
+// Shorter names are left alone, so "1" doesn't turn every digit into a placeholder
+const MIN_SCRUBBED_NAME_CHARS: usize = 3;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactLevel {
+    // Credentials only
+    #[default]
+    Secrets,
+    // Credentials, plus court and player names
+    Names,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsManifestEntry {
+    pub name: String,
+    pub bytes: u64,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsBundleSummary {
+    pub path: String,
+    pub bytes: u64,
+    pub redact_level: RedactLevel,
+    pub manifest: Vec<DiagnosticsManifestEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WindowLayout {
+    label: String,
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    visible: bool,
+    fullscreen: bool,
+    monitor_id: Option<u32>,
+}
+
+struct BundleFile {
+    name: String,
+    description: String,
+    content: String,
+}
+
+pub(crate) fn is_secret_key(key: &str) -> bool {
+    let key: String = key.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .collect::<String>()
+        .to_ascii_lowercase();
+    SECRET_KEY_SUFFIXES.iter().any(|suffix| key.ends_with(suffix))
+}
+
+// Replace the value of every `name=value` pair whose name looks like a credential,
+// which covers query strings in logged URLs and in configured endpoints
+pub(crate) fn redact_text_secrets(text: &str) -> String {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(eq) = rest.find('=') {
+        let (before, after) = (&rest[..eq], &rest[eq + 1..]);
+        out.push_str(before);
+        out.push('=');
+
+        let name_start = before.char_indices().rev()
+            .find(|(_, c)| !is_name_char(*c))
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        if !is_secret_key(&before[name_start..]) {
+            rest = after;
+            continue;
+        }
+        let end = after.find(|c: char| c.is_whitespace() || "&\"',;)}".contains(c)).unwrap_or(after.len());
+        if end > 0 {
+            out.push_str(REDACTED);
+        }
+        rest = &after[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn redact_json_secrets(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_secret_key(key) && !value.is_null() && value.as_str() != Some("") {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact_json_secrets(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json_secrets),
+        serde_json::Value::String(text) => *text = redact_text_secrets(text),
+        _ => {}
+    }
+}
+
+// Maps each name to a numbered placeholder; longest first so "Court 10" isn't
+// half-replaced by "Court 1"
+struct NameScrubber {
+    replacements: Vec<(String, String)>,
+}
+
+impl NameScrubber {
+    fn new(names: Vec<String>) -> Self {
+        let mut names: Vec<String> = names.into_iter()
+            .filter(|name| name.chars().count() >= MIN_SCRUBBED_NAME_CHARS)
+            .collect();
+        names.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        let replacements = names.into_iter()
+            .enumerate()
+            .map(|(index, name)| (name, format!("[name-{}]", index + 1)))
+            .collect();
+        Self { replacements }
+    }
+
+    fn scrub(&self, text: &str) -> String {
+        self.replacements.iter()
+            .fold(text.to_string(), |text, (name, placeholder)| text.replace(name.as_str(), placeholder))
+    }
+}
+
+fn json_file(name: &str, description: &str, mut value: serde_json::Value) -> Result<BundleFile, AppError> {
+    redact_json_secrets(&mut value);
+    Ok(BundleFile {
+        name: name.to_string(),
+        description: description.to_string(),
+        content: serde_json::to_string_pretty(&value)?,
+    })
+}
+
+fn window_layout(app: &AppHandle) -> Result<serde_json::Value, AppError> {
+    let monitors = current_monitors(app, &app.state::<MonitorSimulationStore>())?;
+    let mut windows: Vec<WindowLayout> = app.webview_windows()
+        .into_iter()
+        .map(|(label, window)| {
+            let position = window.outer_position()?;
+            let size = window.outer_size()?;
+            Ok(WindowLayout {
+                monitor_id: monitor_for_window_rect(&monitors, position.x, position.y, size.width, size.height).map(|m| m.id),
+                visible: window.is_visible().unwrap_or(false),
+                fullscreen: window.is_fullscreen().unwrap_or(false),
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+                label,
+            })
+        })
+        .collect::<Result<_, AppError>>()?;
+    windows.sort_by(|a, b| a.label.cmp(&b.label));
+    Ok(serde_json::json!({ "monitors": monitors, "windows": windows }))
+}
+
+fn system_info(app: &AppHandle) -> Result<serde_json::Value, AppError> {
+    let monitors = current_monitors(app, &app.state::<MonitorSimulationStore>())?;
+    Ok(serde_json::json!({
+        "generatedAt": chrono::Utc::now(),
+        "appVersion": app.package_info().version.to_string(),
+        "tauriVersion": tauri::VERSION,
+        "os": std::env::consts::OS,
+        "osFamily": std::env::consts::FAMILY,
+        "arch": std::env::consts::ARCH,
+        "portable": is_portable(),
+        "dataDirectory": data_dir(app)?,
+        "diskSpace": disk_space_status(app)?,
+        "monitors": monitors,
+    }))
+}
+
+// Everything except the async live data snapshots, read from disk and the window manager
+fn collect_local_files(app: &AppHandle, app_data_dir: &Path) -> Result<Vec<BundleFile>, AppError> {
+    let mut files = Vec::new();
+
+    for path in recent_log_files(app, LOG_FILES_INCLUDED).map_err(AppError::io)? {
+        let name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        files.push(BundleFile {
+            name: format!("logs/{}", name),
+            description: "Application log".to_string(),
+            content: redact_text_secrets(&String::from_utf8_lossy(&fs::read(&path)?)),
+        });
+    }
+
+    for state_file in STATE_FILES {
+        let path = app_data_dir.join(state_file);
+        if !path.exists() {
+            continue;
+        }
+        let content = fs::read_to_string(&path)?;
+        let file = match serde_json::from_str(&content) {
+            Ok(value) => json_file(&format!("state/{}", state_file), "Saved UI state", value)?,
+            // Kept as text so a corrupt state file, often the reason for the report, still arrives
+            Err(_) => BundleFile {
+                name: format!("state/{}", state_file),
+                description: "Saved UI state (not valid JSON)".to_string(),
+                content: redact_text_secrets(&content),
+            },
+        };
+        files.push(file);
+    }
+
+    files.push(json_file("config.json", "Application settings", serde_json::to_value(app_config())?)?);
+
+    let connections = app_data_dir.join("live_data").join("connections.json");
+    if connections.exists() {
+        let value = serde_json::from_str(&fs::read_to_string(&connections)?)?;
+        files.push(json_file("live_data/connections.json", "Live data connections and bindings, tokens removed", value)?);
+    }
+
+    files.push(json_file("windows.json", "Open windows, their positions and monitors", window_layout(app)?)?);
+    files.push(json_file("system.json", "OS, app version, monitors and disk space", system_info(app)?)?);
+    Ok(files)
+}
+
+fn write_bundle(target: &Path, files: &[BundleFile], manifest: &str) -> Result<(), AppError> {
+    // Written beside the target and renamed over it, so a failed export leaves no half-written ZIP
+    let temp_path = target.with_extension(format!("zip.{}.tmp", uuid::Uuid::new_v4()));
+    let result = (|| -> Result<(), AppError> {
+        let mut zip = ZipWriter::new(std::io::BufWriter::new(fs::File::create(&temp_path)?));
+        let options: FileOptions<'_, ()> = FileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .unix_permissions(0o644);
+        for file in files {
+            zip.start_file(file.name.as_str(), options)?;
+            zip.write_all(file.content.as_bytes())?;
+        }
+        zip.start_file("manifest.json", options)?;
+        zip.write_all(manifest.as_bytes())?;
+        zip.finish()?.flush()?;
+        Ok(())
+    })();
+
+    if let Err(e) = result {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e);
+    }
+    fs::rename(&temp_path, target)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn export_diagnostics_bundle(
+    app: AppHandle,
+    path: String,
+    redact_level: Option<RedactLevel>,
+) -> Result<DiagnosticsBundleSummary, AppError> {
+    let target = PathBuf::from(path);
+    let is_zip = target.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case("zip"));
+    if !is_zip || target.is_dir() {
+        return Err(AppError::validation("path", "Diagnostics bundle path must be a .zip file"));
+    }
+    let redact_level = redact_level.unwrap_or_default();
+
+    let (sync_status, sync_history) = court_sync_diagnostics().await;
+    let mut websocket = websocket_diagnostics().await;
+    let names = match redact_level {
+        RedactLevel::Names => known_court_and_player_names().await,
+        RedactLevel::Secrets => Vec::new(),
+    };
+    // Captured frames can hold player names that the text scrubber can't see in hex
+    if redact_level == RedactLevel::Names {
+        websocket.recent_parse_errors.iter_mut().for_each(|error| error.hex = None);
+    }
+
+    let app_data_dir = data_dir(&app)?;
+    let summary = tokio::task::spawn_blocking(move || -> Result<DiagnosticsBundleSummary, AppError> {
+        let mut files = collect_local_files(&app, &app_data_dir)?;
+        files.push(json_file(
+            "court_sync.json",
+            "Court sync status and recent sync attempts",
+            serde_json::json!({ "status": sync_status, "history": sync_history }),
+        )?);
+        files.push(json_file(
+            "websocket.json",
+            "WebSocket connections, message counts and recent parse errors",
+            serde_json::to_value(&websocket)?,
+        )?);
+
+        if redact_level == RedactLevel::Names {
+            let scrubber = NameScrubber::new(names);
+            for file in &mut files {
+                file.content = scrubber.scrub(&file.content);
+            }
+        }
+
+        let manifest: Vec<DiagnosticsManifestEntry> = files.iter()
+            .map(|file| DiagnosticsManifestEntry {
+                name: file.name.clone(),
+                bytes: file.content.len() as u64,
+                description: file.description.clone(),
+            })
+            .collect();
+        let manifest_json = serde_json::to_string_pretty(&serde_json::json!({
+            "redactLevel": redact_level,
+            "files": manifest,
+        }))?;
+
+        let total: u64 = manifest.iter().map(|entry| entry.bytes).sum::<u64>() + manifest_json.len() as u64;
+        if let Some(parent) = target.parent() {
+            ensure_free_space(parent, total)?;
+        }
+        write_bundle(&target, &files, &manifest_json)?;
+
+        info!("🩺 Wrote diagnostics bundle to {:?} ({} files)", target, manifest.len());
+        Ok(DiagnosticsBundleSummary {
+            path: target.to_string_lossy().to_string(),
+            bytes: fs::metadata(&target)?.len(),
+            redact_level,
+            manifest,
+        })
+    }).await??;
+
+    Ok(summary)
+}
@@ -0,0 +1,95 @@
+// src-tauri/src/commands/disk_space.rs
+//
+// Free-space checks for the data directory. Writers call `ensure_free_space`
+// with the size they're about to write, so a full disk fails up front with
+// `InsufficientSpace` instead of halfway through a file. A background check
+// emits `low_disk_space` whenever free space crosses the configured threshold,
+// so the UI can keep a warning up before it gets that far.
+use std::path::Path;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use tracing::{error, warn};
+
+use crate::commands::config::app_config;
+use crate::commands::data_dir::data_dir;
+use crate::commands::events::BackendEvent;
+use crate::error::AppError;
+
+// Kept free on top of whatever a write needs, so the OS and logs have room
+const SAFETY_MARGIN_BYTES: u64 = 100 * 1024 * 1024;
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskSpaceStatus {
+    // None when the platform can't report it
+    pub available_bytes: Option<u64>,
+    pub threshold_bytes: u64,
+    pub low: bool,
+}
+
+// Free space on the volume holding `path`, or the nearest existing parent
+pub(crate) fn available_space(path: &Path) -> Option<u64> {
+    let existing = path.ancestors().find(|ancestor| ancestor.exists())?;
+    fs2::available_space(existing).ok()
+}
+
+// Refuse a write of `needed` bytes under `path` that would eat into the safety margin.
+// If free space can't be read the write goes ahead, as it did before this check existed.
+pub(crate) fn ensure_free_space(path: &Path, needed: u64) -> Result<(), AppError> {
+    let Some(available) = available_space(path) else {
+        return Ok(());
+    };
+    if needed.saturating_add(SAFETY_MARGIN_BYTES) > available {
+        warn!("💽 Refusing to write {} bytes to {:?}: only {} available", needed, path, available);
+        return Err(AppError::insufficient_space(needed, available));
+    }
+    Ok(())
+}
+
+pub(crate) fn disk_space_status(app: &AppHandle) -> Result<DiskSpaceStatus, AppError> {
+    let available_bytes = available_space(&data_dir(app)?);
+    let threshold_bytes = app_config().low_disk_space_threshold_bytes;
+    Ok(DiskSpaceStatus {
+        available_bytes,
+        threshold_bytes,
+        low: available_bytes.is_some_and(|available| available < threshold_bytes),
+    })
+}
+
+// Emits on every change between low and not low, and once at startup if already low
+pub fn start_disk_space_monitor(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut was_low = false;
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            let status = match disk_space_status(&app) {
+                Ok(status) => status,
+                Err(e) => {
+                    warn!("Cannot check free disk space: {}", e);
+                    continue;
+                }
+            };
+            if status.low == was_low {
+                continue;
+            }
+            was_low = status.low;
+
+            if status.low {
+                warn!("💽 Low disk space: {:?} bytes free (threshold {})", status.available_bytes, status.threshold_bytes);
+            }
+            if let Err(e) = app.emit(BackendEvent::LowDiskSpace.as_str(), &status) {
+                error!("Failed to emit {}: {}", BackendEvent::LowDiskSpace, e);
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_disk_space_status(app: AppHandle) -> Result<DiskSpaceStatus, AppError> {
+    disk_space_status(&app)
+}
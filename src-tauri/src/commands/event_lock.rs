@@ -0,0 +1,189 @@
+// src-tauri/src/commands/event_lock.rs
+//
+// Event lock: a read-only mode for the show machine once doors open. While it
+// is on, commands that edit boards, components or the media library fail with
+// `LockedByEventMode`; scores, clocks and live data keep working. The lock is
+// held like the app config, in a global the commands can check without an
+// AppHandle, and saved to disk so a restart mid-event comes back locked.
+// Every enable and disable attempt goes to an append-only audit log.
+use std::io::Write;
+use std::sync::RwLock;
+
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::AppHandle;
+use tracing::{info, warn};
+
+use crate::commands::data_dir::data_dir;
+use crate::commands::media_library::write_atomic;
+use crate::error::AppError;
+
+const LOCK_FILE: &str = "event_lock.json";
+const AUDIT_FILE: &str = "event_lock_audit.jsonl";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StoredEventLock {
+    enabled: bool,
+    enabled_at: Option<DateTime<Utc>>,
+    // The PIN itself is never stored, only a salted hash
+    pin_salt: Option<String>,
+    pin_hash: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventLockStatus {
+    pub enabled: bool,
+    pub enabled_at: Option<DateTime<Utc>>,
+    pub pin_required: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EventLockAuditEntry {
+    at: DateTime<Utc>,
+    // "enable" or "disable"
+    action: String,
+    succeeded: bool,
+    message: Option<String>,
+}
+
+lazy_static! {
+    static ref EVENT_LOCK: RwLock<StoredEventLock> = RwLock::new(StoredEventLock::default());
+}
+
+// Called at the top of every editing command; `action` names it in the error.
+// A poisoned lock fails the command rather than letting edits through.
+pub fn ensure_unlocked(action: &str) -> Result<(), AppError> {
+    if EVENT_LOCK.read()?.enabled {
+        return Err(AppError::locked_by_event_mode(action));
+    }
+    Ok(())
+}
+
+fn status_of(lock: &StoredEventLock) -> EventLockStatus {
+    EventLockStatus {
+        enabled: lock.enabled,
+        enabled_at: lock.enabled_at,
+        pin_required: lock.pin_hash.is_some(),
+    }
+}
+
+fn hash_pin(salt: &str, pin: &str) -> String {
+    let digest = Sha256::digest(format!("{}:{}", salt, pin).as_bytes());
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn append_audit_entry(app: &AppHandle, action: &str, result: &Result<EventLockStatus, AppError>) {
+    let entry = EventLockAuditEntry {
+        at: Utc::now(),
+        action: action.to_string(),
+        succeeded: result.is_ok(),
+        message: result.as_ref().err().map(|e| e.to_string()),
+    };
+    let written = (|| -> Result<(), AppError> {
+        let line = serde_json::to_string(&entry)?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(data_dir(app)?.join(AUDIT_FILE))?;
+        writeln!(file, "{}", line)?;
+        Ok(())
+    })();
+    if let Err(e) = written {
+        warn!("Failed to write event lock audit log: {}", e);
+    }
+}
+
+async fn save_lock(app: &AppHandle, lock: &StoredEventLock) -> Result<(), AppError> {
+    write_atomic(&data_dir(app)?.join(LOCK_FILE), &serde_json::to_vec_pretty(lock)?).await
+}
+
+// Restores the lock saved by a previous run; an unreadable file leaves the app unlocked
+pub fn load_event_lock(app: &AppHandle) {
+    let path = match data_dir(app) {
+        Ok(dir) => dir.join(LOCK_FILE),
+        Err(e) => {
+            warn!("Could not restore event lock: {}", e);
+            return;
+        }
+    };
+    let Ok(json) = std::fs::read_to_string(&path) else {
+        return;
+    };
+    match serde_json::from_str::<StoredEventLock>(&json) {
+        Ok(lock) => {
+            if lock.enabled {
+                info!("🔒 Event lock restored: editing is disabled");
+            }
+            if let Ok(mut current) = EVENT_LOCK.write() {
+                *current = lock;
+            }
+        }
+        Err(e) => warn!("Ignoring unreadable {}: {}", LOCK_FILE, e),
+    }
+}
+
+async fn enable(app: &AppHandle, pin: Option<String>) -> Result<EventLockStatus, AppError> {
+    let current = EVENT_LOCK.read()?.clone();
+    if current.enabled {
+        return Err(AppError::conflict("The event lock is already on"));
+    }
+
+    let pin = pin.map(|pin| pin.trim().to_string()).filter(|pin| !pin.is_empty());
+    let salt = pin.as_ref().map(|_| uuid::Uuid::new_v4().to_string());
+    let lock = StoredEventLock {
+        enabled: true,
+        enabled_at: Some(Utc::now()),
+        pin_hash: pin.as_deref().zip(salt.as_deref()).map(|(pin, salt)| hash_pin(salt, pin)),
+        pin_salt: salt,
+    };
+    save_lock(app, &lock).await?;
+    let status = status_of(&lock);
+    *EVENT_LOCK.write()? = lock;
+    info!("🔒 Event lock enabled{}", if status.pin_required { " with PIN" } else { "" });
+    Ok(status)
+}
+
+async fn disable(app: &AppHandle, pin: Option<String>) -> Result<EventLockStatus, AppError> {
+    let current = EVENT_LOCK.read()?.clone();
+    if !current.enabled {
+        return Ok(status_of(&current));
+    }
+
+    if let (Some(salt), Some(hash)) = (&current.pin_salt, &current.pin_hash) {
+        let given = pin.as_deref().map(str::trim).unwrap_or("");
+        if hash_pin(salt, given) != *hash {
+            return Err(AppError::validation("pin", "Incorrect PIN"));
+        }
+    }
+
+    let lock = StoredEventLock::default();
+    save_lock(app, &lock).await?;
+    *EVENT_LOCK.write()? = lock.clone();
+    info!("🔓 Event lock disabled");
+    Ok(status_of(&lock))
+}
+
+// Disables editing until `disable_event_lock` is called, with the PIN if one is given here
+#[tauri::command]
+pub async fn enable_event_lock(app: AppHandle, pin: Option<String>) -> Result<EventLockStatus, AppError> {
+    let result = enable(&app, pin).await;
+    append_audit_entry(&app, "enable", &result);
+    result
+}
+
+#[tauri::command]
+pub async fn disable_event_lock(app: AppHandle, pin: Option<String>) -> Result<EventLockStatus, AppError> {
+    let result = disable(&app, pin).await;
+    append_audit_entry(&app, "disable", &result);
+    result
+}
+
+#[tauri::command]
+pub async fn get_event_lock_status() -> Result<EventLockStatus, AppError> {
+    Ok(status_of(&*EVENT_LOCK.read()?))
+}
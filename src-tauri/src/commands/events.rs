@@ -0,0 +1,206 @@
+// src-tauri/src/commands/events.rs
+use serde::{Deserialize, Serialize};
+use tauri::command;
+
+// Every event the backend emits. The string values are what the frontend
+// subscribes to, so they must never change once released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackendEvent {
+    GameStateUpdated,
+    ScoreUpdated,
+    TimeUpdated,
+    PeriodUpdated,
+    GameActiveToggled,
+    GameReset,
+    TeamInfoUpdated,
+    CourtSyncRestarted,
+    WebsocketDisconnected,
+    SerialOutputError,
+    BindingsResolved,
+    LaunchCompleted,
+    DeepLinkReceived,
+    DeepLinkHandled,
+    DeepLinkFailed,
+    UpdateStatusChanged,
+    UpdateProgress,
+    ScheduledActionExecuted,
+    ScoreboardAutoImported,
+    DataDirectoryProgress,
+    LowDiskSpace,
+    IntegrityCheckProgress,
+    ZipExportProgress,
+    FirstRunSeeded,
+    MatchElapsed,
+    ComponentVisibilityChanged,
+    ConnectionFailed,
+    UnsavedChangesOnClose,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendEventInfo {
+    pub name: String,
+    pub payload: String,
+}
+
+impl BackendEvent {
+    pub const ALL: &'static [BackendEvent] = &[
+        BackendEvent::GameStateUpdated,
+        BackendEvent::ScoreUpdated,
+        BackendEvent::TimeUpdated,
+        BackendEvent::PeriodUpdated,
+        BackendEvent::GameActiveToggled,
+        BackendEvent::GameReset,
+        BackendEvent::TeamInfoUpdated,
+        BackendEvent::CourtSyncRestarted,
+        BackendEvent::WebsocketDisconnected,
+        BackendEvent::SerialOutputError,
+        BackendEvent::BindingsResolved,
+        BackendEvent::LaunchCompleted,
+        BackendEvent::DeepLinkReceived,
+        BackendEvent::DeepLinkHandled,
+        BackendEvent::DeepLinkFailed,
+        BackendEvent::UpdateStatusChanged,
+        BackendEvent::UpdateProgress,
+        BackendEvent::ScheduledActionExecuted,
+        BackendEvent::ScoreboardAutoImported,
+        BackendEvent::DataDirectoryProgress,
+        BackendEvent::LowDiskSpace,
+        BackendEvent::IntegrityCheckProgress,
+        BackendEvent::ZipExportProgress,
+        BackendEvent::FirstRunSeeded,
+        BackendEvent::MatchElapsed,
+        BackendEvent::ComponentVisibilityChanged,
+        BackendEvent::ConnectionFailed,
+        BackendEvent::UnsavedChangesOnClose,
+    ];
+
+    // Events whose payload is the full GameState after a change
+    pub const GAME_STATE_CHANGES: &'static [BackendEvent] = &[
+        BackendEvent::GameStateUpdated,
+        BackendEvent::ScoreUpdated,
+        BackendEvent::TimeUpdated,
+        BackendEvent::PeriodUpdated,
+        BackendEvent::GameActiveToggled,
+        BackendEvent::GameReset,
+        BackendEvent::TeamInfoUpdated,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BackendEvent::GameStateUpdated => "game_state_updated",
+            BackendEvent::ScoreUpdated => "score_updated",
+            BackendEvent::TimeUpdated => "time_updated",
+            BackendEvent::PeriodUpdated => "period_updated",
+            BackendEvent::GameActiveToggled => "game_active_toggled",
+            BackendEvent::GameReset => "game_reset",
+            BackendEvent::TeamInfoUpdated => "team_info_updated",
+            BackendEvent::CourtSyncRestarted => "court_sync_restarted",
+            BackendEvent::WebsocketDisconnected => "websocket_disconnected",
+            BackendEvent::SerialOutputError => "serial_output_error",
+            BackendEvent::BindingsResolved => "bindings_resolved",
+            BackendEvent::LaunchCompleted => "launch_completed",
+            BackendEvent::DeepLinkReceived => "deep_link_received",
+            BackendEvent::DeepLinkHandled => "deep_link_handled",
+            BackendEvent::DeepLinkFailed => "deep_link_failed",
+            BackendEvent::UpdateStatusChanged => "update_status_changed",
+            BackendEvent::UpdateProgress => "update_progress",
+            BackendEvent::ScheduledActionExecuted => "scheduled_action_executed",
+            BackendEvent::ScoreboardAutoImported => "scoreboard_auto_imported",
+            BackendEvent::DataDirectoryProgress => "data_directory_progress",
+            BackendEvent::LowDiskSpace => "low_disk_space",
+            BackendEvent::IntegrityCheckProgress => "integrity_check_progress",
+            BackendEvent::ZipExportProgress => "zip_export_progress",
+            BackendEvent::FirstRunSeeded => "first_run_seeded",
+            BackendEvent::MatchElapsed => "match_elapsed",
+            BackendEvent::ComponentVisibilityChanged => "component_visibility_changed",
+            BackendEvent::ConnectionFailed => "connection_failed",
+            BackendEvent::UnsavedChangesOnClose => "unsaved_changes_on_close",
+        }
+    }
+
+    pub fn payload_description(&self) -> &'static str {
+        match self {
+            BackendEvent::GameStateUpdated
+            | BackendEvent::ScoreUpdated
+            | BackendEvent::TimeUpdated
+            | BackendEvent::PeriodUpdated
+            | BackendEvent::GameActiveToggled
+            | BackendEvent::GameReset
+            | BackendEvent::TeamInfoUpdated => "GameState",
+            BackendEvent::CourtSyncRestarted => "CourtSyncRestartedEvent",
+            BackendEvent::WebsocketDisconnected => "WebSocketCloseRecord",
+            BackendEvent::SerialOutputError => "SerialOutputErrorEvent",
+            BackendEvent::BindingsResolved => "BindingsResolvedEvent",
+            BackendEvent::LaunchCompleted => "LaunchReport",
+            BackendEvent::DeepLinkReceived => "DeepLinkRequest",
+            BackendEvent::DeepLinkHandled => "DeepLinkResult",
+            BackendEvent::DeepLinkFailed => "DeepLinkError",
+            BackendEvent::UpdateStatusChanged => "UpdateStatus",
+            BackendEvent::UpdateProgress => "UpdateProgressEvent",
+            BackendEvent::ScheduledActionExecuted => "ScheduledActionExecution",
+            BackendEvent::ScoreboardAutoImported => "AutoImportEvent",
+            BackendEvent::DataDirectoryProgress => "DataDirectoryProgressEvent",
+            BackendEvent::LowDiskSpace => "DiskSpaceStatus",
+            BackendEvent::IntegrityCheckProgress => "IntegrityProgressEvent",
+            BackendEvent::ZipExportProgress => "ZipExportProgressEvent",
+            BackendEvent::FirstRunSeeded => "SampleContentReport",
+            BackendEvent::MatchElapsed => "MatchElapsedEvent",
+            BackendEvent::ComponentVisibilityChanged => "ComponentVisibilityEvent",
+            BackendEvent::ConnectionFailed => "WebSocketConnectionFailed",
+            BackendEvent::UnsavedChangesOnClose => "UnsavedChanges",
+        }
+    }
+}
+
+impl std::fmt::Display for BackendEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[command]
+pub async fn list_backend_events() -> Result<Vec<BackendEventInfo>, String> {
+    Ok(BackendEvent::ALL.iter().map(|event| BackendEventInfo {
+        name: event.as_str().to_string(),
+        payload: event.payload_description().to_string(),
+    }).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_match_the_historically_emitted_strings() {
+        // Emitted as string literals before this enum existed; the frontend still listens for them
+        let historical = [
+            (BackendEvent::GameStateUpdated, "game_state_updated"),
+            (BackendEvent::ScoreUpdated, "score_updated"),
+            (BackendEvent::TimeUpdated, "time_updated"),
+            (BackendEvent::PeriodUpdated, "period_updated"),
+            (BackendEvent::GameActiveToggled, "game_active_toggled"),
+            (BackendEvent::GameReset, "game_reset"),
+            (BackendEvent::TeamInfoUpdated, "team_info_updated"),
+            (BackendEvent::CourtSyncRestarted, "court_sync_restarted"),
+            (BackendEvent::WebsocketDisconnected, "websocket_disconnected"),
+            (BackendEvent::SerialOutputError, "serial_output_error"),
+        ];
+        for (event, name) in historical {
+            assert_eq!(event.as_str(), name);
+        }
+    }
+
+    #[test]
+    fn serde_names_match_as_str() {
+        for event in BackendEvent::ALL {
+            assert_eq!(serde_json::to_value(event).unwrap(), event.as_str());
+        }
+    }
+
+    #[test]
+    fn all_lists_every_event_once() {
+        let unique: std::collections::HashSet<&str> = BackendEvent::ALL.iter().map(|event| event.as_str()).collect();
+        assert_eq!(unique.len(), BackendEvent::ALL.len());
+    }
+}
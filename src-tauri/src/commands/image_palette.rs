@@ -0,0 +1,121 @@
+// src-tauri/src/commands/image_palette.rs
+//
+// Dominant colors of an uploaded image, suggested to designers when picking
+// team colors to match a logo. Colors come from median-cut quantization over
+// a downscaled copy of the decoded pixels.
+use crate::error::AppError;
+
+pub const DEFAULT_PALETTE_SIZE: usize = 5;
+pub const MAX_PALETTE_SIZE: usize = 16;
+
+// Images are shrunk to fit this before quantizing; more pixels barely move the result
+const SAMPLE_DIMENSION: u32 = 128;
+
+// Pixels less opaque than this are background, not logo
+const MIN_ALPHA: u8 = 128;
+
+fn channel_range(pixels: &[[u8; 3]], channel: usize) -> u8 {
+    let (min, max) = pixels.iter().fold((u8::MAX, u8::MIN), |(min, max), p| (min.min(p[channel]), max.max(p[channel])));
+    max.saturating_sub(min)
+}
+
+// The channel with the widest spread, and that spread
+fn widest_channel(pixels: &[[u8; 3]]) -> (usize, u8) {
+    (0..3)
+        .map(|channel| (channel, channel_range(pixels, channel)))
+        .max_by_key(|(_, range)| *range)
+        .unwrap_or((0, 0))
+}
+
+fn average(pixels: &[[u8; 3]]) -> [u8; 3] {
+    let mut sums = [0u64; 3];
+    for pixel in pixels {
+        for channel in 0..3 {
+            sums[channel] += pixel[channel] as u64;
+        }
+    }
+    let count = pixels.len().max(1) as u64;
+    sums.map(|sum| (sum / count) as u8)
+}
+
+// Median cut: keep splitting the box with the widest channel range until
+// there are `count` boxes or none can be split. Boxes are split at the middle
+// of the range rather than at the median pixel, so a color covering most of a
+// logo isn't averaged with its neighbour. Returns the average color of each
+// box with its pixel count, most common first.
+pub fn median_cut(mut pixels: Vec<[u8; 3]>, count: usize) -> Vec<([u8; 3], usize)> {
+    if pixels.is_empty() || count == 0 {
+        return Vec::new();
+    }
+
+    let mut boxes = vec![pixels.as_mut_slice()];
+    while boxes.len() < count {
+        let Some((index, channel, range)) = boxes.iter()
+            .enumerate()
+            .map(|(index, b)| {
+                let (channel, range) = widest_channel(b);
+                (index, channel, range)
+            })
+            .filter(|(_, _, range)| *range > 0)
+            .max_by_key(|(_, _, range)| *range)
+        else {
+            break;
+        };
+
+        let target = boxes.swap_remove(index);
+        target.sort_unstable_by_key(|p| p[channel]);
+        // Both halves are non-empty since the range is non-zero
+        let split_value = target[0][channel] + range / 2;
+        let split = target.partition_point(|p| p[channel] <= split_value);
+        let (low, high) = target.split_at_mut(split);
+        boxes.push(low);
+        boxes.push(high);
+    }
+
+    let mut colors: Vec<([u8; 3], usize)> = boxes.iter().map(|b| (average(b), b.len())).collect();
+    colors.sort_by_key(|(_, pixel_count)| std::cmp::Reverse(*pixel_count));
+    colors
+}
+
+pub fn to_hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+// Up to `count` dominant colors of the encoded image in `data`, as hex strings
+pub fn extract_palette(data: &[u8], count: usize) -> Result<Vec<String>, AppError> {
+    let decoded = image::load_from_memory(data)
+        .map_err(|e| AppError::validation("image", format!("Failed to decode image: {}", e)))?;
+    let sample = decoded.thumbnail(SAMPLE_DIMENSION, SAMPLE_DIMENSION).to_rgba8();
+
+    let pixels: Vec<[u8; 3]> = sample.pixels()
+        .filter(|p| p[3] >= MIN_ALPHA)
+        .map(|p| [p[0], p[1], p[2]])
+        .collect();
+
+    let mut palette: Vec<String> = Vec::new();
+    for (color, _) in median_cut(pixels, count) {
+        let hex = to_hex(color);
+        if !palette.contains(&hex) {
+            palette.push(hex);
+        }
+    }
+    Ok(palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32, pixel: impl Fn(u32, u32) -> [u8; 4]) -> Vec<u8> {
+        let img = image::RgbaImage::from_fn(width, height, |x, y| image::Rgba(pixel(x, y)));
+        let mut data = Vec::new();
+        img.write_to(&mut std::io::Cursor::new(&mut data), image::ImageFormat::Png).unwrap();
+        data
+    }
+
+    #[test]
+    fn solid_image_yields_its_one_color() {
+        let data = png(32, 32, |_, _| [0x1e, 0x90, 0xff, 255]);
+        assert_eq!(extract_palette(&data, 5).unwrap(), vec!["#1e90ff"]);
+    }
+}
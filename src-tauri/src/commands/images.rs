@@ -1,12 +1,26 @@
 // src-tauri/src/commands/images.rs
-use std::path::PathBuf;
-use std::fs;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use futures_util::{stream, StreamExt};
+use tokio::fs;
 use tauri::{AppHandle, Manager, command};
 use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
 use uuid::Uuid;
 use base64::{Engine as _, engine::general_purpose};
+use tracing::{info, warn};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+use crate::commands::image_palette::{extract_palette, DEFAULT_PALETTE_SIZE, MAX_PALETTE_SIZE};
+use crate::commands::media_library::{read_metadata_file, update_metadata_file, MediaLibraryLocks};
+use crate::commands::media_sniff::{detect_mime, sanitize_svg, verify_media_type, ALLOWED_IMAGE_TYPES};
+use crate::commands::storage::safe_join;
+use crate::commands::upload_limits::{upload_limits, validate_base64_payload, verify_decoded_size};
+use crate::commands::data_dir::data_dir;
+use crate::commands::disk_space::ensure_free_space;
+use crate::commands::event_lock::ensure_unlocked;
+use crate::error::AppError;
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct StoredImage {
     pub id: String,
@@ -17,53 +31,109 @@ pub struct StoredImage {
     pub r#type: String,
     pub uploaded_at: chrono::DateTime<chrono::Utc>,
     pub thumbnail: Option<String>,
+    // Pixel size; None for SVGs, files that won't decode, and entries from before it was recorded
+    #[serde(default)]
+    pub width: Option<u32>,
+    #[serde(default)]
+    pub height: Option<u32>,
+    // Dominant colors, cached from the last extraction; None for SVGs and older uploads
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub palette: Option<ImagePalette>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImagePalette {
+    // The count the colors were extracted for; a flat image yields fewer colors than asked
+    pub count: usize,
+    pub colors: Vec<String>,
+}
+
+// Prefix for fields that point at the image store rather than a URL, e.g. a team's logo_url
+pub const STORED_IMAGE_SCHEME: &str = "stored-image:";
+
+pub fn stored_image_ref(image_id: &str) -> String {
+    format!("{}{}", STORED_IMAGE_SCHEME, image_id)
 }
 
-fn get_images_dir(app: &AppHandle) -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let app_data_dir = app.path().app_data_dir()?;
+pub(crate) async fn get_images_dir(app: &AppHandle) -> Result<PathBuf, AppError> {
+    let app_data_dir = data_dir(app)?;
     let images_dir = app_data_dir.join("images");
     
-    if !images_dir.exists() {
-        fs::create_dir_all(&images_dir)?;
-    }
+    fs::create_dir_all(&images_dir).await?;
     
     Ok(images_dir)
 }
 
-fn get_metadata_file(app: &AppHandle) -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let images_dir = get_images_dir(app)?;
+async fn get_metadata_file(app: &AppHandle) -> Result<PathBuf, AppError> {
+    let images_dir = get_images_dir(app).await?;
     Ok(images_dir.join("metadata.json"))
 }
 
-fn load_image_metadata(app: &AppHandle) -> Result<Vec<StoredImage>, Box<dyn std::error::Error>> {
-    let metadata_file = get_metadata_file(app)?;
-    
-    if !metadata_file.exists() {
-        return Ok(Vec::new());
-    }
-    
-    let content = fs::read_to_string(metadata_file)?;
-    let images: Vec<StoredImage> = serde_json::from_str(&content)?;
-    Ok(images)
+pub(crate) async fn load_image_metadata(app: &AppHandle) -> Result<Vec<StoredImage>, AppError> {
+    read_metadata_file(&get_metadata_file(app).await?).await
 }
 
-fn save_image_metadata(app: &AppHandle, images: &[StoredImage]) -> Result<(), Box<dyn std::error::Error>> {
-    let metadata_file = get_metadata_file(app)?;
-    let content = serde_json::to_string_pretty(images)?;
-    fs::write(metadata_file, content)?;
-    Ok(())
+// Every change to metadata.json goes through here so concurrent uploads and deletes can't drop entries
+async fn update_image_metadata<R>(
+    app: &AppHandle,
+    mutate: impl FnOnce(&mut Vec<StoredImage>) -> Result<R, AppError>,
+) -> Result<R, AppError> {
+    let metadata_file = get_metadata_file(app).await?;
+    let locks = app.state::<MediaLibraryLocks>();
+    update_metadata_file(&locks.images, &metadata_file, mutate).await
+}
+
+// Longest side of a stored thumbnail, in pixels
+const THUMBNAIL_DIMENSION: u32 = 256;
+
+// What upload reads from the decoded image; all None when it won't decode
+#[derive(Debug, Clone, Default)]
+struct ImagePreview {
+    thumbnail: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+}
+
+// Decodes once for both the pixel size and the thumbnail. Formats the image
+// crate can't decode (SVG included) get an empty preview; GIFs use their first frame.
+fn create_preview(image_data: &[u8]) -> ImagePreview {
+    let decoded = match image::load_from_memory(image_data) {
+        Ok(decoded) => decoded,
+        Err(e) => {
+            warn!("⚠️ Could not decode image for thumbnail: {}", e);
+            return ImagePreview::default();
+        }
+    };
+    ImagePreview {
+        width: Some(decoded.width()),
+        height: Some(decoded.height()),
+        thumbnail: create_thumbnail(decoded),
+    }
 }
 
-fn create_thumbnail(image_data: &[u8], _image_type: &str) -> Option<String> {
-    // For now, just return the first 1000 characters of base64 as a simple thumbnail
-    // In a real implementation, you'd want to use an image processing library
-    // to create actual thumbnails
-    if image_data.len() > 1000 {
-        let thumbnail_data = &image_data[..1000];
-        Some(general_purpose::STANDARD.encode(thumbnail_data))
+// A PNG data URL of the image scaled to fit THUMBNAIL_DIMENSION
+fn create_thumbnail(decoded: image::DynamicImage) -> Option<String> {
+    // Smaller images are kept at their own size rather than blown up
+    let thumbnail = if decoded.width() > THUMBNAIL_DIMENSION || decoded.height() > THUMBNAIL_DIMENSION {
+        decoded.thumbnail(THUMBNAIL_DIMENSION, THUMBNAIL_DIMENSION)
     } else {
-        Some(general_purpose::STANDARD.encode(image_data))
+        decoded
+    };
+
+    let mut png = std::io::Cursor::new(Vec::new());
+    if let Err(e) = thumbnail.write_to(&mut png, image::ImageFormat::Png) {
+        warn!("⚠️ Could not encode thumbnail: {}", e);
+        return None;
+    }
+    Some(format!("data:image/png;base64,{}", general_purpose::STANDARD.encode(png.into_inner())))
+}
+
+async fn preview_for(image_data: Vec<u8>, file_type: &str) -> ImagePreview {
+    if file_type == "image/svg+xml" {
+        return ImagePreview::default();
     }
+    tokio::task::spawn_blocking(move || create_preview(&image_data)).await.unwrap_or_default()
 }
 
 #[command]
@@ -73,28 +143,63 @@ pub async fn upload_image(
     file_data: String,
     file_type: String,
     file_size: u64,
-) -> Result<StoredImage, String> {
+) -> Result<StoredImage, AppError> {
+    ensure_unlocked("Uploading images")?;
+    // Reject oversized or mismatched payloads before decoding
+    validate_base64_payload(&file_data, file_size, upload_limits().max_image_bytes)?;
+    
     // Decode base64 data
     let image_data = general_purpose::STANDARD
         .decode(&file_data)
-        .map_err(|e| format!("Failed to decode image data: {}", e))?;
+        .map_err(|e| AppError::validation("file_data", format!("Failed to decode image data: {}", e)))?;
+    verify_decoded_size(image_data.len(), file_size)?;
+    
+    store_image(&app, file_name, file_type, &image_data).await
+}
+
+// Write image bytes into the store and record them in the metadata file
+async fn store_image(app: &AppHandle, file_name: String, file_type: String, image_data: &[u8]) -> Result<StoredImage, AppError> {
+    store_image_with_id(app, Uuid::new_v4().to_string(), file_name, file_type, image_data).await
+}
+
+// As store_image, under a caller-chosen id (the bundled sample images use fixed ids
+// so the sample boards can reference them)
+pub(crate) async fn store_image_with_id(
+    app: &AppHandle,
+    id: String,
+    file_name: String,
+    file_type: String,
+    image_data: &[u8],
+) -> Result<StoredImage, AppError> {
+    // The stored type and extension come from the content, not the client's claim
+    let file_type = verify_media_type(image_data, &file_type, &ALLOWED_IMAGE_TYPES)?.to_string();
+    let sanitized_svg = (file_type == "image/svg+xml")
+        .then(|| sanitize_svg(&String::from_utf8_lossy(image_data)).into_bytes());
+    let image_data = sanitized_svg.as_deref().unwrap_or(image_data);
     
-    // Generate unique ID and filename
-    let id = Uuid::new_v4().to_string();
-    let file_extension = file_name.split('.').last().unwrap_or("png");
-    let stored_filename = format!("{}.{}", id, file_extension);
+    let stored_filename = format!("{}.{}", id, extension_for_mime(&file_type));
     
     // Get images directory
-    let images_dir = get_images_dir(&app)
-        .map_err(|e| format!("Failed to get images directory: {}", e))?;
+    let images_dir = get_images_dir(app).await
+        .map_err(|e| AppError::io(format!("Failed to get images directory: {}", e)))?;
     
     // Save image file
+    ensure_free_space(&images_dir, image_data.len() as u64)?;
     let file_path = images_dir.join(&stored_filename);
-    fs::write(&file_path, &image_data)
-        .map_err(|e| format!("Failed to save image file: {}", e))?;
+    fs::write(&file_path, image_data).await
+        .map_err(|e| AppError::io(format!("Failed to save image file: {}", e)))?;
     
-    // Create thumbnail
-    let thumbnail = create_thumbnail(&image_data, &file_type);
+    // Create thumbnail and read the pixel size
+    let preview = preview_for(image_data.to_vec(), &file_type).await;
+    
+    // A palette is a suggestion; an image that won't decode is still stored
+    let palette = match palette_for(image_data.to_vec(), &file_type, DEFAULT_PALETTE_SIZE).await {
+        Ok(palette) => palette,
+        Err(e) => {
+            warn!("⚠️ Could not extract a palette from {}: {}", file_name, e);
+            None
+        }
+    };
     
     // Create metadata entry
     let stored_image = StoredImage {
@@ -102,75 +207,440 @@ pub async fn upload_image(
         name: stored_filename.clone(),
         original_name: file_name,
         path: file_path.to_string_lossy().to_string(),
-        size: file_size,
+        size: image_data.len() as u64,
         r#type: file_type,
         uploaded_at: chrono::Utc::now(),
-        thumbnail,
+        thumbnail: preview.thumbnail,
+        width: preview.width,
+        height: preview.height,
+        palette,
     };
     
-    // Load existing metadata
-    let mut images = load_image_metadata(&app)
-        .map_err(|e| format!("Failed to load metadata: {}", e))?;
-    
     // Add new image
-    images.push(stored_image.clone());
-    
-    // Save updated metadata
-    save_image_metadata(&app, &images)
-        .map_err(|e| format!("Failed to save metadata: {}", e))?;
+    update_image_metadata(app, |images| {
+        images.push(stored_image.clone());
+        Ok(())
+    }).await?;
     
     Ok(stored_image)
 }
 
+// Vector images have no pixels to quantize, so they get no palette
+async fn palette_for(image_data: Vec<u8>, file_type: &str, count: usize) -> Result<Option<ImagePalette>, AppError> {
+    if file_type == "image/svg+xml" {
+        return Ok(None);
+    }
+    let colors = tokio::task::spawn_blocking(move || extract_palette(&image_data, count)).await??;
+    Ok(Some(ImagePalette { count, colors }))
+}
+
+#[command]
+pub async fn extract_image_palette(app: AppHandle, image_id: String, count: Option<usize>) -> Result<Vec<String>, AppError> {
+    let count = count.unwrap_or(DEFAULT_PALETTE_SIZE);
+    if count == 0 || count > MAX_PALETTE_SIZE {
+        return Err(AppError::validation("count", format!("Palette size must be between 1 and {}", MAX_PALETTE_SIZE)));
+    }
+    
+    let images = load_image_metadata(&app).await
+        .map_err(|e| AppError::io(format!("Failed to load metadata: {}", e)))?;
+    let image = images.iter()
+        .find(|image| image.id == image_id)
+        .ok_or_else(|| AppError::not_found("Image"))?;
+    
+    if let Some(palette) = image.palette.as_ref().filter(|palette| palette.count == count) {
+        return Ok(palette.colors.clone());
+    }
+    
+    let image_path = safe_join(&get_images_dir(&app).await?, &image.name)?;
+    let image_data = fs::read(&image_path).await
+        .map_err(|e| AppError::io(format!("Failed to read image file: {}", e)))?;
+    let palette = palette_for(image_data, &image.r#type, count).await?
+        .ok_or_else(|| AppError::validation("image", "Palettes can't be extracted from SVG images"))?;
+    
+    let colors = palette.colors.clone();
+    update_image_metadata(&app, |images| {
+        // The image may have been deleted while we were decoding it
+        if let Some(image) = images.iter_mut().find(|image| image.id == image_id) {
+            image.palette = Some(palette);
+        }
+        Ok(())
+    }).await?;
+    
+    Ok(colors)
+}
+
 #[command]
-pub async fn get_stored_images(app: AppHandle) -> Result<Vec<StoredImage>, String> {
-    load_image_metadata(&app)
-        .map_err(|e| format!("Failed to load images: {}", e))
+pub async fn get_stored_images(app: AppHandle) -> Result<Vec<StoredImage>, AppError> {
+    load_image_metadata(&app).await
+        .map_err(|e| AppError::io(format!("Failed to load images: {}", e)))
 }
 
 #[command]
-pub async fn delete_image(app: AppHandle, image_id: String) -> Result<(), String> {
-    // Load existing metadata
-    let mut images = load_image_metadata(&app)
-        .map_err(|e| format!("Failed to load metadata: {}", e))?;
+pub async fn delete_image(app: AppHandle, image_id: String) -> Result<(), AppError> {
+    ensure_unlocked("Deleting images")?;
+    let images_dir = get_images_dir(&app).await?;
     
-    // Find the image to delete
-    let image_index = images.iter()
-        .position(|img| img.id == image_id)
-        .ok_or("Image not found")?;
+    // Remove from metadata first; a leftover file is harmless, an entry without one isn't
+    let image = update_image_metadata(&app, |images| {
+        let image_index = images.iter()
+            .position(|img| img.id == image_id)
+            .ok_or_else(|| AppError::not_found("Image"))?;
+        Ok(images.remove(image_index))
+    }).await?;
     
-    let image = &images[image_index];
+    // Resolve from the stored name so an edited metadata file can't point the delete elsewhere
+    let image_path = safe_join(&images_dir, &image.name)?;
     
     // Delete the actual file
-    if let Err(e) = fs::remove_file(&image.path) {
+    if let Err(e) = fs::remove_file(&image_path).await {
         eprintln!("Warning: Failed to delete image file {}: {}", image.path, e);
     }
     
-    // Remove from metadata
-    images.remove(image_index);
-    
-    // Save updated metadata
-    save_image_metadata(&app, &images)
-        .map_err(|e| format!("Failed to save metadata: {}", e))?;
-    
     Ok(())
 }
 
+// Drop the metadata entry for an image whose file is gone; used by the integrity check
+pub(crate) async fn prune_image_metadata(app: &AppHandle, image_id: &str) -> Result<(), AppError> {
+    update_image_metadata(app, |images| {
+        let before = images.len();
+        images.retain(|img| img.id != image_id);
+        if images.len() == before {
+            return Err(AppError::not_found("Image"));
+        }
+        Ok(())
+    }).await
+}
+
+// Record a file already in the images folder that has no metadata entry. Files
+// named `<uuid>.<ext>` keep that uuid as their id, so boards that still refer
+// to it work again.
+pub(crate) async fn adopt_image_file(app: &AppHandle, file_name: &str) -> Result<StoredImage, AppError> {
+    let image_path = safe_join(&get_images_dir(app).await?, file_name)?;
+    let image_data = fs::read(&image_path).await?;
+    let file_type = verify_media_type(&image_data, detect_mime(&image_data), &ALLOWED_IMAGE_TYPES)?.to_string();
+
+    let id = Path::new(file_name).file_stem()
+        .and_then(|stem| stem.to_str())
+        .filter(|stem| Uuid::parse_str(stem).is_ok())
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let modified = fs::metadata(&image_path).await?.modified().ok();
+
+    let preview = preview_for(image_data.clone(), &file_type).await;
+    let stored_image = StoredImage {
+        id: id.clone(),
+        name: file_name.to_string(),
+        original_name: file_name.to_string(),
+        path: image_path.to_string_lossy().to_string(),
+        size: image_data.len() as u64,
+        thumbnail: preview.thumbnail,
+        width: preview.width,
+        height: preview.height,
+        r#type: file_type,
+        uploaded_at: modified.map(chrono::DateTime::<chrono::Utc>::from).unwrap_or_else(chrono::Utc::now),
+        palette: None,
+    };
+
+    update_image_metadata(app, |images| {
+        if images.iter().any(|img| img.id == id || img.name == stored_image.name) {
+            return Err(AppError::conflict(format!("{} is already in the image library", file_name)));
+        }
+        images.push(stored_image.clone());
+        Ok(())
+    }).await?;
+    Ok(stored_image)
+}
+
 #[command]
-pub async fn get_image_data(app: AppHandle, image_id: String) -> Result<String, String> {
+pub async fn get_image_data(app: AppHandle, image_id: String) -> Result<String, AppError> {
     // Load metadata to find the image
-    let images = load_image_metadata(&app)
-        .map_err(|e| format!("Failed to load metadata: {}", e))?;
+    let images = load_image_metadata(&app).await
+        .map_err(|e| AppError::io(format!("Failed to load metadata: {}", e)))?;
     
     let image = images.iter()
         .find(|img| img.id == image_id)
-        .ok_or("Image not found")?;
+        .ok_or_else(|| AppError::not_found("Image"))?;
     
-    // Read the image file
-    let image_data = fs::read(&image.path)
-        .map_err(|e| format!("Failed to read image file: {}", e))?;
+    read_image_data_url(&get_images_dir(&app).await?, image).await
+} 
+
+async fn read_image_data_url(images_dir: &Path, image: &StoredImage) -> Result<String, AppError> {
+    let image_path = safe_join(images_dir, &image.name)?;
+    let image_data = fs::read(&image_path).await
+        .map_err(|e| AppError::io(format!("Failed to read image file: {}", e)))?;
     
     // Encode as base64
     let base64_data = general_purpose::STANDARD.encode(&image_data);
     Ok(format!("data:{};base64,{}", image.r#type, base64_data))
-} 
\ No newline at end of file
+}
+
+// Files read at once by get_images_data
+const MAX_CONCURRENT_IMAGE_READS: usize = 8;
+
+// Exactly one of the two is set
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageDataResult {
+    pub data: Option<String>,
+    pub error: Option<AppError>,
+}
+
+// Data URLs for several images in one call; an image that is missing or
+// unreadable gets an error entry instead of failing the rest
+#[command]
+pub async fn get_images_data(app: AppHandle, image_ids: Vec<String>) -> Result<HashMap<String, ImageDataResult>, AppError> {
+    let images = load_image_metadata(&app).await
+        .map_err(|e| AppError::io(format!("Failed to load metadata: {}", e)))?;
+    let images_dir = get_images_dir(&app).await?;
+    Ok(read_images_data(&images_dir, &images, image_ids).await)
+}
+
+async fn read_images_data(
+    images_dir: &Path,
+    images: &[StoredImage],
+    image_ids: Vec<String>,
+) -> HashMap<String, ImageDataResult> {
+    let mut unique_ids = image_ids;
+    unique_ids.sort();
+    unique_ids.dedup();
+
+    stream::iter(unique_ids)
+        .map(|image_id| {
+            let image = images.iter().find(|img| img.id == image_id);
+            async move {
+                let result = match image {
+                    Some(image) => read_image_data_url(images_dir, image).await,
+                    None => Err(AppError::not_found(format!("Image {}", image_id))),
+                };
+                let entry = match result {
+                    Ok(data) => ImageDataResult { data: Some(data), error: None },
+                    Err(error) => ImageDataResult { data: None, error: Some(error) },
+                };
+                (image_id, entry)
+            }
+        })
+        .buffer_unordered(MAX_CONCURRENT_IMAGE_READS)
+        .collect::<HashMap<_, _>>()
+        .await
+}
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageMigrationResult {
+    pub migrated_components: usize,
+    pub stored_images: Vec<StoredImage>,
+    pub original_size: u64,
+    pub new_size: u64,
+}
+
+// Split a `data:<mime>;base64,<payload>` URL into its MIME type and payload
+pub fn parse_image_data_url(value: &str) -> Option<(&str, &str)> {
+    let rest = value.strip_prefix("data:")?;
+    let (mime, payload) = rest.split_once(";base64,")?;
+    mime.starts_with("image/").then_some((mime, payload))
+}
+
+fn extension_for_mime(mime: &str) -> &str {
+    match mime {
+        "image/jpeg" => "jpg",
+        "image/svg+xml" => "svg",
+        "image/x-icon" | "image/vnd.microsoft.icon" => "ico",
+        other => other.strip_prefix("image/").unwrap_or("png"),
+    }
+}
+
+// An image embedded in a component as a data URL, decoded and ready to store
+struct EmbeddedImage {
+    url: String,
+    file_name: String,
+    mime: String,
+    data: Vec<u8>,
+}
+
+fn embedded_image_url(component: &serde_json::Value) -> Option<&str> {
+    component.get("data")?.get("imageUrl")?.as_str().filter(|url| parse_image_data_url(url).is_some())
+}
+
+// The distinct images embedded in a board's components. The same image is often
+// reused across components, so each is returned once; components that already
+// reference the store only carry a stale copy and are skipped.
+fn find_embedded_images(board: &serde_json::Value) -> Result<Vec<EmbeddedImage>, AppError> {
+    let mut images: Vec<EmbeddedImage> = Vec::new();
+    let components = board.get("components").and_then(|c| c.as_array()).map(Vec::as_slice).unwrap_or_default();
+    for component in components {
+        let Some(url) = embedded_image_url(component) else {
+            continue;
+        };
+        let has_image_id = component.get("data").and_then(|d| d.get("imageId")).and_then(|id| id.as_str()).is_some();
+        if has_image_id || images.iter().any(|image| image.url == url) {
+            continue;
+        }
+        let Some((mime, payload)) = parse_image_data_url(url) else {
+            continue;
+        };
+        let component_id = component.get("id").and_then(|id| id.as_str()).unwrap_or("component");
+        let data = general_purpose::STANDARD
+            .decode(payload)
+            .map_err(|e| AppError::validation("imageUrl", format!("Failed to decode embedded image in {}: {}", component_id, e)))?;
+        images.push(EmbeddedImage {
+            url: url.to_string(),
+            file_name: format!("{}.{}", component_id, extension_for_mime(mime)),
+            mime: mime.to_string(),
+            data,
+        });
+    }
+    Ok(images)
+}
+
+// Point components at their stored images and drop the embedded copies. Returns
+// how many components changed.
+fn replace_embedded_images(board: &mut serde_json::Value, ids_by_url: &HashMap<String, String>) -> usize {
+    let Some(components) = board.get_mut("components").and_then(|c| c.as_array_mut()) else {
+        return 0;
+    };
+    let mut migrated_components = 0;
+    for component in components {
+        let Some(url) = embedded_image_url(component).map(str::to_string) else {
+            continue;
+        };
+        let Some(data) = component.get_mut("data").and_then(|d| d.as_object_mut()) else {
+            continue;
+        };
+        if data.get("imageId").and_then(|id| id.as_str()).is_none() {
+            let Some(image_id) = ids_by_url.get(&url) else {
+                continue;
+            };
+            data.insert("imageId".to_string(), serde_json::Value::String(image_id.clone()));
+        }
+        data.remove("imageUrl");
+        migrated_components += 1;
+    }
+    migrated_components
+}
+
+#[command]
+pub async fn migrate_embedded_images(app: AppHandle, filename: String) -> Result<ImageMigrationResult, AppError> {
+    ensure_unlocked("Migrating embedded images")?;
+    let app_data_dir = data_dir(&app)?;
+    let file_path = safe_join(&app_data_dir.join("scoreboards"), &filename)?;
+    if !file_path.exists() {
+        return Err(AppError::not_found("Scoreboard file"));
+    }
+
+    let json_data = fs::read_to_string(&file_path).await
+        .map_err(|e| AppError::io(format!("Failed to read scoreboard: {}", e)))?;
+    let mut config: crate::commands::storage::ScoreboardConfig = serde_json::from_str(&json_data)
+        .map_err(|e| AppError::validation("json", format!("Failed to parse scoreboard: {}", e)))?;
+
+    let mut stored_images: Vec<StoredImage> = Vec::new();
+    let mut ids_by_url: HashMap<String, String> = HashMap::new();
+    for embedded in find_embedded_images(&config.data)? {
+        let stored = store_image(&app, embedded.file_name, embedded.mime, &embedded.data).await?;
+        ids_by_url.insert(embedded.url, stored.id.clone());
+        stored_images.push(stored);
+    }
+    let migrated_components = replace_embedded_images(&mut config.data, &ids_by_url);
+
+    let original_size = json_data.len() as u64;
+    if migrated_components == 0 {
+        return Ok(ImageMigrationResult { migrated_components, stored_images, original_size, new_size: original_size });
+    }
+
+    config.updated_at = chrono::Utc::now().to_rfc3339();
+    let new_json = serde_json::to_string_pretty(&config)?;
+    fs::write(&file_path, &new_json).await
+        .map_err(|e| AppError::io(format!("Failed to write scoreboard: {}", e)))?;
+
+    info!("🖼️ Migrated {} embedded image(s) in {} ({} -> {} bytes)", migrated_components, filename, original_size, new_json.len());
+
+    Ok(ImageMigrationResult {
+        migrated_components,
+        stored_images,
+        original_size,
+        new_size: new_json.len() as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1x1 transparent PNG
+    const PIXEL_PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=";
+
+    #[test]
+    fn migrates_an_embedded_image_to_a_store_reference() {
+        let url = format!("data:image/png;base64,{}", PIXEL_PNG);
+        let mut board = serde_json::json!({
+            "components": [
+                { "id": "logo", "type": "image", "data": { "imageUrl": url } },
+                { "id": "logo-copy", "type": "image", "data": { "imageUrl": url } },
+                { "id": "title", "type": "text", "data": { "text": "Final" } },
+            ]
+        });
+
+        let embedded = find_embedded_images(&board).unwrap();
+        assert_eq!(embedded.len(), 1);
+        assert_eq!(embedded[0].file_name, "logo.png");
+        assert_eq!(embedded[0].mime, "image/png");
+        assert_eq!(embedded[0].data, general_purpose::STANDARD.decode(PIXEL_PNG).unwrap());
+
+        let ids_by_url = HashMap::from([(url.clone(), "stored-1".to_string())]);
+        assert_eq!(replace_embedded_images(&mut board, &ids_by_url), 2);
+        for component in &board["components"].as_array().unwrap()[..2] {
+            assert_eq!(component["data"]["imageId"], "stored-1");
+            assert!(component["data"].get("imageUrl").is_none());
+        }
+        assert_eq!(board["components"][2]["data"]["text"], "Final");
+    }
+
+    #[test]
+    fn component_already_in_the_store_only_loses_its_embedded_copy() {
+        let mut board = serde_json::json!({
+            "components": [
+                { "id": "logo", "data": { "imageId": "existing", "imageUrl": format!("data:image/png;base64,{}", PIXEL_PNG) } },
+            ]
+        });
+        assert!(find_embedded_images(&board).unwrap().is_empty());
+        assert_eq!(replace_embedded_images(&mut board, &HashMap::new()), 1);
+        assert_eq!(board["components"][0]["data"], serde_json::json!({ "imageId": "existing" }));
+    }
+
+
+    fn stored(id: &str, name: &str) -> StoredImage {
+        StoredImage {
+            id: id.to_string(),
+            name: name.to_string(),
+            original_name: name.to_string(),
+            path: name.to_string(),
+            size: 0,
+            r#type: "image/png".to_string(),
+            uploaded_at: chrono::Utc::now(),
+            thumbnail: None,
+            width: None,
+            height: None,
+            palette: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_fetch_reports_missing_images_per_id() {
+        let dir = std::env::temp_dir().join(format!("images_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).await.unwrap();
+        let png = general_purpose::STANDARD.decode(PIXEL_PNG).unwrap();
+        fs::write(dir.join("a.png"), &png).await.unwrap();
+        fs::write(dir.join("b.png"), &png).await.unwrap();
+        let images = [stored("a", "a.png"), stored("b", "b.png")];
+
+        let ids = vec!["a".to_string(), "b".to_string(), "missing".to_string(), "a".to_string()];
+        let results = read_images_data(&dir, &images, ids).await;
+
+        assert_eq!(results.len(), 3);
+        let expected = format!("data:image/png;base64,{}", PIXEL_PNG);
+        assert_eq!(results["a"].data.as_deref(), Some(expected.as_str()));
+        assert_eq!(results["b"].data.as_deref(), Some(expected.as_str()));
+        assert!(results["missing"].data.is_none());
+        assert!(matches!(results["missing"].error, Some(AppError::NotFound { .. })));
+
+        let _ = fs::remove_dir_all(&dir).await;
+    }
+}
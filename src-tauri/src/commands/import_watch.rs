@@ -0,0 +1,213 @@
+// src-tauri/src/commands/import_watch.rs
+//
+// Automatic import of scoreboard packages dropped into a watch folder (e.g. a
+// synced Dropbox folder on a venue machine). The folder is polled rather than
+// watched, which behaves the same on network and synced drives. A file is only
+// picked up once its size and modification time stop changing, so partially
+// synced ZIPs are left alone. Processed files move to `processed/`, and ones
+// that fail move to `failed/` next to a `.error.txt` explaining why.
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Emitter};
+use tracing::{error, info, warn};
+
+use crate::commands::config::{app_config, update_app_config};
+use crate::commands::events::BackendEvent;
+use crate::commands::storage::{check_zip_limits, content_hash, import_zip, ImportedImageCache};
+use crate::commands::data_dir::data_dir;
+use crate::error::AppError;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const IMPORTED_HASHES_FILE: &str = "import_watch_hashes.json";
+const PROCESSED_DIR: &str = "processed";
+const FAILED_DIR: &str = "failed";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoImportEvent {
+    pub file_name: String,
+    pub scoreboard_name: String,
+    pub scoreboard_id: String,
+}
+
+// Size and modification time last seen for each ZIP not yet processed
+type PendingFiles = HashMap<PathBuf, (u64, SystemTime)>;
+
+fn imported_hashes_path(app: &AppHandle) -> Result<PathBuf, AppError> {
+    Ok(data_dir(app)?.join(IMPORTED_HASHES_FILE))
+}
+
+fn load_imported_hashes(app: &AppHandle) -> HashSet<String> {
+    imported_hashes_path(app).ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+async fn save_imported_hashes(app: &AppHandle, hashes: &HashSet<String>) -> Result<(), AppError> {
+    let path = imported_hashes_path(app)?;
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let mut sorted: Vec<&String> = hashes.iter().collect();
+    sorted.sort();
+    tokio::fs::write(&path, serde_json::to_string_pretty(&sorted)?).await?;
+    Ok(())
+}
+
+fn is_zip(path: &Path) -> bool {
+    path.is_file() && path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+}
+
+// ZIPs whose size and modification time match the previous scan
+async fn settled_zips(folder: &Path, pending: &mut PendingFiles) -> Result<Vec<PathBuf>, AppError> {
+    let mut seen = PendingFiles::new();
+    let mut entries = tokio::fs::read_dir(folder).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if !is_zip(&path) {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata().await else { continue };
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        seen.insert(path, (metadata.len(), modified));
+    }
+
+    let mut settled: Vec<PathBuf> = seen.iter()
+        .filter(|(path, state)| pending.get(*path) == Some(*state))
+        .map(|(path, _)| path.clone())
+        .collect();
+    settled.sort();
+    *pending = seen;
+    Ok(settled)
+}
+
+// Move `file` into `folder/subdir`, adding a timestamp if that name is taken
+async fn move_into(folder: &Path, subdir: &str, file: &Path) -> Result<PathBuf, AppError> {
+    let target_dir = folder.join(subdir);
+    tokio::fs::create_dir_all(&target_dir).await?;
+
+    let file_name = file.file_name().and_then(|name| name.to_str()).unwrap_or("package.zip");
+    let mut target = target_dir.join(file_name);
+    if target.exists() {
+        let stem = file.file_stem().and_then(|stem| stem.to_str()).unwrap_or("package");
+        target = target_dir.join(format!("{}-{}.zip", stem, chrono::Utc::now().format("%Y%m%d%H%M%S")));
+    }
+    tokio::fs::rename(file, &target).await?;
+    Ok(target)
+}
+
+async fn import_file(app: &AppHandle, path: &Path, imported: &mut HashSet<String>) -> Result<Option<AutoImportEvent>, AppError> {
+    let zip_data = tokio::fs::read(path).await?;
+    let hash = content_hash(&zip_data);
+    if imported.contains(&hash) {
+        return Ok(None);
+    }
+
+    let config = app_config();
+    {
+        // Same checks as the package preview, before anything is extracted
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(zip_data.as_slice()))
+            .map_err(|e| AppError::validation("zip", format!("Failed to read ZIP file: {}", e)))?;
+        check_zip_limits(&mut archive, &config)?;
+    }
+
+    let scoreboard = import_zip(app, &zip_data, &mut ImportedImageCache::default(), config.import_watch_conflict).await?;
+
+    imported.insert(hash);
+    save_imported_hashes(app, imported).await?;
+
+    Ok(Some(AutoImportEvent {
+        file_name: path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default(),
+        scoreboard_name: scoreboard.name,
+        scoreboard_id: scoreboard.id,
+    }))
+}
+
+async fn process_file(app: &AppHandle, folder: &Path, path: &Path, imported: &mut HashSet<String>) {
+    match import_file(app, path, imported).await {
+        Ok(Some(event)) => {
+            info!("📦 Auto-imported '{}' from {}", event.scoreboard_name, event.file_name);
+            if let Err(e) = app.emit(BackendEvent::ScoreboardAutoImported.as_str(), &event) {
+                error!("Failed to emit {}: {}", BackendEvent::ScoreboardAutoImported, e);
+            }
+            if let Err(e) = move_into(folder, PROCESSED_DIR, path).await {
+                warn!("Imported {:?} but could not move it to {}/: {}", path, PROCESSED_DIR, e);
+            }
+        }
+        Ok(None) => {
+            info!("📦 Skipping {:?}: this package was already imported", path);
+            if let Err(e) = move_into(folder, PROCESSED_DIR, path).await {
+                warn!("Could not move {:?} to {}/: {}", path, PROCESSED_DIR, e);
+            }
+        }
+        Err(import_error) => {
+            warn!("📦 Failed to auto-import {:?}: {}", path, import_error);
+            match move_into(folder, FAILED_DIR, path).await {
+                Ok(moved) => {
+                    let sidecar = moved.with_extension("error.txt");
+                    if let Err(e) = tokio::fs::write(&sidecar, import_error.to_string()).await {
+                        warn!("Could not write {:?}: {}", sidecar, e);
+                    }
+                }
+                Err(e) => warn!("Could not move {:?} to {}/: {}", path, FAILED_DIR, e),
+            }
+        }
+    }
+}
+
+// Settings are read on every scan, so changes apply without restarting the task
+pub fn start_import_watch(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut imported = load_imported_hashes(&app);
+        let mut pending = PendingFiles::new();
+        let mut watched = PathBuf::new();
+
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            let config = app_config();
+            if !config.import_watch_enabled || config.import_watch_folder.trim().is_empty() {
+                pending.clear();
+                continue;
+            }
+            let folder = PathBuf::from(config.import_watch_folder.trim());
+            if folder != watched {
+                pending.clear();
+                watched = folder.clone();
+            }
+
+            match settled_zips(&folder, &mut pending).await {
+                Ok(files) => {
+                    for path in files {
+                        pending.remove(&path);
+                        process_file(&app, &folder, &path, &mut imported).await;
+                    }
+                }
+                Err(e) => warn!("Cannot scan import watch folder {:?}: {}", folder, e),
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn set_import_watch_folder(app: AppHandle, path: String, enabled: bool) -> Result<(), AppError> {
+    let path = path.trim().to_string();
+    if enabled {
+        let folder = Path::new(&path);
+        if !folder.is_dir() {
+            return Err(AppError::validation("path", format!("'{}' is not a folder", path)));
+        }
+    }
+
+    update_app_config(app, serde_json::json!({
+        "importWatchFolder": path,
+        "importWatchEnabled": enabled,
+    })).await?;
+    info!("📦 Import watch folder {} ({})", if enabled { "enabled" } else { "disabled" }, path);
+    Ok(())
+}
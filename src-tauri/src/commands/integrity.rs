@@ -0,0 +1,460 @@
+// src-tauri/src/commands/integrity.rs
+//
+// Cross-checks the library against the disk: scoreboard files against the
+// filename they record, image/video metadata against the files in their
+// folders, component imageId/videoId references against the libraries, and
+// every other JSON file in the data directory against the parser. Each problem
+// that can be repaired carries a fix with a stable id. `apply_integrity_fixes`
+// rescans before acting, so a fix is only applied if the problem still exists.
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+use tracing::{error, info, warn};
+
+use crate::commands::data_dir::data_dir;
+use crate::commands::events::BackendEvent;
+use crate::commands::images::{adopt_image_file, get_images_dir, load_image_metadata, prune_image_metadata};
+use crate::commands::media_library::write_atomic;
+use crate::commands::storage::{safe_join, ScoreboardConfig};
+use crate::commands::videos::{adopt_video_file, get_videos_dir, load_video_metadata, prune_video_metadata};
+use crate::error::AppError;
+
+const METADATA_FILE: &str = "metadata.json";
+// Copies of boards taken before a fix rewrites them
+const REVISIONS_DIR: &str = "revisions";
+// Checked on their own, or not JSON the app wrote
+const STATE_SCAN_SKIPPED_DIRS: &[&str] = &["scoreboards", "images", "videos", "logs", REVISIONS_DIR];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegritySeverity {
+    // Something shows up broken or fails to load
+    Error,
+    // Inconsistent, but nothing visibly broken yet
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", rename_all_fields = "camelCase")]
+pub enum IntegrityFix {
+    CorrectScoreboardFilename { file: String },
+    PruneImageMetadata { image_id: String },
+    PruneVideoMetadata { video_id: String },
+    AdoptImageFile { file: String },
+    AdoptVideoFile { file: String },
+    StripBrokenReferences { file: String },
+    // Path relative to the data directory
+    QuarantineStateFile { path: String },
+}
+
+impl IntegrityFix {
+    pub fn id(&self) -> String {
+        match self {
+            IntegrityFix::CorrectScoreboardFilename { file } => format!("correct-filename:{}", file),
+            IntegrityFix::PruneImageMetadata { image_id } => format!("prune-image:{}", image_id),
+            IntegrityFix::PruneVideoMetadata { video_id } => format!("prune-video:{}", video_id),
+            IntegrityFix::AdoptImageFile { file } => format!("adopt-image:{}", file),
+            IntegrityFix::AdoptVideoFile { file } => format!("adopt-video:{}", file),
+            IntegrityFix::StripBrokenReferences { file } => format!("strip-references:{}", file),
+            IntegrityFix::QuarantineStateFile { path } => format!("quarantine:{}", path),
+        }
+    }
+
+    // Adopting files can bring a referenced id back, so it runs before references are stripped
+    fn order(&self) -> u8 {
+        match self {
+            IntegrityFix::AdoptImageFile { .. } | IntegrityFix::AdoptVideoFile { .. } => 0,
+            IntegrityFix::PruneImageMetadata { .. } | IntegrityFix::PruneVideoMetadata { .. } => 1,
+            IntegrityFix::CorrectScoreboardFilename { .. } => 2,
+            IntegrityFix::StripBrokenReferences { .. } => 3,
+            IntegrityFix::QuarantineStateFile { .. } => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityIssue {
+    // The fix id when there is a fix, so it can be passed to apply_integrity_fixes
+    pub id: String,
+    pub severity: IntegritySeverity,
+    pub category: String,
+    pub subject: String,
+    pub message: String,
+    pub suggested_fix: Option<String>,
+    pub fix: Option<IntegrityFix>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityReport {
+    pub checked_at: String,
+    pub errors: Vec<IntegrityIssue>,
+    pub warnings: Vec<IntegrityIssue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityProgressEvent {
+    pub phase: String,
+    pub checked: usize,
+    pub total: usize,
+}
+
+// Outcome of one requested fix; error is None when it was applied
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityFixOutcome {
+    pub fix_id: String,
+    pub error: Option<AppError>,
+}
+
+fn emit_progress(app: &AppHandle, phase: &str, checked: usize, total: usize) {
+    let event = IntegrityProgressEvent { phase: phase.to_string(), checked, total };
+    if let Err(e) = app.emit(BackendEvent::IntegrityCheckProgress.as_str(), &event) {
+        error!("Failed to emit {}: {}", BackendEvent::IntegrityCheckProgress, e);
+    }
+}
+
+fn issue(severity: IntegritySeverity, category: &str, subject: &str, message: String) -> IntegrityIssue {
+    IntegrityIssue {
+        id: format!("{}:{}", category, subject),
+        severity,
+        category: category.to_string(),
+        subject: subject.to_string(),
+        message,
+        suggested_fix: None,
+        fix: None,
+    }
+}
+
+fn with_fix(mut issue: IntegrityIssue, suggestion: &str, fix: IntegrityFix) -> IntegrityIssue {
+    issue.id = fix.id();
+    issue.suggested_fix = Some(suggestion.to_string());
+    issue.fix = Some(fix);
+    issue
+}
+
+// Files in a media folder other than metadata.json and temp files
+async fn media_files(dir: &Path) -> Result<Vec<String>, AppError> {
+    let mut files = Vec::new();
+    let mut entries = tokio::fs::read_dir(dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name == METADATA_FILE || name.starts_with('.') || !entry.file_type().await?.is_file() {
+            continue;
+        }
+        files.push(name);
+    }
+    files.sort();
+    Ok(files)
+}
+
+// (id, stored file name) of each entry, checked against the files in `dir`
+async fn check_media_library(
+    kind: &str,
+    dir: &Path,
+    entries: &[(String, String)],
+    issues: &mut Vec<IntegrityIssue>,
+) -> Result<(), AppError> {
+    let files = media_files(dir).await?;
+    let file_set: HashSet<&str> = files.iter().map(String::as_str).collect();
+    let recorded: HashSet<&str> = entries.iter().map(|(_, name)| name.as_str()).collect();
+
+    for (id, name) in entries {
+        let present = safe_join(dir, name).is_ok() && file_set.contains(name.as_str());
+        if !present {
+            let fix = if kind == "image" {
+                IntegrityFix::PruneImageMetadata { image_id: id.clone() }
+            } else {
+                IntegrityFix::PruneVideoMetadata { video_id: id.clone() }
+            };
+            issues.push(with_fix(
+                issue(IntegritySeverity::Error, &format!("missing_{}_file", kind), id,
+                    format!("The {} library lists {} but the file is missing", kind, name)),
+                &format!("Remove the {} from the library", kind),
+                fix,
+            ));
+        }
+    }
+
+    for file in files.iter().filter(|file| !recorded.contains(file.as_str())) {
+        let fix = if kind == "image" {
+            IntegrityFix::AdoptImageFile { file: file.clone() }
+        } else {
+            IntegrityFix::AdoptVideoFile { file: file.clone() }
+        };
+        issues.push(with_fix(
+            issue(IntegritySeverity::Warning, &format!("orphan_{}_file", kind), file,
+                format!("{} is in the {} folder but not in the library", file, kind)),
+            &format!("Add the file to the {} library", kind),
+            fix,
+        ));
+    }
+    Ok(())
+}
+
+// Component ids and the image/video ids they refer to that aren't in the libraries
+fn broken_references(
+    data: &serde_json::Value,
+    image_ids: &HashSet<String>,
+    video_ids: &HashSet<String>,
+) -> Vec<(String, String)> {
+    let components = data.get("components").and_then(|c| c.as_array());
+    let mut broken = Vec::new();
+    for component in components.into_iter().flatten() {
+        let component_id = component.get("id").and_then(|id| id.as_str()).unwrap_or("").to_string();
+        let component_data = component.get("data");
+        let reference = |key: &str| component_data.and_then(|d| d.get(key)).and_then(|id| id.as_str());
+        if let Some(image_id) = reference("imageId").filter(|id| !image_ids.contains(*id)) {
+            broken.push((component_id.clone(), image_id.to_string()));
+        }
+        if let Some(video_id) = reference("videoId").filter(|id| !video_ids.contains(*id)) {
+            broken.push((component_id.clone(), video_id.to_string()));
+        }
+    }
+    broken
+}
+
+fn scoreboard_files(dir: &Path) -> Result<Vec<PathBuf>, AppError> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().and_then(|ext| ext.to_str()) == Some("json"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+// JSON files anywhere in the data directory outside the folders checked separately
+fn state_files(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), AppError> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.file_name().and_then(|name| name.to_str()).unwrap_or("");
+        if path.is_dir() {
+            let skipped = dir == root && STATE_SCAN_SKIPPED_DIRS.contains(&name);
+            if !skipped && !name.starts_with('.') {
+                state_files(root, &path, files)?;
+            }
+        } else if path.extension().and_then(|ext| ext.to_str()) == Some("json") && !name.starts_with('.') {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn library_ids<T>(entries: &Option<Vec<T>>, id: impl Fn(&T) -> String) -> HashSet<String> {
+    entries.iter().flatten().map(id).collect()
+}
+
+async fn scan(app: &AppHandle) -> Result<Vec<IntegrityIssue>, AppError> {
+    let root = data_dir(app)?;
+    let mut issues = Vec::new();
+
+    emit_progress(app, "media", 0, 2);
+    // An unreadable metadata file would make every file look orphaned, so skip those checks
+    let images = match load_image_metadata(app).await {
+        Ok(images) => Some(images),
+        Err(e) => {
+            issues.push(issue(IntegritySeverity::Error, "unreadable_image_metadata", METADATA_FILE,
+                format!("images/{} cannot be read: {}", METADATA_FILE, e)));
+            None
+        }
+    };
+    if let Some(images) = &images {
+        let entries: Vec<(String, String)> = images.iter().map(|img| (img.id.clone(), img.name.clone())).collect();
+        check_media_library("image", &get_images_dir(app).await?, &entries, &mut issues).await?;
+    }
+    emit_progress(app, "media", 1, 2);
+
+    let videos = match load_video_metadata(app).await {
+        Ok(videos) => Some(videos),
+        Err(e) => {
+            issues.push(issue(IntegritySeverity::Error, "unreadable_video_metadata", METADATA_FILE,
+                format!("videos/{} cannot be read: {}", METADATA_FILE, e)));
+            None
+        }
+    };
+    if let Some(videos) = &videos {
+        let entries: Vec<(String, String)> = videos.iter().map(|video| (video.id.clone(), video.name.clone())).collect();
+        check_media_library("video", &get_videos_dir(app).await?, &entries, &mut issues).await?;
+    }
+    emit_progress(app, "media", 2, 2);
+
+    let image_ids = library_ids(&images, |img| img.id.clone());
+    let video_ids = library_ids(&videos, |video| video.id.clone());
+
+    let boards = scoreboard_files(&root.join("scoreboards"))?;
+    for (index, path) in boards.iter().enumerate() {
+        let file = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        let parsed = tokio::fs::read_to_string(path).await
+            .map_err(AppError::from)
+            .and_then(|json| Ok(serde_json::from_str::<ScoreboardConfig>(&json)?));
+        match parsed {
+            Err(e) => issues.push(IntegrityIssue {
+                suggested_fix: Some("Restore the board from an export, or delete the file".to_string()),
+                ..issue(IntegritySeverity::Error, "unreadable_scoreboard", &file, format!("{} cannot be loaded: {}", file, e))
+            }),
+            Ok(config) => {
+                if config.filename != file {
+                    issues.push(with_fix(
+                        issue(IntegritySeverity::Warning, "scoreboard_filename_mismatch", &file,
+                            format!("{} records its filename as '{}'", file, config.filename)),
+                        "Record the actual filename in the board",
+                        IntegrityFix::CorrectScoreboardFilename { file: file.clone() },
+                    ));
+                }
+                // Without both libraries every reference to the missing one would look broken
+                if images.is_some() && videos.is_some() {
+                    let broken = broken_references(&config.data, &image_ids, &video_ids);
+                    if !broken.is_empty() {
+                        let ids: Vec<&str> = broken.iter().map(|(_, media_id)| media_id.as_str()).collect();
+                        issues.push(with_fix(
+                            issue(IntegritySeverity::Error, "broken_media_reference", &file,
+                                format!("{} has {} component(s) pointing at missing media: {}", file, broken.len(), ids.join(", "))),
+                            "Remove the missing media from those components (a copy of the board is kept first)",
+                            IntegrityFix::StripBrokenReferences { file: file.clone() },
+                        ));
+                    }
+                }
+            }
+        }
+        emit_progress(app, "scoreboards", index + 1, boards.len());
+    }
+
+    let mut states = Vec::new();
+    if root.exists() {
+        state_files(&root, &root, &mut states)?;
+    }
+    states.sort();
+    for (index, path) in states.iter().enumerate() {
+        let relative = path.strip_prefix(&root).unwrap_or(path).to_string_lossy().replace('\\', "/");
+        let parsed = tokio::fs::read_to_string(path).await
+            .map_err(AppError::from)
+            .and_then(|json| Ok(serde_json::from_str::<serde_json::Value>(&json)?));
+        if let Err(e) = parsed {
+            issues.push(with_fix(
+                issue(IntegritySeverity::Error, "unreadable_state_file", &relative,
+                    format!("{} cannot be parsed: {}", relative, e)),
+                "Rename it to .corrupt so the app starts from defaults",
+                IntegrityFix::QuarantineStateFile { path: relative.clone() },
+            ));
+        }
+        emit_progress(app, "state", index + 1, states.len());
+    }
+
+    Ok(issues)
+}
+
+// Keep a timestamped copy of a board before a fix rewrites it
+async fn save_revision(root: &Path, board_path: &Path, contents: &str) -> Result<PathBuf, AppError> {
+    let revisions_dir = root.join(REVISIONS_DIR);
+    tokio::fs::create_dir_all(&revisions_dir).await?;
+    let stem = board_path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("scoreboard");
+    let revision = revisions_dir.join(format!("{}.{}.json", stem, chrono::Utc::now().format("%Y%m%d%H%M%S")));
+    tokio::fs::write(&revision, contents).await?;
+    Ok(revision)
+}
+
+fn strip_references(data: &mut serde_json::Value, image_ids: &HashSet<String>, video_ids: &HashSet<String>) -> usize {
+    let mut stripped = 0;
+    let components = data.get_mut("components").and_then(|c| c.as_array_mut());
+    for component in components.into_iter().flatten() {
+        let Some(component_data) = component.get_mut("data").and_then(|d| d.as_object_mut()) else {
+            continue;
+        };
+        for (key, known) in [("imageId", image_ids), ("videoId", video_ids)] {
+            let broken = component_data.get(key).and_then(|id| id.as_str()).is_some_and(|id| !known.contains(id));
+            if broken {
+                component_data.remove(key);
+                stripped += 1;
+            }
+        }
+    }
+    stripped
+}
+
+async fn apply_fix(app: &AppHandle, fix: &IntegrityFix) -> Result<(), AppError> {
+    let root = data_dir(app)?;
+    match fix {
+        IntegrityFix::PruneImageMetadata { image_id } => prune_image_metadata(app, image_id).await,
+        IntegrityFix::PruneVideoMetadata { video_id } => prune_video_metadata(app, video_id).await,
+        IntegrityFix::AdoptImageFile { file } => adopt_image_file(app, file).await.map(|_| ()),
+        IntegrityFix::AdoptVideoFile { file } => adopt_video_file(app, file).await.map(|_| ()),
+        IntegrityFix::CorrectScoreboardFilename { file } => {
+            let path = safe_join(&root.join("scoreboards"), file)?;
+            let mut config: ScoreboardConfig = serde_json::from_str(&tokio::fs::read_to_string(&path).await?)?;
+            config.filename = file.clone();
+            write_atomic(&path, serde_json::to_string_pretty(&config)?.as_bytes()).await
+        }
+        IntegrityFix::StripBrokenReferences { file } => {
+            let path = safe_join(&root.join("scoreboards"), file)?;
+            let contents = tokio::fs::read_to_string(&path).await?;
+            let mut config: ScoreboardConfig = serde_json::from_str(&contents)?;
+
+            // Read after any adoptions in this batch, which may have brought ids back
+            let image_ids: HashSet<String> = load_image_metadata(app).await?.into_iter().map(|img| img.id).collect();
+            let video_ids: HashSet<String> = load_video_metadata(app).await?.into_iter().map(|video| video.id).collect();
+
+            let revision = save_revision(&root, &path, &contents).await?;
+            let stripped = strip_references(&mut config.data, &image_ids, &video_ids);
+            config.updated_at = chrono::Utc::now().to_rfc3339();
+            write_atomic(&path, serde_json::to_string_pretty(&config)?.as_bytes()).await?;
+            info!("🩺 Removed {} broken media reference(s) from {} (previous version in {:?})", stripped, file, revision);
+            Ok(())
+        }
+        IntegrityFix::QuarantineStateFile { path } => {
+            let source = path.split('/').try_fold(root.clone(), |dir, part| safe_join(&dir, part))?;
+            let mut target = source.clone().into_os_string();
+            target.push(".corrupt");
+            tokio::fs::rename(&source, PathBuf::from(target)).await?;
+            Ok(())
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn run_integrity_check(app: AppHandle) -> Result<IntegrityReport, AppError> {
+    let issues = scan(&app).await?;
+    let (errors, warnings): (Vec<_>, Vec<_>) = issues.into_iter()
+        .partition(|issue| issue.severity == IntegritySeverity::Error);
+    info!("🩺 Integrity check: {} error(s), {} warning(s)", errors.len(), warnings.len());
+    Ok(IntegrityReport {
+        checked_at: chrono::Utc::now().to_rfc3339(),
+        errors,
+        warnings,
+    })
+}
+
+#[tauri::command]
+pub async fn apply_integrity_fixes(app: AppHandle, fix_ids: Vec<String>) -> Result<Vec<IntegrityFixOutcome>, AppError> {
+    let mut fixes: Vec<IntegrityFix> = scan(&app).await?
+        .into_iter()
+        .filter_map(|issue| issue.fix)
+        .filter(|fix| fix_ids.contains(&fix.id()))
+        .collect();
+    fixes.sort_by_key(IntegrityFix::order);
+
+    let mut outcomes = Vec::new();
+    for (index, fix) in fixes.iter().enumerate() {
+        let error = apply_fix(&app, fix).await.err();
+        if let Some(e) = &error {
+            warn!("🩺 Integrity fix {} failed: {}", fix.id(), e);
+        }
+        outcomes.push(IntegrityFixOutcome { fix_id: fix.id(), error });
+        emit_progress(&app, "fixes", index + 1, fixes.len());
+    }
+
+    // Ids from an older report whose problem has since gone away
+    let applied: HashSet<String> = fixes.iter().map(IntegrityFix::id).collect();
+    for fix_id in fix_ids.into_iter().filter(|id| !applied.contains(id)) {
+        outcomes.push(IntegrityFixOutcome {
+            fix_id: fix_id.clone(),
+            error: Some(AppError::not_found(format!("Integrity problem {}", fix_id))),
+        });
+    }
+    Ok(outcomes)
+}
@@ -0,0 +1,233 @@
+// src-tauri/src/commands/launch.rs
+//
+// Command-line launch options so an unattended venue PC can boot straight onto
+// a board, e.g. `--load-scoreboard "Center Court" --monitor 1 --fullscreen --connect-live`.
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tracing::{error, info, warn};
+
+use crate::commands::events::BackendEvent;
+use crate::commands::live_data::{connect_websocket, start_websocket_listener};
+use crate::commands::monitor::{create_scoreboard_window, set_scoreboard_fullscreen, ScoreboardInstanceStore};
+use crate::commands::storage::{list_scoreboards, load_live_data_connections, ScoreboardConfig};
+use crate::error::AppError;
+
+// Window size used when the board doesn't record its own dimensions
+const DEFAULT_WINDOW_SIZE: (u32, u32) = (1920, 1080);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchOptions {
+    // Scoreboard name or filename
+    pub load_scoreboard: Option<String>,
+    pub monitor: Option<u32>,
+    pub fullscreen: bool,
+    pub connect_live: bool,
+    // Court the display should follow, same as the frontend's courtFilter
+    pub court: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchReport {
+    pub completed: bool,
+    pub window_id: Option<String>,
+    pub connected: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchStatus {
+    pub options: LaunchOptions,
+    pub report: LaunchReport,
+}
+
+#[derive(Default)]
+pub struct LaunchState {
+    pub options: LaunchOptions,
+    pub report: Arc<Mutex<LaunchReport>>,
+}
+
+impl LaunchState {
+    pub fn new(options: LaunchOptions) -> Self {
+        Self { options, report: Arc::new(Mutex::new(LaunchReport::default())) }
+    }
+}
+
+impl LaunchOptions {
+    pub fn has_actions(&self) -> bool {
+        self.load_scoreboard.is_some() || self.connect_live
+    }
+}
+
+// Unknown arguments are ignored; the OS and the dev tooling pass their own
+pub fn parse_launch_args<I: IntoIterator<Item = String>>(args: I) -> LaunchOptions {
+    let mut options = LaunchOptions::default();
+    let mut args = args.into_iter().skip(1);
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+            _ => (arg, None),
+        };
+
+        match flag.as_str() {
+            "--load-scoreboard" => options.load_scoreboard = inline_value.or_else(|| args.next()),
+            "--court" => options.court = inline_value.or_else(|| args.next()),
+            "--monitor" => {
+                let value = inline_value.or_else(|| args.next()).unwrap_or_default();
+                match value.parse::<u32>() {
+                    Ok(monitor) => options.monitor = Some(monitor),
+                    Err(_) => warn!("Ignoring invalid --monitor value: {}", value),
+                }
+            }
+            "--fullscreen" => options.fullscreen = true,
+            "--connect-live" => options.connect_live = true,
+            _ => {}
+        }
+    }
+
+    options
+}
+
+// Match a saved board by display name or filename (with or without .json)
+pub(crate) async fn find_scoreboard(app: &AppHandle, name: &str) -> Result<ScoreboardConfig, AppError> {
+    list_scoreboards(app.clone()).await?
+        .into_iter()
+        .find(|sb| sb.name == name || sb.filename == name || sb.filename.trim_end_matches(".json") == name)
+        .ok_or_else(|| AppError::not_found(format!("Scoreboard {}", name)))
+}
+
+pub(crate) async fn open_scoreboard(app: &AppHandle, options: &LaunchOptions, name: &str) -> Result<String, AppError> {
+    let window_id = format!("scoreboard_{}", uuid::Uuid::new_v4());
+    open_scoreboard_window(app, options, name, &window_id).await?;
+    Ok(window_id)
+}
+
+// Same as open_scoreboard with a caller-chosen label, so the window can be found again by name
+pub(crate) async fn open_scoreboard_window(
+    app: &AppHandle,
+    options: &LaunchOptions,
+    name: &str,
+    window_id: &str,
+) -> Result<(), AppError> {
+    let scoreboard = find_scoreboard(app, name).await?;
+
+    let monitor_id = options.monitor.unwrap_or(0);
+    let monitor = app.available_monitors()
+        .map_err(|e| AppError::external("tauri", format!("Failed to list monitors: {}", e)))?
+        .into_iter()
+        .nth(monitor_id as usize)
+        .ok_or_else(|| AppError::not_found(format!("Monitor {}", monitor_id)))?;
+
+    let (width, height) = if options.fullscreen {
+        (monitor.size().width, monitor.size().height)
+    } else {
+        scoreboard.data.get("dimensions")
+            .and_then(|d| Some((d.get("width")?.as_u64()? as u32, d.get("height")?.as_u64()? as u32)))
+            .unwrap_or(DEFAULT_WINDOW_SIZE)
+    };
+
+    let mut data = scoreboard.data.clone();
+    if let (Some(court), Some(obj)) = (&options.court, data.as_object_mut()) {
+        obj.insert("courtFilter".to_string(), serde_json::Value::String(court.clone()));
+    }
+
+    create_scoreboard_window(
+        app.clone(),
+        app.state::<ScoreboardInstanceStore>(),
+        window_id.to_string(),
+        monitor_id,
+        width,
+        height,
+        monitor.position().x,
+        monitor.position().y,
+        0,
+        0,
+        Some(data),
+    ).await?;
+
+    // create_scoreboard_window always ends in fullscreen
+    if !options.fullscreen {
+        set_scoreboard_fullscreen(app.clone(), window_id.to_string(), false).await?;
+    }
+
+    info!("🚀 Launched scoreboard '{}' on monitor {}", scoreboard.name, monitor_id);
+    Ok(())
+}
+
+// Connects every active saved connection, or only `connection_id` when given
+pub(crate) async fn connect_saved_connections(
+    app: &AppHandle,
+    connection_id: Option<&str>,
+    report: &mut LaunchReport,
+) -> Result<(), AppError> {
+    let saved = load_live_data_connections(app.clone()).await?;
+    let selected = saved.connections.iter()
+        .filter(|c| connection_id.map_or(c.is_active, |id| c.id == id));
+
+    for connection in selected {
+        // Only WebSocket feeds can be started without the frontend
+        if !connection.api_url.starts_with("ws://") && !connection.api_url.starts_with("wss://") {
+            continue;
+        }
+
+        let result = async {
+            // Scheduled starts may hit a feed that is already up; restart it rather than fail
+            connect_websocket(connection.api_url.clone(), connection.id.clone(), None, Some(true)).await?;
+            start_websocket_listener(app.clone(), connection.id.clone()).await
+        }.await;
+
+        match result {
+            Ok(_) => report.connected.push(connection.id.clone()),
+            Err(e) => report.errors.push(format!("Failed to connect '{}': {}", connection.name, e)),
+        }
+    }
+    Ok(())
+}
+
+pub fn run_launch_actions(app: &AppHandle) {
+    let options = app.state::<LaunchState>().options.clone();
+    if !options.has_actions() {
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut report = LaunchReport::default();
+
+        if options.connect_live {
+            if let Err(e) = connect_saved_connections(&app, None, &mut report).await {
+                report.errors.push(format!("Failed to load live data connections: {}", e));
+            }
+        }
+
+        if let Some(name) = &options.load_scoreboard {
+            match open_scoreboard(&app, &options, name).await {
+                Ok(window_id) => report.window_id = Some(window_id),
+                Err(e) => report.errors.push(e.to_string()),
+            }
+        }
+
+        for error in &report.errors {
+            error!("Launch option failed: {}", error);
+        }
+        report.completed = true;
+
+        let state = app.state::<LaunchState>();
+        if let Ok(mut stored) = state.report.lock() {
+            *stored = report.clone();
+        }
+        if let Err(e) = app.emit_to("main", BackendEvent::LaunchCompleted.as_str(), &report) {
+            error!("Failed to emit {}: {}", BackendEvent::LaunchCompleted, e);
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn get_launch_options(launch_state: State<'_, LaunchState>) -> Result<LaunchStatus, AppError> {
+    let report = launch_state.report.lock()?.clone();
+    Ok(LaunchStatus { options: launch_state.options.clone(), report })
+}
@@ -0,0 +1,165 @@
+// src-tauri/src/commands/logging.rs
+//
+// Logs go to stdout and to a daily-rotated file under app_data/logs/, so they
+// can be collected from venue machines running packaged builds.
+use std::path::PathBuf;
+use std::sync::Mutex;
+use lazy_static::lazy_static;
+use tauri::AppHandle;
+use tracing::info;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::{fmt, prelude::*, reload, EnvFilter, Registry};
+use crate::commands::data_dir::data_dir;
+
+const LOG_DIR: &str = "logs";
+const LOG_FILE_PREFIX: &str = "tempuz";
+const LOG_FILE_SUFFIX: &str = "log";
+const MAX_LOG_FILES: usize = 7;
+const DEFAULT_LOG_LEVEL: &str = "info";
+const DEFAULT_RECENT_LINES: usize = 200;
+const LEVELS: [&str; 5] = ["TRACE", "DEBUG", "INFO", "WARN", "ERROR"];
+
+lazy_static! {
+    static ref LOG_FILTER: Mutex<Option<reload::Handle<EnvFilter, Registry>>> = Mutex::new(None);
+    // Dropping the guard stops the background writer, so it lives for the whole run
+    static ref LOG_GUARD: Mutex<Option<WorkerGuard>> = Mutex::new(None);
+}
+
+// Our own crate logs at `level`; dependencies only at warn and above
+fn filter_for_level(level: &str) -> Result<EnvFilter, String> {
+    let level = level.to_lowercase();
+    if !LEVELS.iter().any(|l| l.eq_ignore_ascii_case(&level)) {
+        return Err(format!("Unknown log level: {}", level));
+    }
+    EnvFilter::try_new(format!("warn,tempuz_scoreboard_lib={}", level))
+        .map_err(|e| format!("Invalid log filter: {}", e))
+}
+
+fn log_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = data_dir(app)
+        .map_err(|e| e.to_string())?;
+    Ok(app_data_dir.join(LOG_DIR))
+}
+
+pub fn init_logging(app: &AppHandle) {
+    let filter = std::env::var("RUST_LOG").ok()
+        .and_then(|directives| EnvFilter::try_new(directives).ok())
+        .or_else(|| filter_for_level(DEFAULT_LOG_LEVEL).ok())
+        .unwrap_or_default();
+    let (filter, handle) = reload::Layer::new(filter);
+
+    let file_layer = log_dir(app)
+        .and_then(|dir| {
+            RollingFileAppender::builder()
+                .rotation(Rotation::DAILY)
+                .filename_prefix(LOG_FILE_PREFIX)
+                .filename_suffix(LOG_FILE_SUFFIX)
+                .max_log_files(MAX_LOG_FILES)
+                .build(dir)
+                .map_err(|e| e.to_string())
+        })
+        .map(|appender| {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            if let Ok(mut stored) = LOG_GUARD.lock() {
+                *stored = Some(guard);
+            }
+            fmt::layer().with_writer(writer).with_ansi(false)
+        });
+
+    let file_error = file_layer.as_ref().err().cloned();
+    let result = tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt::layer())
+        .with(file_layer.ok())
+        .try_init();
+
+    if let Err(e) = result {
+        eprintln!("Failed to initialise logging: {}", e);
+        return;
+    }
+    if let Ok(mut stored) = LOG_FILTER.lock() {
+        *stored = Some(handle);
+    }
+    match file_error {
+        Some(e) => tracing::warn!("File logging disabled: {}", e),
+        None => info!("📝 Logging to {:?}", log_dir(app).unwrap_or_default()),
+    }
+}
+
+fn line_level(line: &str) -> Option<usize> {
+    let token = line.split_whitespace().nth(1)?;
+    LEVELS.iter().position(|level| *level == token)
+}
+
+// Last `lines` entries at or above `min_level`. Lines without a level (wrapped
+// messages) follow the entry they belong to.
+pub fn filter_log_lines(content: &str, lines: usize, min_level: Option<&str>) -> Result<Vec<String>, String> {
+    let min_index = match min_level {
+        Some(level) => LEVELS.iter()
+            .position(|l| l.eq_ignore_ascii_case(level))
+            .ok_or_else(|| format!("Unknown log level: {}", level))?,
+        None => 0,
+    };
+
+    let mut include = true;
+    let matching: Vec<&str> = content.lines()
+        .filter(|line| {
+            if let Some(index) = line_level(line) {
+                include = index >= min_index;
+            }
+            include
+        })
+        .collect();
+
+    let start = matching.len().saturating_sub(lines);
+    Ok(matching[start..].iter().map(|line| line.to_string()).collect())
+}
+
+// Up to `count` log files, newest first
+pub(crate) fn recent_log_files(app: &AppHandle, count: usize) -> Result<Vec<PathBuf>, String> {
+    let dir = log_dir(app)?;
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files: Vec<_> = std::fs::read_dir(&dir)
+        .map_err(|e| format!("Failed to read log directory: {}", e))?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(LOG_FILE_PREFIX))
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .collect();
+    files.sort_by_key(|(modified, _)| std::cmp::Reverse(*modified));
+    Ok(files.into_iter().take(count).map(|(_, path)| path).collect())
+}
+
+fn current_log_file(app: &AppHandle) -> Result<Option<PathBuf>, String> {
+    Ok(recent_log_files(app, 1)?.into_iter().next())
+}
+
+#[tauri::command]
+pub async fn set_log_level(level: String) -> Result<String, String> {
+    let filter = filter_for_level(&level)?;
+    let handle = LOG_FILTER.lock().map_err(|e| e.to_string())?.clone()
+        .ok_or("Logging is not initialised")?;
+    handle.reload(filter)
+        .map_err(|e| format!("Failed to change log level: {}", e))?;
+
+    info!("📝 Log level set to {}", level);
+    Ok(format!("Log level set to {}", level))
+}
+
+#[tauri::command]
+pub async fn get_recent_logs(
+    app: AppHandle,
+    lines: Option<usize>,
+    level_filter: Option<String>,
+) -> Result<Vec<String>, String> {
+    let Some(path) = current_log_file(&app)? else {
+        return Ok(Vec::new());
+    };
+
+    let content = tokio::fs::read_to_string(&path).await
+        .map_err(|e| format!("Failed to read log file: {}", e))?;
+    filter_log_lines(&content, lines.unwrap_or(DEFAULT_RECENT_LINES), level_filter.as_deref())
+}
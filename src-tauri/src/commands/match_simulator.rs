@@ -0,0 +1,440 @@
+// src-tauri/src/commands/match_simulator.rs
+//
+// Simulated tennis matches for rehearsals and layout testing. Each point is
+// written through `store_court_data` in the IonCourt match shape, so bindings,
+// the court sync service and display windows see it exactly like feed data.
+// The same seed always plays the same match, which keeps UI tests repeatable.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{debug, info};
+
+use crate::commands::live_data::store_court_data;
+use crate::error::AppError;
+
+// Seconds between points at speed 1.0 (a typical rally plus the gap before the next serve)
+const MIN_POINT_SECS: f64 = 15.0;
+const MAX_POINT_SECS: f64 = 35.0;
+// Extra pause on a changeover (after the first game of a set and every two games after)
+const CHANGEOVER_SECS: f64 = 90.0;
+const MIN_POINT_DELAY_MS: u64 = 20;
+const MAX_SPEED: f64 = 1000.0;
+// Chance the server wins a point; a little above even, as in real matches
+const SERVE_POINT_WIN_RATE: f64 = 0.62;
+
+const SIMULATED_PLAYERS: &[(&str, &str)] = &[
+    ("Ana", "Moreno"),
+    ("Lucas", "Berger"),
+    ("Mei", "Tanaka"),
+    ("Oliver", "Grant"),
+    ("Sofia", "Lindqvist"),
+    ("Mateo", "Rossi"),
+    ("Amara", "Okafor"),
+    ("Jonas", "Keller"),
+];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MatchSimulationOptions {
+    // Multiplier on real-time pacing; 10.0 plays a point every couple of seconds
+    pub speed: Option<f64>,
+    // Best-of sets: 1, 3 or 5
+    pub sets_to_play: Option<u32>,
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchSimulationStatus {
+    pub court_name: String,
+    pub connection_id: String,
+    pub seed: u64,
+    pub speed: f64,
+    pub sets_to_play: u32,
+    pub points_played: u32,
+    pub score: String,
+    pub is_completed: bool,
+    pub started_at: DateTime<Utc>,
+}
+
+lazy_static! {
+    static ref SIMULATIONS: Arc<Mutex<HashMap<String, JoinHandle<()>>>> = Arc::new(Mutex::new(HashMap::new()));
+    static ref SIMULATION_STATUS: Arc<Mutex<HashMap<String, MatchSimulationStatus>>> = Arc::new(Mutex::new(HashMap::new()));
+}
+
+// SplitMix64: small, fast, and stable across builds, which is all a reproducible rehearsal needs
+struct SimulationRng(u64);
+
+impl SimulationRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct SetScore {
+    games: [u32; 2],
+    tiebreak: Option<[u32; 2]>,
+    completed: bool,
+}
+
+// Score state of one match; sides are indexed 0 and 1
+#[derive(Debug, Clone)]
+pub struct SimulatedMatch {
+    sets_to_play: u32,
+    sets: Vec<SetScore>,
+    points: [u32; 2],
+    server: usize,
+    // Who served the first tiebreak point; the other side serves the next set
+    tiebreak_first_server: usize,
+    winner: Option<usize>,
+}
+
+impl SimulatedMatch {
+    pub fn new(sets_to_play: u32, first_server: usize) -> Self {
+        SimulatedMatch {
+            sets_to_play,
+            sets: vec![SetScore::default()],
+            points: [0, 0],
+            server: first_server,
+            tiebreak_first_server: first_server,
+            winner: None,
+        }
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.winner.is_some()
+    }
+
+    fn current_set(&self) -> &SetScore {
+        self.sets.last().expect("a match always has a set")
+    }
+
+    fn in_tiebreak(&self) -> bool {
+        self.current_set().tiebreak.is_some()
+    }
+
+    fn sets_won(&self, side: usize) -> u32 {
+        self.sets.iter()
+            .filter(|set| set.completed && set.games[side] > set.games[1 - side])
+            .count() as u32
+    }
+
+    // Side serving the next point. In a tiebreak the first server serves one
+    // point, then serve alternates every two points.
+    pub fn serving_side(&self) -> usize {
+        match self.current_set().tiebreak {
+            Some(tiebreak) => {
+                let played = tiebreak[0] + tiebreak[1];
+                if played == 0 || ((played - 1) / 2) % 2 == 1 {
+                    self.tiebreak_first_server
+                } else {
+                    1 - self.tiebreak_first_server
+                }
+            }
+            None => self.server,
+        }
+    }
+
+    // Whether the last game finished put the players at a changeover
+    fn at_changeover(&self) -> bool {
+        let set = self.current_set();
+        let games = set.games[0] + set.games[1];
+        self.points == [0, 0] && set.tiebreak.is_none() && games % 2 == 1
+    }
+
+    pub fn play_point(&mut self, winner: usize) {
+        if self.is_completed() {
+            return;
+        }
+
+        if self.in_tiebreak() {
+            let set = self.sets.last_mut().expect("a match always has a set");
+            let tiebreak = set.tiebreak.as_mut().expect("checked in_tiebreak");
+            tiebreak[winner] += 1;
+            if tiebreak[winner] >= 7 && tiebreak[winner] >= tiebreak[1 - winner] + 2 {
+                set.games[winner] += 1;
+                self.server = 1 - self.tiebreak_first_server;
+                self.finish_set();
+            }
+            return;
+        }
+
+        self.points[winner] += 1;
+        let (won, lost) = (self.points[winner], self.points[1 - winner]);
+        if won >= 4 && won >= lost + 2 {
+            self.points = [0, 0];
+            self.server = 1 - self.server;
+
+            let set = self.sets.last_mut().expect("a match always has a set");
+            set.games[winner] += 1;
+            let (won, lost) = (set.games[winner], set.games[1 - winner]);
+            if won >= 6 && won >= lost + 2 {
+                self.finish_set();
+            } else if set.games == [6, 6] {
+                set.tiebreak = Some([0, 0]);
+                self.tiebreak_first_server = self.server;
+            }
+        }
+    }
+
+    fn finish_set(&mut self) {
+        if let Some(set) = self.sets.last_mut() {
+            set.completed = true;
+        }
+        let needed = self.sets_to_play / 2 + 1;
+        self.winner = (0..2).find(|&side| self.sets_won(side) >= needed);
+        if self.winner.is_none() {
+            self.sets.push(SetScore::default());
+        }
+    }
+
+    // Point score as IonCourt shows it: 0/15/30/40/AD, or the count in a tiebreak
+    fn point_labels(&self) -> [String; 2] {
+        if let Some(tiebreak) = self.current_set().tiebreak {
+            return [tiebreak[0].to_string(), tiebreak[1].to_string()];
+        }
+        let [a, b] = self.points;
+        if a >= 3 && b >= 3 {
+            return match a.cmp(&b) {
+                std::cmp::Ordering::Greater => ["AD".to_string(), "40".to_string()],
+                std::cmp::Ordering::Less => ["40".to_string(), "AD".to_string()],
+                std::cmp::Ordering::Equal => ["40".to_string(), "40".to_string()],
+            };
+        }
+        let label = |points: u32| ["0", "15", "30", "40"][points.min(3) as usize].to_string();
+        [label(a), label(b)]
+    }
+
+    // e.g. "6-4 3-2 (30-15)" from one side's point of view
+    fn score_string(&self, side: usize) -> String {
+        let games: Vec<String> = self.sets.iter()
+            .map(|set| format!("{}-{}", set.games[side], set.games[1 - side]))
+            .collect();
+        if self.is_completed() {
+            return games.join(" ");
+        }
+        let points = self.point_labels();
+        format!("{} ({}-{})", games.join(" "), points[side], points[1 - side])
+    }
+
+    pub fn summary(&self) -> String {
+        self.score_string(0)
+    }
+
+    fn points_in_game(&self) -> u32 {
+        match self.current_set().tiebreak {
+            Some(tiebreak) => tiebreak[0] + tiebreak[1],
+            None => self.points[0] + self.points[1],
+        }
+    }
+}
+
+fn simulated_player(match_id: &str, side_number: usize, first_name: &str, last_name: &str) -> serde_json::Value {
+    let biographical_information = serde_json::json!({
+        "sex": null,
+        "playingHand": "R",
+        "doubleHandedForehand": false,
+        "doubleHandedBackhand": false,
+        "national": null,
+        "itf": null,
+        "atpwta": null,
+        "utr": null
+    });
+    serde_json::json!({
+        "sideNumber": side_number,
+        "participant": null,
+        "note": "",
+        "_id": format!("{}-side{}", match_id, side_number),
+        "players": [{
+            "playerNumber": 1,
+            "participant": {
+                "_id": format!("{}-player{}", match_id, side_number),
+                "first_name": first_name,
+                "last_name": last_name,
+                "biographicalInformation": biographical_information
+            },
+            "biographicalInformation": biographical_information
+        }]
+    })
+}
+
+// The match in the shape of an IonCourt MATCH message's `data`
+pub fn simulated_match_data(
+    court_name: &str,
+    match_id: &str,
+    players: [(&str, &str); 2],
+    state: &SimulatedMatch,
+) -> serde_json::Value {
+    let points = state.point_labels();
+    let server = state.serving_side();
+    let sets: Vec<serde_json::Value> = state.sets.iter().enumerate()
+        .map(|(index, set)| serde_json::json!({
+            "setNumber": index + 1,
+            "side1Score": set.games[0],
+            "side1TiebreakScore": set.tiebreak.map(|t| t[0]),
+            "side2Score": set.games[1],
+            "side2TiebreakScore": set.tiebreak.map(|t| t[1]),
+            "_id": format!("{}-set{}", match_id, index + 1),
+            "games": [],
+            "returnerCourtSides": [],
+            "serverPickleballOrders": [],
+            "isCompleted": set.completed
+        }))
+        .collect();
+
+    serde_json::json!({
+        "id": match_id,
+        "matchId": match_id,
+        "matchFormat": format!("SET{}-S:6/TB7@6", state.sets_to_play),
+        "matchStatus": if state.is_completed() { "COMPLETED" } else { "IN_PROGRESS" },
+        "matchType": "SINGLES",
+        "court": court_name,
+        "sides": [
+            simulated_player(match_id, 1, players[0].0, players[0].1),
+            simulated_player(match_id, 2, players[1].0, players[1].1),
+        ],
+        "score": {
+            "scoreStringSide1": state.score_string(0),
+            "scoreStringSide2": state.score_string(1),
+            "side1PointScore": points[0],
+            "side2PointScore": points[1],
+            "server": {
+                "sideNumber": server + 1,
+                "playerNumber": 1,
+                "player": format!("{}-player{}", match_id, server + 1),
+                "returningSide": if state.points_in_game().is_multiple_of(2) { "DEUCE" } else { "AD" },
+                "_id": format!("{}-server", match_id)
+            },
+            "sets": sets,
+            "_id": format!("{}-score", match_id)
+        },
+        "clocks": [],
+        "isUndo": false,
+        "isStartPoint": false,
+        "isEndPoint": state.is_completed()
+    })
+}
+
+fn simulation_connection_id(court_name: &str) -> String {
+    format!("simulator:{}", court_name)
+}
+
+fn point_delay(rng: &mut SimulationRng, speed: f64, changeover: bool) -> Duration {
+    let mut seconds = MIN_POINT_SECS + rng.next_f64() * (MAX_POINT_SECS - MIN_POINT_SECS);
+    if changeover {
+        seconds += CHANGEOVER_SECS;
+    }
+    let millis = (seconds * 1000.0 / speed) as u64;
+    Duration::from_millis(millis.max(MIN_POINT_DELAY_MS))
+}
+
+async fn update_status(court_name: &str, update: impl FnOnce(&mut MatchSimulationStatus)) {
+    if let Some(status) = SIMULATION_STATUS.lock().await.get_mut(court_name) {
+        update(status);
+    }
+}
+
+#[tauri::command]
+pub async fn start_match_simulation(court_name: String, options: Option<MatchSimulationOptions>) -> Result<MatchSimulationStatus, AppError> {
+    let court_name = court_name.trim().to_string();
+    if court_name.is_empty() {
+        return Err(AppError::validation("court_name", "Court name is required"));
+    }
+
+    let options = options.unwrap_or_default();
+    let speed = options.speed.unwrap_or(1.0);
+    if !(speed > 0.0 && speed <= MAX_SPEED) {
+        return Err(AppError::validation("speed", format!("Speed must be above 0 and at most {}", MAX_SPEED)));
+    }
+    let sets_to_play = options.sets_to_play.unwrap_or(3);
+    if !matches!(sets_to_play, 1 | 3 | 5) {
+        return Err(AppError::validation("sets_to_play", "Sets to play must be 1, 3 or 5"));
+    }
+    let seed = options.seed.unwrap_or_else(|| Utc::now().timestamp_nanos_opt().unwrap_or_default() as u64);
+
+    let mut simulations = SIMULATIONS.lock().await;
+    if simulations.get(&court_name).map(|handle| !handle.is_finished()).unwrap_or(false) {
+        return Err(AppError::conflict(format!("A simulation is already running on court: {}", court_name)));
+    }
+
+    let mut rng = SimulationRng(seed);
+    let first = (rng.next_u64() % SIMULATED_PLAYERS.len() as u64) as usize;
+    let second = (first + 1 + (rng.next_u64() % (SIMULATED_PLAYERS.len() as u64 - 1)) as usize) % SIMULATED_PLAYERS.len();
+    let players = [SIMULATED_PLAYERS[first], SIMULATED_PLAYERS[second]];
+    let mut state = SimulatedMatch::new(sets_to_play, (rng.next_u64() % 2) as usize);
+    let match_id = format!("sim-{:016x}", seed);
+    let connection_id = simulation_connection_id(&court_name);
+
+    let status = MatchSimulationStatus {
+        court_name: court_name.clone(),
+        connection_id: connection_id.clone(),
+        seed,
+        speed,
+        sets_to_play,
+        points_played: 0,
+        score: state.summary(),
+        is_completed: false,
+        started_at: Utc::now(),
+    };
+    SIMULATION_STATUS.lock().await.insert(court_name.clone(), status.clone());
+
+    let task_court = court_name.clone();
+    let handle = tokio::spawn(async move {
+        store_court_data(&connection_id, &task_court, simulated_match_data(&task_court, &match_id, players, &state)).await;
+
+        while !state.is_completed() {
+            tokio::time::sleep(point_delay(&mut rng, speed, state.at_changeover())).await;
+
+            let server = state.serving_side();
+            let winner = if rng.next_f64() < SERVE_POINT_WIN_RATE { server } else { 1 - server };
+            state.play_point(winner);
+
+            let data = simulated_match_data(&task_court, &match_id, players, &state);
+            if !store_court_data(&connection_id, &task_court, data).await {
+                debug!("⏭️ [SIMULATOR {}] Court is owned by a higher-priority connection, point not stored", task_court);
+            }
+            update_status(&task_court, |status| {
+                status.points_played += 1;
+                status.score = state.summary();
+                status.is_completed = state.is_completed();
+            }).await;
+        }
+
+        info!("🏁 [SIMULATOR {}] Match finished: {}", task_court, state.summary());
+    });
+
+    simulations.insert(court_name.clone(), handle);
+    info!("🎾 Started match simulation on court '{}' (seed {}, speed {}x, best of {})", court_name, seed, speed, sets_to_play);
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn stop_match_simulation(court_name: String) -> Result<String, AppError> {
+    let handle = SIMULATIONS.lock().await.remove(&court_name)
+        .ok_or_else(|| AppError::not_found(format!("Simulation for court '{}'", court_name)))?;
+    handle.abort();
+    SIMULATION_STATUS.lock().await.remove(&court_name);
+    info!("🛑 Stopped match simulation on court '{}'", court_name);
+    Ok(format!("Stopped match simulation for: {}", court_name))
+}
+
+// Finished matches stay listed (with is_completed set) until stopped
+#[tauri::command]
+pub async fn list_running_simulations() -> Result<Vec<MatchSimulationStatus>, AppError> {
+    let mut statuses: Vec<MatchSimulationStatus> = SIMULATION_STATUS.lock().await.values().cloned().collect();
+    statuses.sort_by(|a, b| a.court_name.cmp(&b.court_name));
+    Ok(statuses)
+}
@@ -0,0 +1,233 @@
+// src-tauri/src/commands/match_timer.rs
+//
+// Wall-clock match timers for feeds that don't send a clock. A timer only
+// records when it was last started and what it had accumulated before that,
+// so a feed dropping out doesn't affect it, and the timers are saved to disk
+// so an app restart mid-match carries on from the same elapsed time.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use tauri::AppHandle;
+use tokio::sync::Mutex;
+use tracing::{error, info, warn};
+
+use crate::commands::data_dir::data_dir;
+use crate::commands::events::BackendEvent;
+use crate::commands::media_library::write_atomic;
+use crate::commands::window_updates::emit_to_unpaused;
+use crate::error::AppError;
+
+const TIMERS_FILE: &str = "match_timers.json";
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchTimer {
+    pub match_id: String,
+    // First start; kept across stops and restarts
+    pub started_at: DateTime<Utc>,
+    // Elapsed time from earlier runs, before `running_since`
+    pub accumulated_ms: u64,
+    // None while stopped
+    pub running_since: Option<DateTime<Utc>>,
+}
+
+impl MatchTimer {
+    pub fn elapsed_ms_at(&self, now: DateTime<Utc>) -> u64 {
+        let current_run = self.running_since
+            .map(|since| (now - since).num_milliseconds().max(0) as u64)
+            .unwrap_or(0);
+        self.accumulated_ms + current_run
+    }
+
+    // Returns false if it was already running
+    pub fn start(&mut self, now: DateTime<Utc>) -> bool {
+        if self.running_since.is_some() {
+            return false;
+        }
+        self.running_since = Some(now);
+        true
+    }
+
+    // Folds the current run into `accumulated_ms`; returns false if it was already stopped
+    pub fn stop(&mut self, now: DateTime<Utc>) -> bool {
+        if self.running_since.is_none() {
+            return false;
+        }
+        self.accumulated_ms = self.elapsed_ms_at(now);
+        self.running_since = None;
+        true
+    }
+}
+
+// Payload of `match_elapsed`, sent every second for each running timer and on start/stop
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchElapsedEvent {
+    pub match_id: String,
+    pub elapsed_ms: u64,
+    pub running: bool,
+}
+
+lazy_static! {
+    static ref MATCH_TIMERS: Arc<Mutex<HashMap<String, MatchTimer>>> = Arc::new(Mutex::new(HashMap::new()));
+}
+
+fn elapsed_event(timer: &MatchTimer, now: DateTime<Utc>) -> MatchElapsedEvent {
+    MatchElapsedEvent {
+        match_id: timer.match_id.clone(),
+        elapsed_ms: timer.elapsed_ms_at(now),
+        running: timer.running_since.is_some(),
+    }
+}
+
+fn emit_elapsed(app: &AppHandle, event: &MatchElapsedEvent) {
+    if let Err(e) = emit_to_unpaused(app, BackendEvent::MatchElapsed, event) {
+        error!("Failed to emit {}: {}", BackendEvent::MatchElapsed, e);
+    }
+}
+
+async fn save_timers(app: &AppHandle, timers: &HashMap<String, MatchTimer>) -> Result<(), AppError> {
+    let mut sorted: Vec<&MatchTimer> = timers.values().collect();
+    sorted.sort_by(|a, b| a.match_id.cmp(&b.match_id));
+    write_atomic(&data_dir(app)?.join(TIMERS_FILE), &serde_json::to_vec_pretty(&sorted)?).await
+}
+
+async fn load_timers(app: &AppHandle) -> Result<(), AppError> {
+    let path = data_dir(app)?.join(TIMERS_FILE);
+    if !tokio::fs::try_exists(&path).await? {
+        return Ok(());
+    }
+    let timers: Vec<MatchTimer> = serde_json::from_str(&tokio::fs::read_to_string(&path).await?)?;
+    let mut stored = MATCH_TIMERS.lock().await;
+    for timer in timers {
+        stored.insert(timer.match_id.clone(), timer);
+    }
+    Ok(())
+}
+
+// Restores saved timers, then emits `match_elapsed` for every running one each second
+pub fn start_match_timers(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = load_timers(&app).await {
+            warn!("Could not restore match timers: {}", e);
+        }
+
+        let mut interval = tokio::time::interval(TICK_INTERVAL);
+        loop {
+            interval.tick().await;
+            let now = Utc::now();
+            let events: Vec<MatchElapsedEvent> = MATCH_TIMERS.lock().await.values()
+                .filter(|timer| timer.running_since.is_some())
+                .map(|timer| elapsed_event(timer, now))
+                .collect();
+            for event in &events {
+                emit_elapsed(&app, event);
+            }
+        }
+    });
+}
+
+fn validate_match_id(match_id: &str) -> Result<String, AppError> {
+    let match_id = match_id.trim();
+    if match_id.is_empty() {
+        return Err(AppError::validation("match_id", "Match id is required"));
+    }
+    Ok(match_id.to_string())
+}
+
+// Starts a new timer, or resumes a stopped one from where it left off
+#[tauri::command]
+pub async fn start_match_timer(app: AppHandle, match_id: String) -> Result<MatchElapsedEvent, AppError> {
+    let match_id = validate_match_id(&match_id)?;
+    let now = Utc::now();
+
+    let mut timers = MATCH_TIMERS.lock().await;
+    let timer = timers.entry(match_id.clone()).or_insert_with(|| MatchTimer {
+        match_id: match_id.clone(),
+        started_at: now,
+        accumulated_ms: 0,
+        running_since: None,
+    });
+    if timer.start(now) {
+        info!("⏱️ Match timer started for {} at {}ms", match_id, timer.accumulated_ms);
+    }
+    let event = elapsed_event(timer, now);
+    save_timers(&app, &timers).await?;
+    drop(timers);
+
+    emit_elapsed(&app, &event);
+    Ok(event)
+}
+
+// Pauses the timer, keeping its elapsed time
+#[tauri::command]
+pub async fn stop_match_timer(app: AppHandle, match_id: String) -> Result<MatchElapsedEvent, AppError> {
+    let now = Utc::now();
+    let mut timers = MATCH_TIMERS.lock().await;
+    let timer = timers.get_mut(match_id.trim())
+        .ok_or_else(|| AppError::not_found(format!("Match timer '{}'", match_id)))?;
+    if timer.stop(now) {
+        info!("⏱️ Match timer stopped for {} at {}ms", timer.match_id, timer.accumulated_ms);
+    }
+    let event = elapsed_event(timer, now);
+    save_timers(&app, &timers).await?;
+    drop(timers);
+
+    emit_elapsed(&app, &event);
+    Ok(event)
+}
+
+#[tauri::command]
+pub async fn get_match_elapsed(match_id: String) -> Result<MatchElapsedEvent, AppError> {
+    let timers = MATCH_TIMERS.lock().await;
+    let timer = timers.get(match_id.trim())
+        .ok_or_else(|| AppError::not_found(format!("Match timer '{}'", match_id)))?;
+    Ok(elapsed_event(timer, Utc::now()))
+}
+
+// Removes the timer entirely, so the next start counts from zero
+#[tauri::command]
+pub async fn reset_match_timer(app: AppHandle, match_id: String) -> Result<(), AppError> {
+    let mut timers = MATCH_TIMERS.lock().await;
+    if timers.remove(match_id.trim()).is_none() {
+        return Err(AppError::not_found(format!("Match timer '{}'", match_id)));
+    }
+    save_timers(&app, &timers).await?;
+    info!("⏱️ Match timer reset for {}", match_id.trim());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elapsed_counts_only_running_time() {
+        let t0 = DateTime::parse_from_rfc3339("2024-06-01T12:00:00Z").unwrap().with_timezone(&Utc);
+        let at = |seconds: i64| t0 + chrono::Duration::seconds(seconds);
+        let mut timer = MatchTimer {
+            match_id: "match-1".to_string(),
+            started_at: t0,
+            accumulated_ms: 0,
+            running_since: None,
+        };
+
+        assert!(timer.start(t0));
+        assert!(!timer.start(at(30)));
+        assert_eq!(timer.elapsed_ms_at(at(90)), 90_000);
+
+        assert!(timer.stop(at(90)));
+        assert!(!timer.stop(at(100)));
+        assert_eq!(timer.elapsed_ms_at(at(200)), 90_000);
+
+        assert!(timer.start(at(300)));
+        assert_eq!(timer.elapsed_ms_at(at(310)), 100_000);
+        // A clock that steps backwards doesn't take time off
+        assert_eq!(timer.elapsed_ms_at(at(250)), 90_000);
+    }
+}
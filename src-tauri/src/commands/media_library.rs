@@ -0,0 +1,125 @@
+// src-tauri/src/commands/media_library.rs
+//
+// Coordination for the image and video libraries' metadata.json files. Every
+// change is a load/mutate/save of the whole file, so two uploads landing at
+// once would each save a list missing the other's entry. Each library has one
+// async lock held across that sequence, and saves go through a temp file so a
+// crash mid-write leaves the previous metadata intact.
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::Path;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+use crate::error::AppError;
+
+// Managed state; one lock per library directory
+#[derive(Default)]
+pub struct MediaLibraryLocks {
+    pub images: Mutex<()>,
+    pub videos: Mutex<()>,
+}
+
+// Replace `path` with `contents` by writing a sibling temp file and renaming it over
+pub(crate) async fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), AppError> {
+    let file_name = path.file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| AppError::validation("path", format!("{:?} has no file name", path)))?;
+    let temp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, uuid::Uuid::new_v4()));
+
+    let result = async {
+        let mut file = fs::File::create(&temp_path).await?;
+        file.write_all(contents).await?;
+        file.sync_all().await?;
+        drop(file);
+        fs::rename(&temp_path, path).await
+    }.await;
+
+    if let Err(e) = result {
+        let _ = fs::remove_file(&temp_path).await;
+        return Err(AppError::io(format!("Failed to write {:?}: {}", path, e)));
+    }
+    Ok(())
+}
+
+pub(crate) async fn read_metadata_file<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>, AppError> {
+    if !fs::try_exists(path).await? {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(path).await?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+// Load the metadata list at `path`, apply `mutate` and save it, all under
+// `lock`. Nothing is saved if `mutate` fails.
+pub(crate) async fn update_metadata_file<T, R>(
+    lock: &Mutex<()>,
+    path: &Path,
+    mutate: impl FnOnce(&mut Vec<T>) -> Result<R, AppError>,
+) -> Result<R, AppError>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let _guard = lock.lock().await;
+    let mut entries = read_metadata_file(path).await
+        .map_err(|e| AppError::io(format!("Failed to load metadata: {}", e)))?;
+    let result = mutate(&mut entries)?;
+    let content = serde_json::to_string_pretty(&entries)?;
+    write_atomic(path, content.as_bytes()).await
+        .map_err(|e| AppError::io(format!("Failed to save metadata: {}", e)))?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn concurrent_updates_keep_every_entry() {
+        let dir = std::env::temp_dir().join(format!("media_library_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).await.unwrap();
+        let path = Arc::new(dir.join("metadata.json"));
+        let locks = Arc::new(MediaLibraryLocks::default());
+
+        let uploads: Vec<_> = (0..20)
+            .map(|i| {
+                let (locks, path) = (Arc::clone(&locks), Arc::clone(&path));
+                tokio::spawn(async move {
+                    update_metadata_file(&locks.images, &path, |entries: &mut Vec<String>| {
+                        entries.push(format!("image-{}", i));
+                        Ok(())
+                    }).await
+                })
+            })
+            .collect();
+        for upload in uploads {
+            upload.await.unwrap().unwrap();
+        }
+
+        let mut entries: Vec<String> = read_metadata_file(&path).await.unwrap();
+        entries.sort();
+        entries.dedup();
+        assert_eq!(entries.len(), 20);
+
+        let _ = fs::remove_dir_all(&dir).await;
+    }
+
+    #[tokio::test]
+    async fn failed_update_saves_nothing() {
+        let dir = std::env::temp_dir().join(format!("media_library_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).await.unwrap();
+        let path = dir.join("metadata.json");
+        let lock = Mutex::new(());
+
+        let result = update_metadata_file(&lock, &path, |entries: &mut Vec<String>| {
+            entries.push("half-done".to_string());
+            Err::<(), _>(AppError::validation("file", "rejected"))
+        }).await;
+        assert!(result.is_err());
+        assert!(!path.exists());
+
+        let _ = fs::remove_dir_all(&dir).await;
+    }
+}
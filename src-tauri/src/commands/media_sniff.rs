@@ -0,0 +1,156 @@
+// src-tauri/src/commands/media_sniff.rs
+//
+// Content-based type detection for uploads. The client's file_type and
+// extension are only hints; what gets stored (and later served back to the
+// webviews as a data URI) is decided by the file's leading bytes.
+use tracing::warn;
+
+use crate::error::AppError;
+
+pub const ALLOWED_IMAGE_TYPES: [&str; 5] = ["image/png", "image/jpeg", "image/gif", "image/webp", "image/svg+xml"];
+pub const ALLOWED_VIDEO_TYPES: [&str; 3] = ["video/mp4", "video/webm", "video/quicktime"];
+
+// How far into a Matroska header to look for the doctype
+const EBML_HEADER_SCAN: usize = 64;
+
+fn looks_like_svg(data: &[u8]) -> bool {
+    // The prefix may end mid-character, so decode lossily
+    let text = String::from_utf8_lossy(&data[..data.len().min(4096)]).to_lowercase();
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    (text.starts_with("<svg") || text.starts_with("<?xml") || text.starts_with("<!doctype svg") || text.starts_with("<!--"))
+        && text.contains("<svg")
+}
+
+// Best guess at the MIME type of `data`, including types we don't accept, so
+// rejections can name what was actually uploaded
+pub fn detect_mime(data: &[u8]) -> &'static str {
+    let starts = |magic: &[u8]| data.starts_with(magic);
+    let at = |offset: usize, magic: &[u8]| data.get(offset..offset + magic.len()) == Some(magic);
+
+    if starts(b"\x89PNG\r\n\x1a\n") {
+        "image/png"
+    } else if starts(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else if starts(b"GIF87a") || starts(b"GIF89a") {
+        "image/gif"
+    } else if starts(b"RIFF") && at(8, b"WEBP") {
+        "image/webp"
+    } else if starts(b"RIFF") && at(8, b"AVI ") {
+        "video/x-msvideo"
+    } else if at(4, b"ftyp") {
+        if at(8, b"qt  ") { "video/quicktime" } else { "video/mp4" }
+    } else if starts(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        let header = &data[..data.len().min(EBML_HEADER_SCAN)];
+        if header.windows(4).any(|w| w == b"webm") { "video/webm" } else { "video/x-matroska" }
+    } else if starts(b"MZ") {
+        "application/x-msdownload"
+    } else if starts(b"\x7fELF") {
+        "application/x-elf"
+    } else if starts(b"%PDF") {
+        "application/pdf"
+    } else if starts(b"PK\x03\x04") {
+        "application/zip"
+    } else if looks_like_svg(data) {
+        "image/svg+xml"
+    } else if std::str::from_utf8(&data[..data.len().min(1024)]).is_ok() {
+        "text/plain"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+// The detected type, if it's one of `allowed`; otherwise an error naming what was found
+pub fn verify_media_type(data: &[u8], claimed: &str, allowed: &[&str]) -> Result<&'static str, AppError> {
+    let detected = detect_mime(data);
+    if !allowed.contains(&detected) {
+        return Err(AppError::unsupported_media_type(detected, claimed));
+    }
+    if !claimed.eq_ignore_ascii_case(detected) {
+        warn!("⚠️ Upload claimed {} but contains {}; storing as {}", claimed, detected, detected);
+    }
+    Ok(detected)
+}
+
+// Byte offset of lowercase `needle` in `haystack` at or after `from`, ignoring ASCII case
+fn find_ignore_case(haystack: &str, needle: &str, from: usize) -> Option<usize> {
+    haystack.get(from..)?
+        .to_ascii_lowercase()
+        .find(needle)
+        .map(|i| i + from)
+}
+
+// Strip what lets an SVG run script when shown in a webview: <script> elements,
+// on* event attributes and javascript: URLs
+pub fn sanitize_svg(svg: &str) -> String {
+    let mut out = svg.to_string();
+
+    while let Some(start) = find_ignore_case(&out, "<script", 0) {
+        let end = find_ignore_case(&out, "</script", start)
+            .and_then(|close| out[close..].find('>').map(|i| close + i + 1))
+            .or_else(|| out[start..].find("/>").map(|i| start + i + 2))
+            .unwrap_or(out.len());
+        out.replace_range(start..end, "");
+    }
+
+    let mut search_from = 0;
+    while let Some(index) = find_ignore_case(&out, "on", search_from) {
+        search_from = index + 2;
+        let preceded_by_space = out[..index].chars().last().is_some_and(|c| c.is_ascii_whitespace());
+        if !preceded_by_space {
+            continue;
+        }
+        let name_end = out[index + 2..]
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .map_or(out.len(), |i| index + 2 + i);
+        if name_end == index + 2 {
+            continue;
+        }
+        let rest = out[name_end..].trim_start();
+        let Some(value) = rest.strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let value_start = out.len() - value.len();
+        let value_end = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].find(quote).map_or(out.len(), |i| value_start + i + 2),
+            _ => value.find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+                .map_or(out.len(), |i| value_start + i),
+        };
+        out.replace_range(index..value_end, "");
+        search_from = index;
+    }
+
+    while let Some(index) = find_ignore_case(&out, "javascript:", 0) {
+        out.replace_range(index..index + "javascript:".len(), "");
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    #[test]
+    fn png_named_mp4_is_detected_as_png() {
+        assert_eq!(detect_mime(PNG), "image/png");
+        assert!(matches!(
+            verify_media_type(PNG, "video/mp4", &ALLOWED_VIDEO_TYPES),
+            Err(AppError::UnsupportedMediaType { .. })
+        ));
+        // A mislabelled image is still stored, under its real type
+        assert_eq!(verify_media_type(PNG, "image/jpeg", &ALLOWED_IMAGE_TYPES).unwrap(), "image/png");
+    }
+
+    #[test]
+    fn text_claiming_png_is_rejected() {
+        let text = b"just some notes, definitely not an image";
+        assert_eq!(detect_mime(text), "text/plain");
+        assert!(matches!(
+            verify_media_type(text, "image/png", &ALLOWED_IMAGE_TYPES),
+            Err(AppError::UnsupportedMediaType { .. })
+        ));
+    }
+}
@@ -0,0 +1,179 @@
+// src-tauri/src/commands/memory_estimate.rs
+//
+// Rough memory footprint of a board once it is open in a display window, so
+// operators can see what a video wall will cost before opening it.
+// The figures are estimates from stored sizes and layout, not measurements.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tauri::AppHandle;
+use tracing::warn;
+
+use crate::commands::images::load_image_metadata;
+use crate::commands::storage::read_scoreboard_config;
+use crate::commands::videos::load_video_metadata;
+use crate::error::AppError;
+
+const MB: u64 = 1024 * 1024;
+const DEFAULT_WARNING_THRESHOLD_MB: u64 = 512;
+// Decoded RGBA bitmaps are typically several times larger than the compressed file
+const IMAGE_DECODE_FACTOR: u64 = 4;
+// Frames a video element keeps decoded at once
+const VIDEO_FRAME_BUFFERS: u64 = 8;
+// Compressed video the webview keeps buffered ahead of playback, at most
+const MAX_VIDEO_BUFFER_BYTES: u64 = 64 * MB;
+// DOM, style and layer bookkeeping per component
+const COMPONENT_OVERHEAD_BYTES: u64 = 64 * 1024;
+// Baseline for an empty webview window
+const WINDOW_BASELINE_BYTES: u64 = 60 * MB;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaEstimate {
+    pub component_id: String,
+    pub media_id: String,
+    pub kind: String,
+    pub file_bytes: u64,
+    pub estimated_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryEstimate {
+    pub total_bytes: u64,
+    pub window_bytes: u64,
+    pub component_bytes: u64,
+    pub image_bytes: u64,
+    pub video_bytes: u64,
+    pub media: Vec<MediaEstimate>,
+    // Referenced media that is no longer in the store
+    pub missing_media: Vec<String>,
+    pub warning_threshold_bytes: u64,
+    pub warning: Option<String>,
+}
+
+fn bitmap_bytes(width: f64, height: f64) -> u64 {
+    (width.max(0.0) * height.max(0.0) * 4.0) as u64
+}
+
+// `image_sizes` and `video_sizes` map stored media IDs to file sizes in bytes
+pub fn estimate_memory(
+    data: &serde_json::Value,
+    image_sizes: &HashMap<String, u64>,
+    video_sizes: &HashMap<String, u64>,
+    warning_threshold_bytes: u64,
+) -> MemoryEstimate {
+    let dimensions = data.get("dimensions");
+    let canvas_width = dimensions.and_then(|d| d.get("width")).and_then(|w| w.as_f64()).unwrap_or(1920.0);
+    let canvas_height = dimensions.and_then(|d| d.get("height")).and_then(|h| h.as_f64()).unwrap_or(1080.0);
+    // The composited window surface, double buffered
+    let window_bytes = WINDOW_BASELINE_BYTES + bitmap_bytes(canvas_width, canvas_height) * 2;
+
+    let components: &[serde_json::Value] = data.get("components")
+        .and_then(|c| c.as_array())
+        .map(|c| c.as_slice())
+        .unwrap_or_default();
+    let component_bytes = components.len() as u64 * COMPONENT_OVERHEAD_BYTES;
+
+    let mut media: Vec<MediaEstimate> = Vec::new();
+    let mut missing_media = Vec::new();
+    // The webview decodes each image once however many components show it
+    let mut counted_images: HashMap<&str, usize> = HashMap::new();
+
+    for component in components {
+        let component_id = component.get("id").and_then(|id| id.as_str()).unwrap_or("").to_string();
+        let size = component.get("size");
+        let width = size.and_then(|s| s.get("width")).and_then(|w| w.as_f64()).unwrap_or(0.0);
+        let height = size.and_then(|s| s.get("height")).and_then(|h| h.as_f64()).unwrap_or(0.0);
+        let component_data = component.get("data");
+
+        if let Some(image_id) = component_data.and_then(|d| d.get("imageId")).and_then(|id| id.as_str()) {
+            match image_sizes.get(image_id) {
+                Some(&file_bytes) => {
+                    let estimated_bytes = (file_bytes * IMAGE_DECODE_FACTOR).max(bitmap_bytes(width, height));
+                    match counted_images.get(image_id) {
+                        // Keep the larger estimate for shared images, counted once
+                        Some(&index) => {
+                            media[index].estimated_bytes = media[index].estimated_bytes.max(estimated_bytes);
+                        }
+                        None => {
+                            counted_images.insert(image_id, media.len());
+                            media.push(MediaEstimate {
+                                component_id: component_id.clone(),
+                                media_id: image_id.to_string(),
+                                kind: "image".to_string(),
+                                file_bytes,
+                                estimated_bytes,
+                            });
+                        }
+                    }
+                }
+                None => missing_media.push(image_id.to_string()),
+            }
+        }
+
+        if let Some(video_id) = component_data.and_then(|d| d.get("videoId")).and_then(|id| id.as_str()) {
+            match video_sizes.get(video_id) {
+                // Every video element decodes independently, even for the same file
+                Some(&file_bytes) => media.push(MediaEstimate {
+                    component_id: component_id.clone(),
+                    media_id: video_id.to_string(),
+                    kind: "video".to_string(),
+                    file_bytes,
+                    estimated_bytes: file_bytes.min(MAX_VIDEO_BUFFER_BYTES)
+                        + bitmap_bytes(width, height) * VIDEO_FRAME_BUFFERS,
+                }),
+                None => missing_media.push(video_id.to_string()),
+            }
+        }
+    }
+
+    let sum_kind = |kind: &str| media.iter().filter(|m| m.kind == kind).map(|m| m.estimated_bytes).sum::<u64>();
+    let image_bytes = sum_kind("image");
+    let video_bytes = sum_kind("video");
+    let total_bytes = window_bytes + component_bytes + image_bytes + video_bytes;
+
+    let warning = (total_bytes > warning_threshold_bytes).then(|| format!(
+        "This board needs ~{}MB, above the {}MB warning threshold",
+        total_bytes / MB,
+        warning_threshold_bytes / MB
+    ));
+
+    MemoryEstimate {
+        total_bytes,
+        window_bytes,
+        component_bytes,
+        image_bytes,
+        video_bytes,
+        media,
+        missing_media,
+        warning_threshold_bytes,
+        warning,
+    }
+}
+
+#[tauri::command]
+pub async fn estimate_scoreboard_memory(
+    app: AppHandle,
+    filename: String,
+    warning_threshold_mb: Option<u64>,
+) -> Result<MemoryEstimate, AppError> {
+    let (_, config) = read_scoreboard_config(&app, &filename)?;
+
+    let image_sizes: HashMap<String, u64> = load_image_metadata(&app).await?
+        .into_iter()
+        .map(|image| (image.id, image.size))
+        .collect();
+    let video_sizes: HashMap<String, u64> = load_video_metadata(&app).await?
+        .into_iter()
+        .map(|video| (video.id, video.size))
+        .collect();
+
+    let threshold = warning_threshold_mb.unwrap_or(DEFAULT_WARNING_THRESHOLD_MB) * MB;
+    let estimate = estimate_memory(&config.data, &image_sizes, &video_sizes, threshold);
+
+    if let Some(warning) = &estimate.warning {
+        warn!("⚠️ {}: {}", filename, warning);
+    }
+
+    Ok(estimate)
+}
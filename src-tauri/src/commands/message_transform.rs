@@ -0,0 +1,169 @@
+// src-tauri/src/commands/message_transform.rs
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use lazy_static::lazy_static;
+use tracing::info;
+
+use crate::error::AppError;
+
+// Paths are dot-separated object keys, e.g. "payload.match.court".
+// An empty path refers to the whole message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum TransformRule {
+    // Replace the whole message with the value at `path` (strip an envelope)
+    Unwrap { path: String },
+    // Move a value to a new path, creating intermediate objects as needed
+    Move { from: String, to: String },
+    // Rename a key in place, keeping it under the same parent
+    Rename { path: String, to: String },
+    // Write a constant, e.g. {"op": "set", "path": "type", "value": "MATCH"}
+    Set { path: String, value: serde_json::Value },
+    Delete { path: String },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MessageTransform {
+    pub rules: Vec<TransformRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransformTestResult {
+    pub output: serde_json::Value,
+    pub is_match_message: bool,
+    pub court: Option<String>,
+}
+
+lazy_static! {
+    static ref CONNECTION_TRANSFORMS: Arc<Mutex<HashMap<String, MessageTransform>>> = Arc::new(Mutex::new(HashMap::new()));
+}
+
+fn split_path(path: &str) -> Vec<&str> {
+    path.split('.').filter(|segment| !segment.is_empty()).collect()
+}
+
+fn take_at(value: &mut serde_json::Value, path: &str) -> Option<serde_json::Value> {
+    let segments = split_path(path);
+    let (last, parents) = segments.split_last()?;
+
+    let mut current = value;
+    for segment in parents {
+        current = current.get_mut(*segment)?;
+    }
+    current.as_object_mut()?.remove(*last)
+}
+
+fn put_at(value: &mut serde_json::Value, path: &str, new_value: serde_json::Value) -> Result<(), AppError> {
+    let segments = split_path(path);
+    let Some((last, parents)) = segments.split_last() else {
+        *value = new_value;
+        return Ok(());
+    };
+
+    let mut current = value;
+    for segment in parents {
+        let object = current.as_object_mut()
+            .ok_or_else(|| AppError::validation("path", format!("Cannot write below non-object at '{}'", segment)))?;
+        current = object.entry(segment.to_string())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+    }
+
+    current.as_object_mut()
+        .ok_or_else(|| AppError::validation("path", format!("Cannot write '{}': parent is not an object", path)))?
+        .insert(last.to_string(), new_value);
+    Ok(())
+}
+
+fn apply_rule(message: &mut serde_json::Value, rule: &TransformRule) -> Result<(), AppError> {
+    match rule {
+        TransformRule::Unwrap { path } => {
+            let inner = take_at(message, path)
+                .ok_or_else(|| AppError::validation("path", format!("Unwrap path '{}' not found", path)))?;
+            *message = inner;
+        }
+        TransformRule::Move { from, to } => {
+            // A missing source is not an error: feeds often omit optional fields
+            if let Some(value) = take_at(message, from) {
+                put_at(message, to, value)?;
+            }
+        }
+        TransformRule::Rename { path, to } => {
+            if let Some(value) = take_at(message, path) {
+                let mut segments = split_path(path);
+                segments.pop();
+                segments.push(to.as_str());
+                put_at(message, &segments.join("."), value)?;
+            }
+        }
+        TransformRule::Set { path, value } => put_at(message, path, value.clone())?,
+        TransformRule::Delete { path } => {
+            take_at(message, path);
+        }
+    }
+    Ok(())
+}
+
+pub fn apply_transform(transform: &MessageTransform, message: serde_json::Value) -> Result<serde_json::Value, AppError> {
+    let mut message = message;
+    for (index, rule) in transform.rules.iter().enumerate() {
+        apply_rule(&mut message, rule)
+            .map_err(|e| AppError::validation("rules", format!("Rule {} failed: {}", index + 1, e)))?;
+    }
+    Ok(message)
+}
+
+// Run the connection's transform on a raw message; messages pass through untouched when none is set
+pub(crate) async fn transform_for_connection(connection_id: &str, message: serde_json::Value) -> Result<serde_json::Value, AppError> {
+    let transforms = CONNECTION_TRANSFORMS.lock().await;
+    match transforms.get(connection_id) {
+        Some(transform) => apply_transform(transform, message),
+        None => Ok(message),
+    }
+}
+
+#[tauri::command]
+pub async fn set_connection_transform(connection_id: String, transform: Option<MessageTransform>) -> Result<String, AppError> {
+    let mut transforms = CONNECTION_TRANSFORMS.lock().await;
+
+    match transform {
+        Some(transform) if !transform.rules.is_empty() => {
+            info!("🔧 Set {} transform rule(s) for connection {}", transform.rules.len(), connection_id);
+            transforms.insert(connection_id.clone(), transform);
+            Ok(format!("Transform set for connection: {}", connection_id))
+        }
+        _ => {
+            transforms.remove(&connection_id);
+            Ok(format!("Transform cleared for connection: {}", connection_id))
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_connection_transform(connection_id: String) -> Result<Option<MessageTransform>, AppError> {
+    let transforms = CONNECTION_TRANSFORMS.lock().await;
+    Ok(transforms.get(&connection_id).cloned())
+}
+
+#[tauri::command]
+pub async fn test_transform(connection_id: String, sample_message: String) -> Result<TransformTestResult, AppError> {
+    let parsed: serde_json::Value = serde_json::from_str(&sample_message)
+        .map_err(|e| AppError::validation("sampleMessage", format!("Sample message is not valid JSON: {}", e)))?;
+
+    let output = transform_for_connection(&connection_id, parsed).await?;
+
+    // Mirror the checks the WebSocket listener makes before storing court data
+    let is_match_message = output.get("type").map(|t| t == "MATCH").unwrap_or(false);
+    let court = output.get("data")
+        .and_then(|data| data.get("court"))
+        .and_then(|court| court.as_str())
+        .filter(|court| !court.trim().is_empty())
+        .map(|court| court.to_string());
+
+    Ok(TransformTestResult {
+        output,
+        is_match_message,
+        court,
+    })
+}
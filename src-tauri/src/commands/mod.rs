@@ -7,6 +7,47 @@ pub mod live_data;
 pub mod videos;
 pub mod court_data_sync;
 pub mod tennis_processor;
+pub mod sport_detection;
+pub mod udp_broadcast;
+pub mod serial_output;
+pub mod message_transform;
+pub mod vmix;
+pub mod rest_polling;
+pub mod events;
+pub mod binding_resolver;
+pub mod undo_history;
+pub mod upload_limits;
+pub mod launch;
+pub mod deep_link;
+pub mod memory_estimate;
+pub mod updater;
+pub mod logging;
+pub mod session_recorder;
+pub mod scheduler;
+pub mod component_csv;
+pub mod theme;
+pub mod config;
+pub mod contrast;
+pub mod providers;
+pub mod image_palette;
+pub mod media_library;
+pub mod media_sniff;
+pub mod qr_code;
+pub mod type_schema;
+pub mod match_simulator;
+pub mod import_watch;
+pub mod integrity;
+pub mod diagnostics;
+pub mod samples;
+pub mod match_timer;
+pub mod secrets;
+pub mod event_lock;
+pub mod window_updates;
+pub mod conditional_visibility;
+pub mod data_dir;
+pub mod disk_space;
+pub mod state_commands;
+pub mod storage_commands;
 
 pub use monitor::*;
 pub use scoreboard::*;
@@ -15,4 +56,42 @@ pub use images::*;
 pub use live_data::*;
 pub use videos::*;
 pub use court_data_sync::*;
-pub use tennis_processor::*; 
\ No newline at end of file
+pub use tennis_processor::*;
+pub use sport_detection::*;
+pub use udp_broadcast::*;
+pub use serial_output::*;
+pub use message_transform::*;
+pub use vmix::*;
+pub use rest_polling::*;
+pub use events::*;
+pub use binding_resolver::*;
+pub use undo_history::*;
+pub use upload_limits::*;
+pub use launch::*;
+pub use deep_link::*;
+pub use memory_estimate::*;
+pub use updater::*;
+pub use logging::*;
+pub use session_recorder::*;
+pub use scheduler::*;
+pub use component_csv::*;
+pub use theme::*;
+pub use config::*;
+pub use providers::*;
+pub use qr_code::*;
+pub use type_schema::*;
+pub use match_simulator::*;
+pub use import_watch::*;
+pub use data_dir::*;
+pub use disk_space::*;
+pub use contrast::*;
+pub use integrity::*;
+pub use diagnostics::*;
+pub use samples::*;
+pub use match_timer::*;
+pub use secrets::*;
+pub use event_lock::*;
+pub use window_updates::*;
+pub use conditional_visibility::*;
+pub use state_commands::*;
+pub use storage_commands::*;
\ No newline at end of file
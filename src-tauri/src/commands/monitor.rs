@@ -1,8 +1,19 @@
 // src-tauri/src/commands/monitor.rs
 use tauri::{Manager, WebviewUrl, WebviewWindowBuilder, AppHandle, State};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
+use tracing::{debug, info, warn};
+
+use crate::commands::data_dir::data_dir;
+use crate::commands::event_lock::ensure_unlocked;
+use crate::commands::media_library::write_atomic;
+use crate::commands::storage::{read_scoreboard_config, ScoreboardConfig};
+use crate::error::AppError;
+
+const WINDOW_ORDER_FILE: &str = "window_order.json";
+// Labels remembered; closed windows stay in the list so they can be restored once recreated
+const MAX_WINDOW_ORDER_ENTRIES: usize = 32;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonitorInfo {
@@ -25,10 +36,57 @@ pub struct ScoreboardInstanceStore {
     pub instances: Arc<Mutex<HashMap<String, serde_json::Value>>>,
 }
 
+// Design/test mode: when enabled, get_available_monitors reports these instead of the hardware
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MonitorSimulation {
+    pub enabled: bool,
+    pub monitors: Vec<MonitorInfo>,
+}
+
+#[derive(Default)]
+pub struct MonitorSimulationStore {
+    pub simulation: Arc<Mutex<MonitorSimulation>>,
+}
+
+// Labels of scoreboard windows that currently pass mouse input through to whatever is behind them
+#[derive(Default)]
+pub struct ClickThroughStore {
+    pub windows: Arc<Mutex<HashSet<String>>>,
+}
+
+// Window labels front to back, as last focused
+#[derive(Default)]
+pub struct WindowOrderStore {
+    pub order: Arc<Mutex<Vec<String>>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreboardWindowMonitor {
+    pub label: String,
+    // None when the window is entirely off every known monitor
+    pub monitor_id: Option<u32>,
+    pub monitor_name: Option<String>,
+}
+
 #[tauri::command]
-pub async fn get_available_monitors(app: AppHandle) -> Result<Vec<MonitorInfo>, String> {
-    let monitors = app.available_monitors()
-        .map_err(|e| e.to_string())?;
+pub async fn get_available_monitors(
+    app: AppHandle,
+    simulation_store: State<'_, MonitorSimulationStore>,
+) -> Result<Vec<MonitorInfo>, AppError> {
+    current_monitors(&app, &simulation_store)
+}
+
+// Simulated monitors when simulation mode is on, the hardware otherwise
+pub(crate) fn current_monitors(app: &AppHandle, simulation_store: &MonitorSimulationStore) -> Result<Vec<MonitorInfo>, AppError> {
+    {
+        let simulation = simulation_store.simulation.lock()?;
+        if simulation.enabled && !simulation.monitors.is_empty() {
+            debug!("Returning {} simulated monitors", simulation.monitors.len());
+            return Ok(simulation.monitors.clone());
+        }
+    }
+
+    let monitors = app.available_monitors()?;
     
     let monitor_info: Vec<MonitorInfo> = monitors
         .into_iter()
@@ -70,7 +128,41 @@ pub async fn get_available_monitors(app: AppHandle) -> Result<Vec<MonitorInfo>,
     Ok(monitor_info)
 }
 
+fn overlap_area(monitor: &MonitorInfo, x: i32, y: i32, width: u32, height: u32) -> i64 {
+    let (left, top) = (x as i64, y as i64);
+    let (right, bottom) = (left + width as i64, top + height as i64);
+    let (monitor_left, monitor_top) = (monitor.x as i64, monitor.y as i64);
+    let monitor_right = monitor_left + monitor.width as i64;
+    let monitor_bottom = monitor_top + monitor.height as i64;
+
+    let overlap_width = right.min(monitor_right) - left.max(monitor_left);
+    let overlap_height = bottom.min(monitor_bottom) - top.max(monitor_top);
+    if overlap_width <= 0 || overlap_height <= 0 {
+        0
+    } else {
+        overlap_width * overlap_height
+    }
+}
+
+// The monitor covering the largest part of a window rect (physical pixels), so a
+// window straddling two displays is reported on the one showing most of it
+pub fn monitor_for_window_rect(
+    monitors: &[MonitorInfo],
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+) -> Option<&MonitorInfo> {
+    monitors.iter()
+        .map(|monitor| (monitor, overlap_area(monitor, x, y, width, height)))
+        .filter(|(_, area)| *area > 0)
+        .max_by_key(|(_, area)| *area)
+        .map(|(monitor, _)| monitor)
+}
+
+// Arguments mirror the frontend's invoke payload
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn create_scoreboard_window(
     app: AppHandle,
     store: State<'_, ScoreboardInstanceStore>,
@@ -83,18 +175,18 @@ pub async fn create_scoreboard_window(
     offset_x: i32,
     offset_y: i32,
     scoreboard_data: Option<serde_json::Value>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     // Get fresh monitor info to determine if we should use fullscreen
-    let monitors = app.available_monitors().map_err(|e| e.to_string())?;
+    let monitors = app.available_monitors()?;
     let monitor_list: Vec<_> = monitors.into_iter().collect();
     
     // Debug logging
-    println!("Creating scoreboard window:");
-    println!("  Requested monitor_id: {}", monitor_id);
-    println!("  Available monitors: {}", monitor_list.len());
+    info!("Creating scoreboard window:");
+    debug!("Requested monitor_id: {}", monitor_id);
+    debug!("Available monitors: {}", monitor_list.len());
     for (i, monitor) in monitor_list.iter().enumerate() {
         let monitor_name = monitor.name().map_or("Unknown".to_string(), |n| n.clone());
-        println!("    Monitor {}: {} at ({}, {})", i, 
+        debug!("Monitor {}: {} at ({}, {})", i, 
                 monitor_name, 
                 monitor.position().x, monitor.position().y);
     }
@@ -103,7 +195,7 @@ pub async fn create_scoreboard_window(
     
     // Store the scoreboard data for this window
     if let Some(data) = scoreboard_data {
-        let mut instances = store.instances.lock().map_err(|e| e.to_string())?;
+        let mut instances = store.instances.lock()?;
         instances.insert(window_id.clone(), data);
     }
 
@@ -121,8 +213,7 @@ pub async fn create_scoreboard_window(
     .skip_taskbar(true) // Hide from taskbar/dock
     .fullscreen(false) // Start in windowed mode, then set fullscreen after positioning
     .inner_size(width as f64, height as f64) // Set initial size
-    .build()
-    .map_err(|e| e.to_string())?;
+    .build()?;
 
     // Position the window on the target monitor first
     if let Some(monitor) = target_monitor {
@@ -131,54 +222,56 @@ pub async fn create_scoreboard_window(
         let final_x = monitor_x + offset_x;
         let final_y = monitor_y + offset_y;
         
-        println!("  Target monitor position: ({}, {})", monitor_x, monitor_y);
-        println!("  Offsets: ({}, {})", offset_x, offset_y);
-        println!("  Final position: ({}, {})", final_x, final_y);
+        debug!("Target monitor position: ({}, {})", monitor_x, monitor_y);
+        debug!("Offsets: ({}, {})", offset_x, offset_y);
+        debug!("Final position: ({}, {})", final_x, final_y);
         
         // Move to target monitor before setting fullscreen
         window.set_position(tauri::Position::Physical(tauri::PhysicalPosition { 
             x: final_x, 
             y: final_y 
-        })).map_err(|e| e.to_string())?;
+        }))?;
         
         // Small delay to ensure positioning takes effect
         std::thread::sleep(std::time::Duration::from_millis(200));
         
-        println!("  Window positioned, setting fullscreen...");
+        debug!("Window positioned, setting fullscreen...");
     } else {
-        println!("  Warning: No target monitor found for ID {}", monitor_id);
+        warn!("No target monitor found for ID {}", monitor_id);
     }
     
     // Show the window first in windowed mode on the target monitor
-    window.show().map_err(|e| e.to_string())?;
+    window.show()?;
     
     // Additional delay to ensure window is fully positioned and shown
     std::thread::sleep(std::time::Duration::from_millis(300));
     
     // Now set fullscreen - this will make it fullscreen on the monitor where it's positioned
-    println!("  Setting fullscreen...");
-    window.set_fullscreen(true).map_err(|e| e.to_string())?;
+    debug!("Setting fullscreen...");
+    window.set_fullscreen(true)?;
     
-    println!("  Scoreboard window created and shown in fullscreen");
+    debug!("Scoreboard window created and shown in fullscreen");
     
     Ok(())
 }
 
 #[tauri::command]
-pub async fn close_scoreboard_window(app: AppHandle, window_id: String) -> Result<(), String> {
+pub async fn close_scoreboard_window(app: AppHandle, window_id: String) -> Result<(), AppError> {
+    ensure_unlocked("Closing scoreboard windows")?;
     if let Some(window) = app.get_webview_window(&window_id) {
-        window.close().map_err(|e| e.to_string())?;
+        window.close()?;
     }
     Ok(())
 }
 
 #[tauri::command]
-pub async fn close_all_scoreboard_windows(app: AppHandle) -> Result<(), String> {
+pub async fn close_all_scoreboard_windows(app: AppHandle) -> Result<(), AppError> {
+    ensure_unlocked("Closing scoreboard windows")?;
     // Get all windows and close those that start with "scoreboard_"
     let windows = app.webview_windows();
     for (label, window) in windows {
         if label.starts_with("scoreboard_") {
-            window.close().map_err(|e| e.to_string())?;
+            window.close()?;
         }
     }
     Ok(())
@@ -192,15 +285,15 @@ pub async fn update_scoreboard_window_position(
     y: i32,
     offset_x: i32,
     offset_y: i32,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
+    ensure_unlocked("Moving scoreboard windows")?;
     if let Some(window) = app.get_webview_window(&window_id) {
         let final_x = x + offset_x;
         let final_y = y + offset_y;
         window.set_position(tauri::Position::Physical(tauri::PhysicalPosition { 
             x: final_x, 
             y: final_y 
-        }))
-            .map_err(|e| e.to_string())?;
+        }))?;
     }
     Ok(())
 }
@@ -211,33 +304,137 @@ pub async fn update_scoreboard_window_size(
     window_id: String,
     width: u32,
     height: u32,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
+    ensure_unlocked("Resizing scoreboard windows")?;
     if let Some(window) = app.get_webview_window(&window_id) {
-        window.set_size(tauri::Size::Physical(tauri::PhysicalSize { width, height }))
-            .map_err(|e| e.to_string())?;
+        window.set_size(tauri::Size::Physical(tauri::PhysicalSize { width, height }))?;
     }
     Ok(())
 }
 
 #[tauri::command]
-pub async fn toggle_scoreboard_fullscreen(app: AppHandle, window_id: String) -> Result<(), String> {
+pub async fn toggle_scoreboard_fullscreen(app: AppHandle, window_id: String) -> Result<(), AppError> {
     if let Some(window) = app.get_webview_window(&window_id) {
-        let is_fullscreen = window.is_fullscreen().map_err(|e| e.to_string())?;
-        window.set_fullscreen(!is_fullscreen).map_err(|e| e.to_string())?;
+        let is_fullscreen = window.is_fullscreen()?;
+        window.set_fullscreen(!is_fullscreen)?;
     }
     Ok(())
 }
 
 #[tauri::command]
-pub async fn set_scoreboard_fullscreen(app: AppHandle, window_id: String, fullscreen: bool) -> Result<(), String> {
+pub async fn set_scoreboard_fullscreen(app: AppHandle, window_id: String, fullscreen: bool) -> Result<(), AppError> {
     if let Some(window) = app.get_webview_window(&window_id) {
-        window.set_fullscreen(fullscreen).map_err(|e| e.to_string())?;
+        window.set_fullscreen(fullscreen)?;
     }
     Ok(())
 } 
 
+// Click-through relies on the window system ignoring cursor events, which only the desktop platforms offer
+#[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+fn apply_click_through(window: &tauri::WebviewWindow, enabled: bool) -> Result<(), AppError> {
+    window.set_ignore_cursor_events(enabled)
+        .map_err(|e| AppError::external("tauri", format!("Failed to set click-through on {}: {}", window.label(), e)))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+fn apply_click_through(_window: &tauri::WebviewWindow, _enabled: bool) -> Result<(), AppError> {
+    Err(AppError::conflict("Click-through scoreboard windows are not supported on this platform"))
+}
+
+// Commands aimed at one scoreboard window fail with NotFound once it has been closed
+fn require_window<W>(window: Option<W>, window_id: &str) -> Result<W, AppError> {
+    window.ok_or_else(|| AppError::not_found(format!("Scoreboard window '{}'", window_id)))
+}
+
+#[tauri::command]
+pub async fn set_scoreboard_window_click_through(
+    app: AppHandle,
+    store: State<'_, ClickThroughStore>,
+    window_id: String,
+    enabled: bool,
+) -> Result<(), AppError> {
+    let window = require_window(app.get_webview_window(&window_id), &window_id)?;
+    apply_click_through(&window, enabled)?;
+
+    let mut windows = store.windows.lock()?;
+    if enabled {
+        windows.insert(window_id.clone());
+    } else {
+        windows.remove(&window_id);
+    }
+    info!("🖱️ Click-through {} for {}", if enabled { "enabled" } else { "disabled" }, window_id);
+    Ok(())
+}
+
 #[tauri::command]
-pub async fn list_scoreboard_windows(app: AppHandle) -> Result<Vec<String>, String> {
+pub async fn get_scoreboard_window_click_through(
+    app: AppHandle,
+    store: State<'_, ClickThroughStore>,
+    window_id: String,
+) -> Result<bool, AppError> {
+    require_window(app.get_webview_window(&window_id), &window_id)?;
+    let windows = store.windows.lock()?;
+    Ok(windows.contains(&window_id))
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum FramePacingMode {
+    VsyncOn,
+    VsyncOff,
+    TargetFrameRate { fps: u32 },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FramePacingResult {
+    pub window_id: String,
+    pub mode: FramePacingMode,
+    pub supported: bool,
+    // Why the request could not be applied, when it wasn't
+    pub reason: Option<String>,
+}
+
+const MAX_TARGET_FRAME_RATE: u32 = 240;
+
+// The system webviews (WebView2, WKWebView, WebKitGTK) always present in step
+// with the compositor and offer no hook to change that, so vsync is the only
+// mode that can be honoured. Anything else is reported back as unsupported
+// rather than failing, so the UI can grey the option out.
+#[tauri::command]
+pub async fn set_scoreboard_frame_pacing(
+    app: AppHandle,
+    window_id: String,
+    mode: FramePacingMode,
+) -> Result<FramePacingResult, AppError> {
+    if app.get_webview_window(&window_id).is_none() {
+        return Err(AppError::not_found(format!("Scoreboard window '{}'", window_id)));
+    }
+    if let FramePacingMode::TargetFrameRate { fps } = mode {
+        if fps == 0 || fps > MAX_TARGET_FRAME_RATE {
+            return Err(AppError::validation("fps", format!("Target frame rate must be between 1 and {}", MAX_TARGET_FRAME_RATE)));
+        }
+    }
+
+    let reason = match mode {
+        FramePacingMode::VsyncOn => None,
+        FramePacingMode::VsyncOff => Some("The webview compositor does not allow vsync to be disabled on this platform"),
+        FramePacingMode::TargetFrameRate { .. } => Some("The webview compositor does not expose a frame rate cap on this platform"),
+    };
+    if let Some(reason) = reason {
+        debug!("Frame pacing {:?} for {} unsupported: {}", mode, window_id, reason);
+    }
+
+    Ok(FramePacingResult {
+        window_id,
+        mode,
+        supported: reason.is_none(),
+        reason: reason.map(str::to_string),
+    })
+}
+
+#[tauri::command]
+pub async fn list_scoreboard_windows(app: AppHandle) -> Result<Vec<String>, AppError> {
     let windows = app.webview_windows();
     let scoreboard_windows: Vec<String> = windows
         .keys()
@@ -247,13 +444,461 @@ pub async fn list_scoreboard_windows(app: AppHandle) -> Result<Vec<String>, Stri
     Ok(scoreboard_windows)
 } 
 
+#[tauri::command]
+pub async fn get_scoreboard_windows_with_monitors(
+    app: AppHandle,
+    simulation_store: State<'_, MonitorSimulationStore>,
+) -> Result<Vec<ScoreboardWindowMonitor>, AppError> {
+    let monitors = current_monitors(&app, &simulation_store)?;
+
+    let mut windows: Vec<ScoreboardWindowMonitor> = app.webview_windows()
+        .into_iter()
+        .filter(|(label, _)| label.starts_with("scoreboard_"))
+        .map(|(label, window)| {
+            let position = window.outer_position()?;
+            let size = window.outer_size()?;
+            let monitor = monitor_for_window_rect(&monitors, position.x, position.y, size.width, size.height);
+            Ok(ScoreboardWindowMonitor {
+                label,
+                monitor_id: monitor.map(|m| m.id),
+                monitor_name: monitor.map(|m| m.name.clone()),
+            })
+        })
+        .collect::<Result<_, AppError>>()?;
+    windows.sort_by(|a, b| a.label.cmp(&b.label));
+    Ok(windows)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowPlacementWarning {
+    pub monitor_id: u32,
+    pub monitor_name: String,
+    pub window_ids: Vec<String>,
+    pub message: String,
+}
+
+// One warning per monitor showing more than one of the given fullscreen windows,
+// since only the topmost of them can be seen
+pub fn find_fullscreen_overlaps(fullscreen_windows: &[ScoreboardWindowMonitor]) -> Vec<WindowPlacementWarning> {
+    let mut by_monitor: HashMap<u32, (String, Vec<String>)> = HashMap::new();
+    for window in fullscreen_windows {
+        let Some(monitor_id) = window.monitor_id else {
+            continue;
+        };
+        let entry = by_monitor.entry(monitor_id)
+            .or_insert_with(|| (window.monitor_name.clone().unwrap_or_default(), Vec::new()));
+        entry.1.push(window.label.clone());
+    }
+
+    let mut warnings: Vec<WindowPlacementWarning> = by_monitor.into_iter()
+        .filter(|(_, (_, labels))| labels.len() > 1)
+        .map(|(monitor_id, (monitor_name, mut window_ids))| {
+            window_ids.sort();
+            WindowPlacementWarning {
+                message: format!("{} fullscreen scoreboard windows share {}; only one of them is visible", window_ids.len(), monitor_name),
+                monitor_id,
+                monitor_name,
+                window_ids,
+            }
+        })
+        .collect();
+    warnings.sort_by_key(|warning| warning.monitor_id);
+    warnings
+}
+
+// Pre-show sanity check; an empty list means nothing looks wrong
+#[tauri::command]
+pub async fn check_window_placement(
+    app: AppHandle,
+    simulation_store: State<'_, MonitorSimulationStore>,
+) -> Result<Vec<WindowPlacementWarning>, AppError> {
+    let monitors = current_monitors(&app, &simulation_store)?;
+
+    let mut fullscreen_windows = Vec::new();
+    for (label, window) in app.webview_windows() {
+        if !label.starts_with("scoreboard_") || !window.is_fullscreen()? {
+            continue;
+        }
+        let position = window.outer_position()?;
+        let size = window.outer_size()?;
+        let monitor = monitor_for_window_rect(&monitors, position.x, position.y, size.width, size.height);
+        fullscreen_windows.push(ScoreboardWindowMonitor {
+            label,
+            monitor_id: monitor.map(|m| m.id),
+            monitor_name: monitor.map(|m| m.name.clone()),
+        });
+    }
+
+    let warnings = find_fullscreen_overlaps(&fullscreen_windows);
+    for warning in &warnings {
+        warn!("🖥️ {}: {:?}", warning.message, warning.window_ids);
+    }
+    Ok(warnings)
+}
+
+// One line of a venue's board-to-monitor mapping. `monitor_match` is a
+// monitor id, or a name matched exactly first and then as a substring,
+// ignoring case (e.g. "projector" for "EPSON Projector X").
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreboardMonitorMapping {
+    pub scoreboard_filename: String,
+    pub monitor_match: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappedScoreboardWindow {
+    pub scoreboard_filename: String,
+    pub window_id: String,
+    pub monitor_id: u32,
+    pub monitor_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnmatchedMonitorMapping {
+    pub scoreboard_filename: String,
+    pub monitor_match: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MonitorMappingReport {
+    pub created: Vec<MappedScoreboardWindow>,
+    pub unmatched: Vec<UnmatchedMonitorMapping>,
+}
+
+fn match_monitor<'a>(monitors: &'a [MonitorInfo], monitor_match: &str) -> Option<&'a MonitorInfo> {
+    let wanted = monitor_match.trim();
+    if let Ok(id) = wanted.parse::<u32>() {
+        return monitors.iter().find(|monitor| monitor.id == id);
+    }
+    let wanted = wanted.to_lowercase();
+    monitors.iter().find(|monitor| monitor.name.to_lowercase() == wanted)
+        .or_else(|| monitors.iter().find(|monitor| monitor.name.to_lowercase().contains(&wanted)))
+}
+
+// The shape the frontend passes as `scoreboard_data` when it opens a saved board
+fn window_data_for_board(filename: &str, config: &ScoreboardConfig) -> serde_json::Value {
+    let mut board = config.data.clone();
+    let components = board.as_object_mut()
+        .and_then(|data| data.remove("components"))
+        .unwrap_or_else(|| serde_json::json!([]));
+    serde_json::json!({
+        "config": board,
+        "components": components,
+        "gameState": null,
+        "scoreboardFilename": filename,
+    })
+}
+
+// Venue setup in one step: opens each mapped board fullscreen on its monitor.
+// Entries whose board or monitor can't be found, or whose monitor was already
+// taken earlier in the list, are reported rather than stopping the rest.
+#[tauri::command]
+pub async fn apply_scoreboard_monitor_mapping(
+    app: AppHandle,
+    store: State<'_, ScoreboardInstanceStore>,
+    simulation_store: State<'_, MonitorSimulationStore>,
+    mapping: Vec<ScoreboardMonitorMapping>,
+) -> Result<MonitorMappingReport, AppError> {
+    let monitors = current_monitors(&app, &simulation_store)?;
+    let mut report = MonitorMappingReport::default();
+    let mut used_monitors = HashSet::new();
+
+    for entry in mapping {
+        let unmatched = |reason: String| UnmatchedMonitorMapping {
+            scoreboard_filename: entry.scoreboard_filename.clone(),
+            monitor_match: entry.monitor_match.clone(),
+            reason,
+        };
+        let Some(monitor) = match_monitor(&monitors, &entry.monitor_match) else {
+            report.unmatched.push(unmatched(format!("No monitor matches '{}'", entry.monitor_match)));
+            continue;
+        };
+        if !used_monitors.insert(monitor.id) {
+            report.unmatched.push(unmatched(format!("{} is already assigned earlier in the mapping", monitor.name)));
+            continue;
+        }
+        let config = match read_scoreboard_config(&app, &entry.scoreboard_filename) {
+            Ok((_, config)) => config,
+            Err(e) => {
+                used_monitors.remove(&monitor.id);
+                report.unmatched.push(unmatched(e.to_string()));
+                continue;
+            }
+        };
+
+        let window_id = format!("scoreboard_{}", uuid::Uuid::new_v4());
+        let created = create_scoreboard_window(
+            app.clone(), store.clone(), window_id.clone(), monitor.id,
+            monitor.width, monitor.height, monitor.x, monitor.y, 0, 0,
+            Some(window_data_for_board(&entry.scoreboard_filename, &config)),
+        ).await;
+        if let Err(e) = created {
+            used_monitors.remove(&monitor.id);
+            report.unmatched.push(unmatched(format!("Could not open the window: {}", e)));
+            continue;
+        }
+
+        info!("🖥️ Opened {} on {}", entry.scoreboard_filename, monitor.name);
+        report.created.push(MappedScoreboardWindow {
+            scoreboard_filename: entry.scoreboard_filename,
+            window_id,
+            monitor_id: monitor.id,
+            monitor_name: monitor.name.clone(),
+        });
+    }
+    Ok(report)
+}
+
+// Hide or show every scoreboard window without closing it; returns how many were changed.
+// Shared by the blank/restore commands and the tray menu.
+pub fn set_scoreboard_windows_visible(app: &AppHandle, visible: bool) -> Result<usize, AppError> {
+    let mut changed = 0;
+    for (label, window) in app.webview_windows() {
+        if !label.starts_with("scoreboard_") {
+            continue;
+        }
+        if visible {
+            window.show().map_err(|e| AppError::external("tauri", format!("Failed to show {}: {}", label, e)))?;
+        } else {
+            window.hide().map_err(|e| AppError::external("tauri", format!("Failed to hide {}: {}", label, e)))?;
+        }
+        changed += 1;
+    }
+    Ok(changed)
+}
+
+// (open, visible) scoreboard window counts
+pub fn scoreboard_window_counts(app: &AppHandle) -> (usize, usize) {
+    let windows: Vec<_> = app.webview_windows()
+        .into_iter()
+        .filter(|(label, _)| label.starts_with("scoreboard_"))
+        .collect();
+    let visible = windows.iter()
+        .filter(|(_, window)| window.is_visible().unwrap_or(false))
+        .count();
+    (windows.len(), visible)
+}
+
+#[tauri::command]
+pub async fn blank_all_displays(app: AppHandle) -> Result<usize, AppError> {
+    let count = set_scoreboard_windows_visible(&app, false)?;
+    info!("⬛ Blanked {} scoreboard display(s)", count);
+    Ok(count)
+}
+
+#[tauri::command]
+pub async fn restore_all_displays(app: AppHandle) -> Result<usize, AppError> {
+    let count = set_scoreboard_windows_visible(&app, true)?;
+    info!("🖥️ Restored {} scoreboard display(s)", count);
+    Ok(count)
+}
+
 #[tauri::command]
 pub async fn get_scoreboard_instance_data(
     store: State<'_, ScoreboardInstanceStore>,
     window_id: String,
-) -> Result<Option<serde_json::Value>, String> {
-    let instances = store.instances.lock().map_err(|e| e.to_string())?;
+) -> Result<Option<serde_json::Value>, AppError> {
+    let instances = store.instances.lock()?;
     Ok(instances.get(&window_id).cloned())
 }
 
- 
\ No newline at end of file
+#[tauri::command]
+pub async fn set_monitor_simulation_mode(
+    simulation_store: State<'_, MonitorSimulationStore>,
+    enabled: bool,
+) -> Result<(), AppError> {
+    let mut simulation = simulation_store.simulation.lock()?;
+    simulation.enabled = enabled;
+    info!("Monitor simulation mode {}", if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_simulated_monitors(
+    simulation_store: State<'_, MonitorSimulationStore>,
+    monitors: Vec<MonitorInfo>,
+) -> Result<(), AppError> {
+    let mut simulation = simulation_store.simulation.lock()?;
+
+    if !simulation.enabled {
+        return Err(AppError::conflict("Monitor simulation mode is not enabled"));
+    }
+
+    if monitors.is_empty() {
+        return Err(AppError::validation("monitors", "At least one simulated monitor is required"));
+    }
+
+    let mut ids = std::collections::HashSet::new();
+    for monitor in &monitors {
+        if monitor.width == 0 || monitor.height == 0 {
+            return Err(AppError::validation("monitors", format!("Simulated monitor {} has zero width or height", monitor.id)));
+        }
+        if !ids.insert(monitor.id) {
+            return Err(AppError::validation("monitors", format!("Duplicate simulated monitor id: {}", monitor.id)));
+        }
+    }
+
+    simulation.monitors = monitors;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn clear_simulated_monitors(
+    simulation_store: State<'_, MonitorSimulationStore>,
+) -> Result<(), AppError> {
+    let mut simulation = simulation_store.simulation.lock()?;
+    simulation.monitors.clear();
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_monitor_simulation(
+    simulation_store: State<'_, MonitorSimulationStore>,
+) -> Result<MonitorSimulation, AppError> {
+    let simulation = simulation_store.simulation.lock()?;
+    Ok(simulation.clone())
+}
+
+// Read the saved stacking order at startup; a missing or unreadable file starts empty
+pub fn load_window_order(app: &AppHandle) {
+    let path = match data_dir(app) {
+        Ok(dir) => dir.join(WINDOW_ORDER_FILE),
+        Err(e) => {
+            warn!("Cannot load window order: {}", e);
+            return;
+        }
+    };
+    let Ok(json) = std::fs::read_to_string(&path) else {
+        return;
+    };
+    match serde_json::from_str::<Vec<String>>(&json) {
+        Ok(order) => {
+            if let Ok(mut stored) = app.state::<WindowOrderStore>().order.lock() {
+                *stored = order;
+            }
+        }
+        Err(e) => warn!("Ignoring unreadable {}: {}", WINDOW_ORDER_FILE, e),
+    }
+}
+
+// Called on every focus change: the focused window moves to the front and the
+// new order is written next to the saved window layout
+pub fn record_window_focus(app: &AppHandle, label: &str) {
+    let order = {
+        let store = app.state::<WindowOrderStore>();
+        let Ok(mut order) = store.order.lock() else {
+            return;
+        };
+        if order.first().map(String::as_str) == Some(label) {
+            return;
+        }
+        order.retain(|existing| existing != label);
+        order.insert(0, label.to_string());
+        order.truncate(MAX_WINDOW_ORDER_ENTRIES);
+        order.clone()
+    };
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let result = async {
+            let path = data_dir(&app)?.join(WINDOW_ORDER_FILE);
+            write_atomic(&path, &serde_json::to_vec_pretty(&order)?).await
+        }.await;
+        if let Err(e) = result {
+            warn!("Failed to save window order: {}", e);
+        }
+    });
+}
+
+// Focus the open windows back to front so they end up stacked as last recorded;
+// returns the labels that were reordered, front first. Windows created with
+// always_on_top still sit above regular ones whatever the recorded order.
+#[tauri::command]
+pub async fn restore_window_order(
+    app: AppHandle,
+    store: State<'_, WindowOrderStore>,
+) -> Result<Vec<String>, AppError> {
+    let order = store.order.lock()?.clone();
+    let open: Vec<(String, tauri::WebviewWindow)> = order.iter()
+        .filter_map(|label| app.get_webview_window(label).map(|window| (label.clone(), window)))
+        .collect();
+
+    for (label, window) in open.iter().rev() {
+        if !window.is_visible().unwrap_or(false) {
+            continue;
+        }
+        window.set_focus()
+            .map_err(|e| AppError::external("tauri", format!("Failed to focus {}: {}", label, e)))?;
+    }
+
+    info!("🪟 Restored stacking order for {} window(s)", open.len());
+    Ok(open.into_iter().map(|(label, _)| label).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(id: u32, x: i32, width: u32) -> MonitorInfo {
+        MonitorInfo {
+            id,
+            name: format!("Display {}", id),
+            width,
+            height: 1080,
+            x,
+            y: 0,
+            is_primary: id == 1,
+            scale_factor: 1.0,
+            work_area_width: width,
+            work_area_height: 1080,
+            work_area_x: x,
+            work_area_y: 0,
+        }
+    }
+
+    #[test]
+    fn window_resolves_to_monitor_with_largest_overlap() {
+        let monitors = [monitor(1, 0, 1920), monitor(2, 1920, 1920)];
+
+        assert_eq!(monitor_for_window_rect(&monitors, 100, 100, 800, 600).map(|m| m.id), Some(1));
+        assert_eq!(monitor_for_window_rect(&monitors, 2000, 0, 1920, 1080).map(|m| m.id), Some(2));
+        // Straddles the boundary with most of it on the second display
+        assert_eq!(monitor_for_window_rect(&monitors, 1820, 0, 800, 600).map(|m| m.id), Some(2));
+        assert!(monitor_for_window_rect(&monitors, -5000, 0, 800, 600).is_none());
+    }
+
+    #[test]
+    fn click_through_requires_an_open_window() {
+        let err = require_window(None::<()>, "scoreboard_missing").unwrap_err();
+        assert!(matches!(err, AppError::NotFound { .. }));
+        assert!(err.to_string().contains("scoreboard_missing"));
+        assert!(require_window(Some(()), "scoreboard_1").is_ok());
+    }
+
+
+    #[test]
+    fn two_fullscreen_windows_on_one_monitor_are_flagged() {
+        let monitors = [monitor(1, 0, 1920), monitor(2, 1920, 1920)];
+        let placed = |label: &str, x: i32| {
+            let monitor = monitor_for_window_rect(&monitors, x, 0, 1920, 1080);
+            ScoreboardWindowMonitor {
+                label: label.to_string(),
+                monitor_id: monitor.map(|m| m.id),
+                monitor_name: monitor.map(|m| m.name.clone()),
+            }
+        };
+
+        let warnings = find_fullscreen_overlaps(&[
+            placed("scoreboard_b", 1920),
+            placed("scoreboard_a", 1920),
+            placed("scoreboard_c", 0),
+        ]);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].monitor_id, 2);
+        assert_eq!(warnings[0].window_ids, ["scoreboard_a", "scoreboard_b"]);
+
+        assert!(find_fullscreen_overlaps(&[placed("scoreboard_a", 0), placed("scoreboard_b", 1920)]).is_empty());
+    }
+}
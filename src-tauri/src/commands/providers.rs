@@ -0,0 +1,78 @@
+// src-tauri/src/commands/providers.rs
+//
+// Per-provider limits for live data connections. Operators tend to set poll
+// intervals as low as they'll go, which gets the venue throttled or banned by
+// the provider mid-event, so connections are checked against these on save.
+use serde::{Deserialize, Serialize};
+use tauri::command;
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderDefinition {
+    pub provider: &'static str,
+    // Seconds, matching the connection's pollInterval
+    pub min_poll_interval: u32,
+    // Seconds between keepalive pings for WebSocket providers; None for polled providers
+    pub min_keepalive_interval: Option<u32>,
+}
+
+const PROVIDERS: [ProviderDefinition; 3] = [
+    ProviderDefinition { provider: "mock", min_poll_interval: 1, min_keepalive_interval: None },
+    ProviderDefinition { provider: "api", min_poll_interval: 5, min_keepalive_interval: None },
+    ProviderDefinition { provider: "tennis_api", min_poll_interval: 10, min_keepalive_interval: Some(20) },
+];
+
+// Unknown providers get the generic API limits
+pub fn provider_definition(provider: &str) -> ProviderDefinition {
+    PROVIDERS.iter()
+        .find(|definition| definition.provider == provider)
+        .copied()
+        .unwrap_or(PROVIDERS[1])
+}
+
+pub fn check_poll_interval(provider: &str, poll_interval: u32) -> Result<(), AppError> {
+    let definition = provider_definition(provider);
+    if poll_interval < definition.min_poll_interval {
+        return Err(AppError::validation("pollInterval", format!(
+            "Poll interval of {}s is below the {}s minimum for the {} provider; polling faster risks being rate limited",
+            poll_interval, definition.min_poll_interval, provider
+        )));
+    }
+    Ok(())
+}
+
+// Aggressive keepalives are only a warning: some venues need them to get through proxies
+pub fn keepalive_warning(provider: &str, keepalive_interval: u32) -> Option<String> {
+    let minimum = provider_definition(provider).min_keepalive_interval?;
+    (keepalive_interval < minimum).then(|| format!(
+        "Keepalive every {}s is more frequent than the {}s recommended for the {} provider",
+        keepalive_interval, minimum, provider
+    ))
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntervalCheck {
+    pub warnings: Vec<String>,
+}
+
+#[command]
+pub async fn get_live_data_providers() -> Result<Vec<ProviderDefinition>, AppError> {
+    Ok(PROVIDERS.to_vec())
+}
+
+#[command]
+pub async fn validate_connection_intervals(
+    provider: String,
+    poll_interval: u32,
+    keepalive_interval: Option<u32>,
+) -> Result<IntervalCheck, AppError> {
+    check_poll_interval(&provider, poll_interval)?;
+    let warnings = keepalive_interval
+        .and_then(|interval| keepalive_warning(&provider, interval))
+        .into_iter()
+        .collect();
+    Ok(IntervalCheck { warnings })
+}
@@ -0,0 +1,149 @@
+// src-tauri/src/commands/qr_code.rs
+//
+// QR codes spectators can scan for a court's live score. The URL comes from
+// the `liveViewUrlTemplate` config value, so venues with a public server can
+// point codes there instead of at this machine.
+use serde::{Deserialize, Serialize};
+use tauri::command;
+use tracing::info;
+
+use crate::commands::config::app_config;
+use crate::commands::vmix::vmix_server_port;
+use crate::error::AppError;
+
+// Pixels per QR module, and the white border (in modules) scanners need
+const MODULE_PIXELS: usize = 8;
+const QUIET_ZONE_MODULES: usize = 4;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreboardQrCode {
+    pub url: String,
+    pub png: Vec<u8>,
+}
+
+// Percent-encode everything but RFC 3986 unreserved characters, so any court
+// name is safe as a single path segment
+fn encode_path_segment(value: &str) -> String {
+    value.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+pub fn live_view_url(template: &str, court_name: &str, host: &str, port: Option<u16>) -> Result<String, AppError> {
+    if template.contains("{port}") && port.is_none() {
+        return Err(AppError::conflict(
+            "Start the vMix data server, or set liveViewUrlTemplate to an external URL",
+        ));
+    }
+    Ok(template
+        .replace("{court}", &encode_path_segment(court_name))
+        .replace("{host}", host)
+        .replace("{port}", &port.map(|p| p.to_string()).unwrap_or_default()))
+}
+
+// Address other devices on the LAN can reach us at. Connecting a UDP socket
+// only picks a route; nothing is sent.
+fn lan_address() -> String {
+    std::net::UdpSocket::bind("0.0.0.0:0")
+        .and_then(|socket| {
+            socket.connect("8.8.8.8:80")?;
+            socket.local_addr()
+        })
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_else(|_| "localhost".to_string())
+}
+
+pub fn qr_png(data: &str) -> Result<Vec<u8>, AppError> {
+    let code = qrcode::QrCode::new(data.as_bytes())
+        .map_err(|e| AppError::validation("url", format!("Failed to encode QR code: {}", e)))?;
+    let modules = code.width();
+    let colors = code.to_colors();
+
+    let size = (modules + QUIET_ZONE_MODULES * 2) * MODULE_PIXELS;
+    let mut pixels = vec![255u8; size * size];
+    for (index, color) in colors.iter().enumerate() {
+        if *color != qrcode::Color::Dark {
+            continue;
+        }
+        let (module_x, module_y) = (index % modules + QUIET_ZONE_MODULES, index / modules + QUIET_ZONE_MODULES);
+        for y in module_y * MODULE_PIXELS..(module_y + 1) * MODULE_PIXELS {
+            let row = y * size;
+            pixels[row + module_x * MODULE_PIXELS..row + (module_x + 1) * MODULE_PIXELS].fill(0);
+        }
+    }
+
+    let mut png_data = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png_data, size as u32, size as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()
+        .map_err(|e| AppError::io(format!("Failed to write QR code PNG: {}", e)))?;
+    writer.write_image_data(&pixels)
+        .map_err(|e| AppError::io(format!("Failed to write QR code PNG: {}", e)))?;
+    writer.finish()
+        .map_err(|e| AppError::io(format!("Failed to write QR code PNG: {}", e)))?;
+    Ok(png_data)
+}
+
+#[command]
+pub async fn generate_scoreboard_qr(court_name: String) -> Result<ScoreboardQrCode, AppError> {
+    let court_name = court_name.trim();
+    if court_name.is_empty() {
+        return Err(AppError::validation("court_name", "Court name is required"));
+    }
+
+    let template = app_config().live_view_url_template;
+    let url = live_view_url(&template, court_name, &lan_address(), vmix_server_port().await)?;
+    let png = qr_png(&url)?;
+
+    info!("📱 Generated QR code for {}: {}", court_name, url);
+    Ok(ScoreboardQrCode { url, png })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Read the module grid back out of the PNG by sampling each module's centre
+    fn png_modules(png_data: &[u8]) -> Vec<qrcode::Color> {
+        let decoder = png::Decoder::new(std::io::Cursor::new(png_data));
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        let size = info.width as usize;
+        let modules = size / MODULE_PIXELS - QUIET_ZONE_MODULES * 2;
+
+        (0..modules * modules)
+            .map(|index| {
+                let x = (index % modules + QUIET_ZONE_MODULES) * MODULE_PIXELS + MODULE_PIXELS / 2;
+                let y = (index / modules + QUIET_ZONE_MODULES) * MODULE_PIXELS + MODULE_PIXELS / 2;
+                if pixels[y * size + x] == 0 { qrcode::Color::Dark } else { qrcode::Color::Light }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn qr_png_decodes_to_the_live_view_url() {
+        let url = live_view_url("http://{host}:{port}/live/{court}", "Court 1/A", "192.168.1.20", Some(8088)).unwrap();
+        assert_eq!(url, "http://192.168.1.20:8088/live/Court%201%2FA");
+
+        let expected = qrcode::QrCode::new(url.as_bytes()).unwrap().to_colors();
+        assert_eq!(png_modules(&qr_png(&url).unwrap()), expected);
+
+        let other = qrcode::QrCode::new(b"http://192.168.1.20:8088/live/Court%202").unwrap().to_colors();
+        assert_ne!(png_modules(&qr_png(&url).unwrap()), other);
+    }
+
+    #[test]
+    fn port_template_needs_a_running_server() {
+        assert!(live_view_url("http://{host}:{port}/live/{court}", "Court 1", "localhost", None).is_err());
+        assert_eq!(
+            live_view_url("https://scores.example.com/{court}", "Court 1", "localhost", None).unwrap(),
+            "https://scores.example.com/Court%201"
+        );
+    }
+}
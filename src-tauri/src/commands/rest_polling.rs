@@ -0,0 +1,271 @@
+// src-tauri/src/commands/rest_polling.rs
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use tracing::{info, warn};
+
+use crate::commands::live_data::store_court_data;
+use crate::error::AppError;
+
+const MIN_INTERVAL_MS: u64 = 250;
+const MAX_BACKOFF_MS: u64 = 5 * 60 * 1000;
+const REQUEST_TIMEOUT_SECS: u64 = 15;
+
+// Dot-separated paths into the response; numeric segments index arrays ("results.0.matches")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestPollingMapping {
+    // Path to the array of matches; the response root when omitted
+    pub matches_path: Option<String>,
+    // Path to the court name within each match entry
+    pub court_path: String,
+    // Path to the payload to store within each entry; the whole entry when omitted
+    pub match_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RestPollingStatus {
+    pub connection_id: String,
+    pub url: String,
+    pub interval_ms: u64,
+    pub is_running: bool,
+    pub polls: u64,
+    pub not_modified_count: u64,
+    pub matches_stored: u64,
+    pub consecutive_errors: u32,
+    pub last_poll: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+lazy_static! {
+    static ref REST_POLLERS: Arc<Mutex<HashMap<String, JoinHandle<()>>>> = Arc::new(Mutex::new(HashMap::new()));
+    static ref REST_POLLING_STATUS: Arc<Mutex<HashMap<String, RestPollingStatus>>> = Arc::new(Mutex::new(HashMap::new()));
+}
+
+// Also used by the binding resolver
+pub(crate) fn value_at_path<'a>(value: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |current, segment| match current {
+            serde_json::Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => current.get(segment),
+        })
+}
+
+// Pull (court name, match payload) pairs out of a response; entries without a court are skipped
+pub fn extract_matches(body: &serde_json::Value, mapping: &RestPollingMapping) -> Result<Vec<(String, serde_json::Value)>, AppError> {
+    let matches = match mapping.matches_path.as_deref() {
+        Some(path) => value_at_path(body, path)
+            .ok_or_else(|| AppError::validation("matchesPath", format!("Matches path '{}' not found in response", path)))?,
+        None => body,
+    };
+    let matches = matches.as_array()
+        .ok_or_else(|| AppError::validation("matchesPath", "Matches path does not point to an array"))?;
+
+    Ok(matches.iter().filter_map(|entry| {
+        let court = value_at_path(entry, &mapping.court_path)?.as_str()?.trim();
+        if court.is_empty() {
+            return None;
+        }
+        let payload = match mapping.match_path.as_deref() {
+            Some(path) => value_at_path(entry, path)?,
+            None => entry,
+        };
+        Some((court.to_string(), payload.clone()))
+    }).collect())
+}
+
+// Exponential backoff from the poll interval, capped at five minutes
+fn backoff_delay(interval_ms: u64, consecutive_errors: u32) -> Duration {
+    let factor = 2u64.saturating_pow(consecutive_errors.min(16));
+    Duration::from_millis(interval_ms.saturating_mul(factor).min(MAX_BACKOFF_MS))
+}
+
+async fn update_status(connection_id: &str, update: impl FnOnce(&mut RestPollingStatus)) {
+    if let Some(status) = REST_POLLING_STATUS.lock().await.get_mut(connection_id) {
+        update(status);
+    }
+}
+
+#[derive(Default)]
+struct CacheValidators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+// One request; Ok(None) means the server reported the data unchanged
+async fn poll_once(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &HashMap<String, String>,
+    validators: &mut CacheValidators,
+) -> Result<Option<serde_json::Value>, AppError> {
+    let mut request = client.get(url);
+    for (name, value) in headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+    if let Some(etag) = &validators.etag {
+        request = request.header(IF_NONE_MATCH, etag.as_str());
+    }
+    if let Some(last_modified) = &validators.last_modified {
+        request = request.header(IF_MODIFIED_SINCE, last_modified.as_str());
+    }
+
+    let response = request.send().await
+        .map_err(|e| AppError::external("rest", format!("Request failed: {}", e)))?;
+
+    if response.status() == StatusCode::NOT_MODIFIED {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        return Err(AppError::external("rest", format!("Server returned {}", response.status())));
+    }
+
+    let headers = response.headers();
+    validators.etag = headers.get(ETAG).and_then(|v| v.to_str().ok()).map(|v| v.to_string());
+    validators.last_modified = headers.get(LAST_MODIFIED).and_then(|v| v.to_str().ok()).map(|v| v.to_string());
+
+    let body = response.json::<serde_json::Value>().await
+        .map_err(|e| AppError::external("rest", format!("Failed to parse JSON response: {}", e)))?;
+    Ok(Some(body))
+}
+
+pub(crate) async fn running_poller_count() -> usize {
+    REST_POLLERS.lock().await.values().filter(|handle| !handle.is_finished()).count()
+}
+
+#[tauri::command]
+pub async fn start_rest_polling_connection(
+    connection_id: String,
+    url: String,
+    headers: Option<HashMap<String, String>>,
+    interval_ms: u64,
+    mapping: RestPollingMapping,
+) -> Result<String, AppError> {
+    let mut pollers = REST_POLLERS.lock().await;
+    if pollers.get(&connection_id).map(|handle| !handle.is_finished()).unwrap_or(false) {
+        return Err(AppError::conflict(format!("REST polling already running for: {}", connection_id)));
+    }
+
+    let parsed_url = url::Url::parse(&url)
+        .map_err(|e| AppError::validation("url", format!("Invalid polling URL: {}", e)))?;
+    if !matches!(parsed_url.scheme(), "http" | "https") {
+        return Err(AppError::validation("url", "Polling URL must use http or https"));
+    }
+
+    let interval_ms = interval_ms.max(MIN_INTERVAL_MS);
+    let headers = headers.unwrap_or_default();
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .build()
+        .map_err(|e| AppError::external("rest", format!("Failed to create HTTP client: {}", e)))?;
+
+    REST_POLLING_STATUS.lock().await.insert(connection_id.clone(), RestPollingStatus {
+        connection_id: connection_id.clone(),
+        url: url.clone(),
+        interval_ms,
+        is_running: true,
+        ..RestPollingStatus::default()
+    });
+
+    let task_connection_id = connection_id.clone();
+    let handle = tokio::spawn(async move {
+        let mut validators = CacheValidators::default();
+        let mut consecutive_errors: u32 = 0;
+
+        loop {
+            let result = poll_once(&client, &url, &headers, &mut validators).await;
+
+            let delay = match result {
+                Ok(Some(body)) => match extract_matches(&body, &mapping) {
+                    Ok(matches) => {
+                        consecutive_errors = 0;
+                        let mut stored = 0;
+                        for (court, payload) in matches {
+                            if store_court_data(&task_connection_id, &court, payload).await {
+                                stored += 1;
+                            }
+                        }
+                        update_status(&task_connection_id, |status| {
+                            status.polls += 1;
+                            status.matches_stored += stored;
+                            status.consecutive_errors = 0;
+                            status.last_poll = Some(Utc::now());
+                            status.last_error = None;
+                        }).await;
+                        Duration::from_millis(interval_ms)
+                    }
+                    Err(e) => {
+                        consecutive_errors += 1;
+                        // A mapping error won't fix itself; drop validators so the next poll re-reads the body
+                        validators = CacheValidators::default();
+                        warn!("REST polling mapping error for {}: {}", task_connection_id, e);
+                        update_status(&task_connection_id, |status| {
+                            status.polls += 1;
+                            status.consecutive_errors = consecutive_errors;
+                            status.last_poll = Some(Utc::now());
+                            status.last_error = Some(e.to_string());
+                        }).await;
+                        backoff_delay(interval_ms, consecutive_errors)
+                    }
+                },
+                Ok(None) => {
+                    consecutive_errors = 0;
+                    update_status(&task_connection_id, |status| {
+                        status.polls += 1;
+                        status.not_modified_count += 1;
+                        status.consecutive_errors = 0;
+                        status.last_poll = Some(Utc::now());
+                    }).await;
+                    Duration::from_millis(interval_ms)
+                }
+                Err(e) => {
+                    consecutive_errors += 1;
+                    warn!("REST polling error for {}: {}", task_connection_id, e);
+                    update_status(&task_connection_id, |status| {
+                        status.polls += 1;
+                        status.consecutive_errors = consecutive_errors;
+                        status.last_poll = Some(Utc::now());
+                        status.last_error = Some(e.to_string());
+                    }).await;
+                    backoff_delay(interval_ms, consecutive_errors)
+                }
+            };
+
+            tokio::time::sleep(delay).await;
+        }
+    });
+
+    pollers.insert(connection_id.clone(), handle);
+    info!("🌐 Started REST polling for {} every {}ms", connection_id, interval_ms);
+    Ok(format!("Started REST polling for: {}", connection_id))
+}
+
+#[tauri::command]
+pub async fn stop_rest_polling_connection(connection_id: String) -> Result<String, AppError> {
+    let mut pollers = REST_POLLERS.lock().await;
+
+    if let Some(handle) = pollers.remove(&connection_id) {
+        handle.abort();
+        update_status(&connection_id, |status| status.is_running = false).await;
+        info!("🛑 Stopped REST polling for {}", connection_id);
+        Ok(format!("Stopped REST polling for: {}", connection_id))
+    } else {
+        Err(AppError::not_found(format!("REST polling for {}", connection_id)))
+    }
+}
+
+#[tauri::command]
+pub async fn get_rest_polling_status(connection_id: Option<String>) -> Result<Vec<RestPollingStatus>, AppError> {
+    let statuses = REST_POLLING_STATUS.lock().await;
+    Ok(match connection_id {
+        Some(id) => statuses.get(&id).cloned().into_iter().collect(),
+        None => statuses.values().cloned().collect(),
+    })
+}
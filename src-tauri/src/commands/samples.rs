@@ -0,0 +1,187 @@
+// src-tauri/src/commands/samples.rs
+//
+// Sample content for new installs, so the first launch opens to a few boards,
+// logos and a simulator connection instead of an empty library. Everything is
+// bundled into the binary and written through the normal save paths. Samples
+// never replace an existing board, image or connection: a board of the same
+// name is only rewritten by `reset_sample_content` when it is still a sample
+// (its data carries our `sampleId`).
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use tracing::{error, info, warn};
+
+use crate::commands::data_dir::data_dir;
+use crate::commands::events::BackendEvent;
+use crate::commands::images::{load_image_metadata, store_image_with_id};
+use crate::commands::media_library::write_atomic;
+use crate::commands::storage::{
+    load_live_data_connections, save_live_data_connections, save_scoreboard, scoreboard_save_target,
+    LiveDataConnectionData,
+};
+use crate::error::AppError;
+
+// Written once the first launch has been handled, whether or not anything was seeded
+const FIRST_RUN_MARKER: &str = "first_run.json";
+
+const SAMPLE_SCOREBOARDS: &[&str] = &[
+    include_str!("../../samples/scoreboards/tennis_singles.json"),
+    include_str!("../../samples/scoreboards/tennis_doubles.json"),
+    include_str!("../../samples/scoreboards/welcome_screen.json"),
+];
+
+// (image id, original file name, SVG)
+const SAMPLE_IMAGES: &[(&str, &str, &str)] = &[
+    ("sample-logo-club", "Sample club logo.svg", include_str!("../../samples/images/club_logo.svg")),
+    ("sample-logo-sponsor", "Sample sponsor logo.svg", include_str!("../../samples/images/sponsor_logo.svg")),
+    ("sample-court-banner", "Sample court banner.svg", include_str!("../../samples/images/court_banner.svg")),
+];
+
+const SAMPLE_CONNECTION_ID: &str = "sample-simulator";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SampleContentReport {
+    // Board file names, image ids and connection ids written this time
+    pub scoreboards: Vec<String>,
+    pub images: Vec<String>,
+    pub connections: Vec<String>,
+    // Samples left alone because something of the same name or id already exists
+    pub skipped: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FirstRunMarker {
+    completed_at: chrono::DateTime<chrono::Utc>,
+    seeded: bool,
+}
+
+async fn seed_scoreboards(app: &AppHandle, overwrite_samples: bool, report: &mut SampleContentReport) -> Result<(), AppError> {
+    let scoreboards_dir = data_dir(app)?.join("scoreboards");
+    for sample in SAMPLE_SCOREBOARDS {
+        let data: serde_json::Value = serde_json::from_str(sample)?;
+        let name = data.get("name").and_then(|name| name.as_str()).unwrap_or("Sample").to_string();
+        let sample_id = data.get("sampleId").cloned();
+
+        let (_, existing) = scoreboard_save_target(&scoreboards_dir, &name).await;
+        if let Some(existing) = existing {
+            let still_sample = sample_id.is_some() && existing.data.get("sampleId") == sample_id.as_ref();
+            if !(overwrite_samples && still_sample) {
+                report.skipped.push(name);
+                continue;
+            }
+        }
+        report.scoreboards.push(save_scoreboard(app.clone(), name, data).await?);
+    }
+    Ok(())
+}
+
+async fn seed_images(app: &AppHandle, report: &mut SampleContentReport) -> Result<(), AppError> {
+    let existing = load_image_metadata(app).await?;
+    for (id, file_name, svg) in SAMPLE_IMAGES {
+        if existing.iter().any(|image| image.id == *id) {
+            report.skipped.push(id.to_string());
+            continue;
+        }
+        store_image_with_id(app, id.to_string(), file_name.to_string(), "image/svg+xml".to_string(), svg.as_bytes()).await?;
+        report.images.push(id.to_string());
+    }
+    Ok(())
+}
+
+// A mock connection, off by default, for trying bindings against the match simulator
+async fn seed_connection(app: &AppHandle, report: &mut SampleContentReport) -> Result<(), AppError> {
+    let mut state = load_live_data_connections(app.clone()).await?;
+    if state.connections.iter().any(|connection| connection.id == SAMPLE_CONNECTION_ID) {
+        report.skipped.push(SAMPLE_CONNECTION_ID.to_string());
+        return Ok(());
+    }
+
+    state.connections.push(LiveDataConnectionData {
+        id: SAMPLE_CONNECTION_ID.to_string(),
+        name: "Match simulator (sample)".to_string(),
+        provider: "mock".to_string(),
+        api_url: "simulator://Center Court".to_string(),
+        token: String::new(),
+        poll_interval: 2,
+        is_active: false,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        updated_at: None,
+        last_updated: None,
+        last_error: None,
+        transform: None,
+        priority: 0,
+        update_mode: Default::default(),
+        message_types: Vec::new(),
+    });
+    save_live_data_connections(app.clone(), state).await?;
+    report.connections.push(SAMPLE_CONNECTION_ID.to_string());
+    Ok(())
+}
+
+// Images go first so the boards that reference them never point at nothing
+async fn seed_samples(app: &AppHandle, overwrite_samples: bool) -> Result<SampleContentReport, AppError> {
+    let mut report = SampleContentReport::default();
+    seed_images(app, &mut report).await?;
+    seed_scoreboards(app, overwrite_samples, &mut report).await?;
+    seed_connection(app, &mut report).await?;
+    Ok(report)
+}
+
+async fn has_saved_scoreboards(app: &AppHandle) -> Result<bool, AppError> {
+    let Ok(mut entries) = tokio::fs::read_dir(data_dir(app)?.join("scoreboards")).await else {
+        return Ok(false);
+    };
+    while let Some(entry) = entries.next_entry().await? {
+        if entry.path().extension().is_some_and(|ext| ext == "json") {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+async fn run_first_launch(app: &AppHandle) -> Result<Option<SampleContentReport>, AppError> {
+    let marker = data_dir(app)?.join(FIRST_RUN_MARKER);
+    if tokio::fs::try_exists(&marker).await? {
+        return Ok(None);
+    }
+
+    // Installs from before seeding existed have no marker but do have a library
+    let report = if has_saved_scoreboards(app).await? {
+        None
+    } else {
+        Some(seed_samples(app, false).await?)
+    };
+
+    let contents = FirstRunMarker { completed_at: chrono::Utc::now(), seeded: report.is_some() };
+    write_atomic(&marker, &serde_json::to_vec_pretty(&contents)?).await?;
+    Ok(report)
+}
+
+// Seeds samples on the very first launch and emits `first_run_seeded` so the UI can open its welcome tour
+pub fn seed_first_run(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match run_first_launch(&app).await {
+            Ok(Some(report)) => {
+                info!("🌱 First run: seeded {} board(s), {} image(s), {} connection(s)",
+                    report.scoreboards.len(), report.images.len(), report.connections.len());
+                if let Err(e) = app.emit(BackendEvent::FirstRunSeeded.as_str(), &report) {
+                    error!("Failed to emit {}: {}", BackendEvent::FirstRunSeeded, e);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to seed sample content: {}", e),
+        }
+    });
+}
+
+// Puts back any missing samples and restores sample boards to their original
+// layout. Boards, images and connections that aren't samples are never touched.
+#[tauri::command]
+pub async fn reset_sample_content(app: AppHandle) -> Result<SampleContentReport, AppError> {
+    let report = seed_samples(&app, true).await?;
+    info!("🌱 Restored sample content: {} board(s), {} image(s), {} connection(s)",
+        report.scoreboards.len(), report.images.len(), report.connections.len());
+    Ok(report)
+}
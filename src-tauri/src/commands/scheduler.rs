@@ -0,0 +1,368 @@
+// src-tauri/src/commands/scheduler.rs
+//
+// Display and live data actions that run at set times, e.g. boards appearing
+// for the 10:00 and 18:00 sessions. Actions are kept in app data and checked by
+// a background task, so a schedule survives restarts. Anything that came due
+// while the app was closed is skipped rather than run late.
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Local, Utc};
+use tokio::sync::Mutex;
+use lazy_static::lazy_static;
+use tauri::{AppHandle, Emitter, Manager};
+use tracing::{error, info, warn};
+
+use crate::commands::court_data_sync::trigger_manual_sync;
+use crate::commands::events::BackendEvent;
+use crate::commands::launch::{connect_saved_connections, open_scoreboard_window, LaunchOptions, LaunchReport};
+use crate::commands::live_data::{disconnect_websocket, stop_websocket_listener};
+use crate::commands::storage::{load_live_data_connections, sanitize_filename};
+use crate::commands::data_dir::data_dir;
+
+const SCHEDULE_FILE: &str = "scheduled_actions.json";
+const AUDIT_FILE: &str = "scheduler_audit.jsonl";
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+// How late an action may still run; anything older counts as missed
+const MISSED_GRACE_SECS: i64 = 60;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduledActionKind {
+    // `display` names the window so later actions can find it
+    CreateDisplay {
+        display: String,
+        scoreboard: String,
+        monitor: u32,
+        #[serde(default = "default_fullscreen")]
+        fullscreen: bool,
+        court: Option<String>,
+    },
+    CloseDisplay { display: String },
+    ShowDisplay { display: String },
+    HideDisplay { display: String },
+    // Without an ID, every active saved connection
+    StartLiveData { connection_id: Option<String> },
+    StopLiveData { connection_id: Option<String> },
+    CourtSync,
+}
+
+fn default_fullscreen() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Recurrence {
+    #[default]
+    Once,
+    Daily,
+    Weekly,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledAction {
+    pub id: String,
+    pub action: ScheduledActionKind,
+    pub run_at: DateTime<Utc>,
+    pub recurrence: Recurrence,
+    pub created_at: DateTime<Utc>,
+    pub last_run: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledActionExecution {
+    pub id: String,
+    pub action: ScheduledActionKind,
+    pub scheduled_for: DateTime<Utc>,
+    pub executed_at: DateTime<Utc>,
+    pub skipped: bool,
+    pub success: bool,
+    pub message: String,
+}
+
+lazy_static! {
+    static ref SCHEDULED_ACTIONS: Arc<Mutex<Vec<ScheduledAction>>> = Arc::new(Mutex::new(Vec::new()));
+}
+
+// Scoreboard windows must keep the scoreboard_ prefix to be managed as displays
+pub fn display_window_label(display: &str) -> String {
+    format!("scoreboard_scheduled_{}", sanitize_filename(display))
+}
+
+// The next occurrence after `run_at`, stepping in local time so a daily 10:00
+// stays at 10:00 across daylight saving changes
+pub fn next_occurrence(run_at: DateTime<Utc>, recurrence: Recurrence) -> Option<DateTime<Utc>> {
+    let days = match recurrence {
+        Recurrence::Once => return None,
+        Recurrence::Daily => 1,
+        Recurrence::Weekly => 7,
+    };
+    let local = run_at.with_timezone(&Local).naive_local() + chrono::Duration::days(days);
+    local.and_local_timezone(Local)
+        .earliest()
+        // The wall-clock time doesn't exist that day (spring forward); keep the interval instead
+        .map(|next| next.with_timezone(&Utc))
+        .or_else(|| Some(run_at + chrono::Duration::days(days)))
+}
+
+// First occurrence after `now`, or None once a one-off action has passed
+pub fn next_run_after(run_at: DateTime<Utc>, recurrence: Recurrence, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let mut next = Some(run_at);
+    while let Some(candidate) = next.filter(|candidate| *candidate <= now) {
+        next = next_occurrence(candidate, recurrence);
+    }
+    next
+}
+
+pub enum Due {
+    NotYet,
+    Run,
+    Missed,
+}
+
+pub fn due_state(run_at: DateTime<Utc>, now: DateTime<Utc>) -> Due {
+    if run_at > now {
+        Due::NotYet
+    } else if (now - run_at).num_seconds() > MISSED_GRACE_SECS {
+        Due::Missed
+    } else {
+        Due::Run
+    }
+}
+
+fn app_data_path(app: &AppHandle, file: &str) -> Result<PathBuf, String> {
+    let app_data_dir = data_dir(app)
+        .map_err(|e| e.to_string())?;
+    Ok(app_data_dir.join(file))
+}
+
+fn load_schedule(app: &AppHandle) -> Vec<ScheduledAction> {
+    app_data_path(app, SCHEDULE_FILE).ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_schedule(app: &AppHandle, actions: &[ScheduledAction]) -> Result<(), String> {
+    let path = app_data_path(app, SCHEDULE_FILE)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create app data directory: {}", e))?;
+    }
+    let json = serde_json::to_string_pretty(actions)
+        .map_err(|e| format!("Failed to serialize scheduled actions: {}", e))?;
+    std::fs::write(&path, json)
+        .map_err(|e| format!("Failed to write scheduled actions: {}", e))
+}
+
+fn append_audit_entry(app: &AppHandle, execution: &ScheduledActionExecution) -> Result<(), String> {
+    let path = app_data_path(app, AUDIT_FILE)?;
+    let line = serde_json::to_string(execution).map_err(|e| e.to_string())?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open scheduler audit log: {}", e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("Failed to write scheduler audit log: {}", e))
+}
+
+async fn stop_saved_connections(app: &AppHandle, connection_id: Option<&str>) -> Result<String, String> {
+    let saved = load_live_data_connections(app.clone()).await?;
+    let mut stopped = 0;
+    for connection in saved.connections.iter().filter(|c| connection_id.is_none_or(|id| c.id == id)) {
+        // Either half may already be gone; only count connections that were open
+        let _ = stop_websocket_listener(connection.id.clone()).await;
+        if disconnect_websocket(connection.id.clone()).await.is_ok() {
+            stopped += 1;
+        }
+    }
+    Ok(format!("Stopped {} live data connection(s)", stopped))
+}
+
+async fn run_action(app: &AppHandle, action: &ScheduledActionKind) -> Result<String, String> {
+    match action {
+        ScheduledActionKind::CreateDisplay { display, scoreboard, monitor, fullscreen, court } => {
+            let label = display_window_label(display);
+            if app.get_webview_window(&label).is_some() {
+                return Err(format!("Display '{}' is already open", display));
+            }
+            let options = LaunchOptions {
+                monitor: Some(*monitor),
+                fullscreen: *fullscreen,
+                court: court.clone(),
+                ..Default::default()
+            };
+            open_scoreboard_window(app, &options, scoreboard, &label).await?;
+            Ok(format!("Opened '{}' as display '{}'", scoreboard, display))
+        }
+        ScheduledActionKind::CloseDisplay { display }
+        | ScheduledActionKind::ShowDisplay { display }
+        | ScheduledActionKind::HideDisplay { display } => {
+            let window = app.get_webview_window(&display_window_label(display))
+                .ok_or_else(|| format!("Display '{}' is not open", display))?;
+            let result = match action {
+                ScheduledActionKind::CloseDisplay { .. } => window.close(),
+                ScheduledActionKind::ShowDisplay { .. } => window.show(),
+                _ => window.hide(),
+            };
+            result.map_err(|e| format!("Failed to update display '{}': {}", display, e))?;
+            Ok(format!("Display '{}' updated", display))
+        }
+        ScheduledActionKind::StartLiveData { connection_id } => {
+            let mut report = LaunchReport::default();
+            connect_saved_connections(app, connection_id.as_deref(), &mut report).await?;
+            if !report.errors.is_empty() {
+                return Err(report.errors.join("; "));
+            }
+            Ok(format!("Started {} live data connection(s)", report.connected.len()))
+        }
+        ScheduledActionKind::StopLiveData { connection_id } => {
+            stop_saved_connections(app, connection_id.as_deref()).await
+        }
+        ScheduledActionKind::CourtSync => trigger_manual_sync().await,
+    }
+}
+
+fn record_execution(app: &AppHandle, execution: &ScheduledActionExecution) {
+    if let Err(e) = append_audit_entry(app, execution) {
+        warn!("{}", e);
+    }
+    if let Err(e) = app.emit(BackendEvent::ScheduledActionExecuted.as_str(), execution) {
+        error!("Failed to emit {}: {}", BackendEvent::ScheduledActionExecuted, e);
+    }
+}
+
+// Runs or skips everything that is due, then reschedules recurring actions
+async fn process_due_actions(app: &AppHandle) {
+    let now = Utc::now();
+    let due: Vec<(ScheduledAction, bool)> = {
+        let actions = SCHEDULED_ACTIONS.lock().await;
+        actions.iter()
+            .filter_map(|action| match due_state(action.run_at, now) {
+                Due::NotYet => None,
+                Due::Run => Some((action.clone(), false)),
+                Due::Missed => Some((action.clone(), true)),
+            })
+            .collect()
+    };
+    if due.is_empty() {
+        return;
+    }
+
+    for (action, missed) in &due {
+        let (success, message) = if *missed {
+            info!("⏰ Skipping missed scheduled action {} (was due {})", action.id, action.run_at);
+            (false, "Missed while the app was not running".to_string())
+        } else {
+            match run_action(app, &action.action).await {
+                Ok(message) => {
+                    info!("⏰ Ran scheduled action {}: {}", action.id, message);
+                    (true, message)
+                }
+                Err(e) => {
+                    warn!("⏰ Scheduled action {} failed: {}", action.id, e);
+                    (false, e)
+                }
+            }
+        };
+
+        record_execution(app, &ScheduledActionExecution {
+            id: action.id.clone(),
+            action: action.action.clone(),
+            scheduled_for: action.run_at,
+            executed_at: Utc::now(),
+            skipped: *missed,
+            success,
+            message,
+        });
+    }
+
+    let mut actions = SCHEDULED_ACTIONS.lock().await;
+    actions.retain_mut(|action| {
+        if !due.iter().any(|(d, _)| d.id == action.id) {
+            return true;
+        }
+        if !matches!(due_state(action.run_at, now), Due::Missed) {
+            action.last_run = Some(now);
+        }
+        match next_run_after(action.run_at, action.recurrence, now) {
+            Some(run_at) => {
+                action.run_at = run_at;
+                true
+            }
+            None => false,
+        }
+    });
+    if let Err(e) = save_schedule(app, &actions) {
+        error!("{}", e);
+    }
+}
+
+pub fn start_scheduler(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let loaded = load_schedule(&app);
+        if !loaded.is_empty() {
+            info!("⏰ Loaded {} scheduled action(s)", loaded.len());
+        }
+        *SCHEDULED_ACTIONS.lock().await = loaded;
+
+        let mut interval = tokio::time::interval(TICK_INTERVAL);
+        loop {
+            interval.tick().await;
+            process_due_actions(&app).await;
+        }
+    });
+}
+
+#[tauri::command]
+pub async fn add_scheduled_action(
+    app: AppHandle,
+    action: ScheduledActionKind,
+    run_at: DateTime<Utc>,
+    recurrence: Option<Recurrence>,
+) -> Result<ScheduledAction, String> {
+    let recurrence = recurrence.unwrap_or_default();
+    // A recurring action starting in the past begins at its next occurrence
+    let run_at = next_run_after(run_at, recurrence, Utc::now())
+        .ok_or("Scheduled time is in the past")?;
+
+    let scheduled = ScheduledAction {
+        id: uuid::Uuid::new_v4().to_string(),
+        action,
+        run_at,
+        recurrence,
+        created_at: Utc::now(),
+        last_run: None,
+    };
+
+    let mut actions = SCHEDULED_ACTIONS.lock().await;
+    actions.push(scheduled.clone());
+    save_schedule(&app, &actions)?;
+
+    info!("⏰ Scheduled {:?} for {} ({:?})", scheduled.action, scheduled.run_at, recurrence);
+    Ok(scheduled)
+}
+
+#[tauri::command]
+pub async fn list_scheduled_actions() -> Result<Vec<ScheduledAction>, String> {
+    let mut actions = SCHEDULED_ACTIONS.lock().await.clone();
+    actions.sort_by_key(|action| action.run_at);
+    Ok(actions)
+}
+
+#[tauri::command]
+pub async fn remove_scheduled_action(app: AppHandle, id: String) -> Result<(), String> {
+    let mut actions = SCHEDULED_ACTIONS.lock().await;
+    let before = actions.len();
+    actions.retain(|action| action.id != id);
+    if actions.len() == before {
+        return Err(format!("No scheduled action with ID: {}", id));
+    }
+    save_schedule(&app, &actions)
+}
@@ -1,10 +1,18 @@
 // src-tauri/src/commands/scoreboard.rs
 use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use tauri::{AppHandle, Emitter, State};
+use tauri::{AppHandle, State};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::commands::events::BackendEvent;
+use crate::commands::images::{load_image_metadata, stored_image_ref, StoredImage};
+use crate::commands::session_recorder::{record_command, RecordedCommand};
+use crate::commands::conditional_visibility::schedule_visibility_refresh;
+use crate::commands::window_updates::emit_to_unpaused;
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GameState {
     pub home_team: Team,
     pub away_team: Team,
@@ -15,9 +23,76 @@ pub struct GameState {
     pub is_game_active: bool,
     pub sport: String,
     pub metadata: HashMap<String, serde_json::Value>,
+    // Overrides the sport's usual clock direction when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clock_direction: Option<ClockDirection>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ClockDirection {
+    Up,
+    Down,
+    None,
+}
+
+// How each sport's game clock runs; sports without a clock report None
+pub fn default_clock_direction(sport: &str) -> ClockDirection {
+    match sport.to_lowercase().as_str() {
+        "basketball" | "football" | "hockey" => ClockDirection::Down,
+        "soccer" => ClockDirection::Up,
+        "tennis" | "volleyball" | "baseball" => ClockDirection::None,
+        _ => ClockDirection::Down,
+    }
+}
+
+pub fn validate_clock_direction(sport: &str, direction: ClockDirection) -> Result<(), AppError> {
+    if direction != ClockDirection::None && default_clock_direction(sport) == ClockDirection::None {
+        return Err(AppError::validation("direction", format!("{} has no game clock", sport)));
+    }
+    Ok(())
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+// Longest clock we display, 99:59
+const MAX_CLOCK_SECONDS: u32 = 99 * 60 + 59;
+
+// Accept "MM:SS", "M:SS" or bare seconds and normalize to "MM:SS", e.g.
+// "5:3" -> "05:03" and "90" -> "01:30"
+pub fn normalize_clock_string(value: &str) -> Result<String, AppError> {
+    let invalid = || AppError::validation("time_remaining", format!(
+        "Invalid clock '{}': expected MM:SS, M:SS or seconds", value
+    ));
+    let parse = |part: &str| -> Option<u32> {
+        (!part.is_empty() && part.len() <= 4 && part.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| part.parse().ok())
+            .flatten()
+    };
+
+    let value = value.trim();
+    let total_seconds = match value.split_once(':') {
+        Some((minutes, seconds)) => {
+            let (minutes, seconds) = (parse(minutes).ok_or_else(invalid)?, parse(seconds).ok_or_else(invalid)?);
+            if seconds >= 60 {
+                return Err(invalid());
+            }
+            minutes * 60 + seconds
+        }
+        None => parse(value).ok_or_else(invalid)?,
+    };
+
+    if total_seconds > MAX_CLOCK_SECONDS {
+        return Err(AppError::validation("time_remaining", format!("Clock '{}' is longer than 99:59", value)));
+    }
+    Ok(format!("{:02}:{:02}", total_seconds / 60, total_seconds % 60))
+}
+
+impl GameState {
+    pub fn effective_clock_direction(&self) -> ClockDirection {
+        self.clock_direction.unwrap_or_else(|| default_clock_direction(&self.sport))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Team {
     pub id: String,
     pub name: String,
@@ -32,29 +107,36 @@ pub struct ScoreboardState {
     pub game_state: Arc<Mutex<Option<GameState>>>,
 }
 
+// Sends a game-state event to the displays and re-checks the visibility rules that depend on it
+fn emit_game_state(app: &AppHandle, event: BackendEvent, game_state: &GameState) -> tauri::Result<()> {
+    emit_to_unpaused(app, event, game_state)?;
+    schedule_visibility_refresh(app);
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn update_game_state(
     state: State<'_, ScoreboardState>,
     app: AppHandle,
     game_state: GameState,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     {
-        let mut current_state = state.game_state.lock().map_err(|e| e.to_string())?;
+        let mut current_state = state.game_state.lock()?;
         *current_state = Some(game_state.clone());
     }
     
     // Emit event to all windows
-    app.emit("game_state_updated", &game_state)
-        .map_err(|e| e.to_string())?;
+    emit_game_state(&app, BackendEvent::GameStateUpdated, &game_state)?;
     
+    record_command(RecordedCommand::UpdateGameState { game_state: Box::new(game_state) });
     Ok(())
 }
 
 #[tauri::command]
 pub async fn get_game_state(
     state: State<'_, ScoreboardState>,
-) -> Result<Option<GameState>, String> {
-    let game_state = state.game_state.lock().map_err(|e| e.to_string())?;
+) -> Result<Option<GameState>, AppError> {
+    let game_state = state.game_state.lock()?;
     Ok(game_state.clone())
 }
 
@@ -64,22 +146,22 @@ pub async fn update_score(
     app: AppHandle,
     team: String, // "home" or "away"
     score: u32,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     {
-        let mut current_state = state.game_state.lock().map_err(|e| e.to_string())?;
+        let mut current_state = state.game_state.lock()?;
         if let Some(ref mut game_state) = *current_state {
             match team.as_str() {
                 "home" => game_state.home_score = score,
                 "away" => game_state.away_score = score,
-                _ => return Err("Invalid team specified".to_string()),
+                _ => return Err(AppError::validation("team", "Invalid team specified")),
             }
             
             // Emit score update event
-            app.emit("score_updated", &*game_state)
-                .map_err(|e| e.to_string())?;
+            emit_game_state(&app, BackendEvent::ScoreUpdated, &*game_state)?;
         }
     }
     
+    record_command(RecordedCommand::UpdateScore { team, score });
     Ok(())
 }
 
@@ -88,38 +170,45 @@ pub async fn update_time(
     state: State<'_, ScoreboardState>,
     app: AppHandle,
     time_remaining: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
+    let time_remaining = normalize_clock_string(&time_remaining)?;
     {
-        let mut current_state = state.game_state.lock().map_err(|e| e.to_string())?;
+        let mut current_state = state.game_state.lock()?;
         if let Some(ref mut game_state) = *current_state {
-            game_state.time_remaining = time_remaining;
+            game_state.time_remaining = time_remaining.clone();
             
             // Emit time update event
-            app.emit("time_updated", &*game_state)
-                .map_err(|e| e.to_string())?;
+            emit_game_state(&app, BackendEvent::TimeUpdated, &*game_state)?;
         }
     }
     
+    record_command(RecordedCommand::UpdateTime { time_remaining });
     Ok(())
 }
 
+// Lets the UI check a clock entry before sending it, and show how it will be stored
+#[tauri::command]
+pub async fn validate_clock_string(time_remaining: String) -> Result<String, AppError> {
+    normalize_clock_string(&time_remaining)
+}
+
 #[tauri::command]
 pub async fn update_period(
     state: State<'_, ScoreboardState>,
     app: AppHandle,
     period: u32,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     {
-        let mut current_state = state.game_state.lock().map_err(|e| e.to_string())?;
+        let mut current_state = state.game_state.lock()?;
         if let Some(ref mut game_state) = *current_state {
             game_state.period = period;
             
             // Emit period update event
-            app.emit("period_updated", &*game_state)
-                .map_err(|e| e.to_string())?;
+            emit_game_state(&app, BackendEvent::PeriodUpdated, &*game_state)?;
         }
     }
     
+    record_command(RecordedCommand::UpdatePeriod { period });
     Ok(())
 }
 
@@ -127,23 +216,23 @@ pub async fn update_period(
 pub async fn toggle_game_active(
     state: State<'_, ScoreboardState>,
     app: AppHandle,
-) -> Result<bool, String> {
+) -> Result<bool, AppError> {
     let new_state = {
-        let mut current_state = state.game_state.lock().map_err(|e| e.to_string())?;
+        let mut current_state = state.game_state.lock()?;
         if let Some(ref mut game_state) = *current_state {
             game_state.is_game_active = !game_state.is_game_active;
             let new_state = game_state.is_game_active;
             
             // Emit game state change event
-            app.emit("game_active_toggled", &*game_state)
-                .map_err(|e| e.to_string())?;
+            emit_game_state(&app, BackendEvent::GameActiveToggled, &*game_state)?;
             
             new_state
         } else {
-            return Err("No game state available".to_string());
+            return Err(AppError::conflict("No game state available"));
         }
     };
     
+    record_command(RecordedCommand::ToggleGameActive);
     Ok(new_state)
 }
 
@@ -151,9 +240,9 @@ pub async fn toggle_game_active(
 pub async fn reset_game(
     state: State<'_, ScoreboardState>,
     app: AppHandle,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     {
-        let mut current_state = state.game_state.lock().map_err(|e| e.to_string())?;
+        let mut current_state = state.game_state.lock()?;
         if let Some(ref mut game_state) = *current_state {
             game_state.home_score = 0;
             game_state.away_score = 0;
@@ -163,11 +252,11 @@ pub async fn reset_game(
             game_state.metadata.clear();
             
             // Emit reset event
-            app.emit("game_reset", &*game_state)
-                .map_err(|e| e.to_string())?;
+            emit_game_state(&app, BackendEvent::GameReset, &*game_state)?;
         }
     }
     
+    record_command(RecordedCommand::ResetGame);
     Ok(())
 }
 
@@ -177,21 +266,169 @@ pub async fn update_team_info(
     app: AppHandle,
     team_side: String, // "home" or "away"
     team: Team,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     {
-        let mut current_state = state.game_state.lock().map_err(|e| e.to_string())?;
+        let mut current_state = state.game_state.lock()?;
         if let Some(ref mut game_state) = *current_state {
             match team_side.as_str() {
-                "home" => game_state.home_team = team,
-                "away" => game_state.away_team = team,
-                _ => return Err("Invalid team side specified".to_string()),
+                "home" => game_state.home_team = team.clone(),
+                "away" => game_state.away_team = team.clone(),
+                _ => return Err(AppError::validation("team_side", "Invalid team side specified")),
             }
             
             // Emit team info update event
-            app.emit("team_info_updated", &*game_state)
-                .map_err(|e| e.to_string())?;
+            emit_game_state(&app, BackendEvent::TeamInfoUpdated, &*game_state)?;
         }
     }
     
+    record_command(RecordedCommand::UpdateTeamInfo { team_side, team });
+    Ok(())
+}
+
+// Point a team's logo at a stored image; the frontend resolves the reference through get_image_data
+pub fn apply_team_logo(
+    game_state: &mut GameState,
+    team_side: &str,
+    image_id: &str,
+    images: &[StoredImage],
+) -> Result<Team, AppError> {
+    if !images.iter().any(|image| image.id == image_id) {
+        return Err(AppError::not_found(format!("Image {}", image_id)));
+    }
+    let team = match team_side {
+        "home" => &mut game_state.home_team,
+        "away" => &mut game_state.away_team,
+        _ => return Err(AppError::validation("team_side", "Invalid team side specified")),
+    };
+    team.logo_url = Some(stored_image_ref(image_id));
+    Ok(team.clone())
+}
+
+#[tauri::command]
+pub async fn set_team_logo(
+    state: State<'_, ScoreboardState>,
+    app: AppHandle,
+    team_side: String,
+    image_id: String,
+) -> Result<Team, AppError> {
+    let images = load_image_metadata(&app).await?;
+
+    let team = {
+        let mut current_state = state.game_state.lock()?;
+        let game_state = current_state.as_mut()
+            .ok_or_else(|| AppError::conflict("No game state available"))?;
+        let team = apply_team_logo(game_state, &team_side, &image_id, &images)?;
+        emit_game_state(&app, BackendEvent::TeamInfoUpdated, &*game_state)?;
+        team
+    };
+
+    record_command(RecordedCommand::UpdateTeamInfo { team_side, team: team.clone() });
+    Ok(team)
+}
+
+#[tauri::command]
+pub async fn get_clock_direction(
+    state: State<'_, ScoreboardState>,
+) -> Result<ClockDirection, AppError> {
+    let current_state = state.game_state.lock()?;
+    current_state.as_ref()
+        .map(|game_state| game_state.effective_clock_direction())
+        .ok_or_else(|| AppError::conflict("No game state available"))
+}
+
+#[tauri::command]
+pub async fn set_clock_direction(
+    state: State<'_, ScoreboardState>,
+    app: AppHandle,
+    direction: ClockDirection,
+) -> Result<(), AppError> {
+    let mut current_state = state.game_state.lock()?;
+    let game_state = current_state.as_mut().ok_or_else(|| AppError::conflict("No game state available"))?;
+    validate_clock_direction(&game_state.sport, direction)?;
+    game_state.clock_direction = Some(direction);
+
+    emit_game_state(&app, BackendEvent::GameStateUpdated, &*game_state)?;
+
+    record_command(RecordedCommand::SetClockDirection { direction });
     Ok(())
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_direction_follows_sport() {
+        assert!(validate_clock_direction("tennis", ClockDirection::Down).is_err());
+        assert!(validate_clock_direction("tennis", ClockDirection::None).is_ok());
+        assert!(validate_clock_direction("basketball", ClockDirection::Down).is_ok());
+        assert_eq!(default_clock_direction("Basketball"), ClockDirection::Down);
+        assert_eq!(default_clock_direction("soccer"), ClockDirection::Up);
+    }
+
+
+    fn team(id: &str) -> Team {
+        Team {
+            id: id.to_string(),
+            name: id.to_string(),
+            abbreviation: None,
+            logo_url: None,
+            primary_color: None,
+            secondary_color: None,
+        }
+    }
+
+    fn game_state() -> GameState {
+        GameState {
+            home_team: team("home"),
+            away_team: team("away"),
+            home_score: 0,
+            away_score: 0,
+            period: 1,
+            time_remaining: "10:00".to_string(),
+            is_game_active: false,
+            sport: "basketball".to_string(),
+            metadata: HashMap::new(),
+            clock_direction: None,
+        }
+    }
+
+    #[test]
+    fn team_logo_must_be_a_stored_image() {
+        let image = StoredImage {
+            id: "logo-1".to_string(),
+            name: "logo.png".to_string(),
+            original_name: "logo.png".to_string(),
+            path: "logo.png".to_string(),
+            size: 1,
+            r#type: "image/png".to_string(),
+            uploaded_at: chrono::Utc::now(),
+            thumbnail: None,
+            width: None,
+            height: None,
+            palette: None,
+        };
+        let mut state = game_state();
+
+        let updated = apply_team_logo(&mut state, "away", "logo-1", std::slice::from_ref(&image)).unwrap();
+        assert_eq!(updated.logo_url, Some(stored_image_ref("logo-1")));
+        assert_eq!(state.away_team.logo_url, Some(stored_image_ref("logo-1")));
+
+        let err = apply_team_logo(&mut state, "home", "missing", &[image]).unwrap_err();
+        assert!(matches!(err, AppError::NotFound { .. }));
+        assert_eq!(state.home_team.logo_url, None);
+    }
+
+
+    #[test]
+    fn clock_strings_are_normalized() {
+        assert_eq!(normalize_clock_string("5:3").unwrap(), "05:03");
+        assert_eq!(normalize_clock_string("90").unwrap(), "01:30");
+        assert_eq!(normalize_clock_string(" 12:00 ").unwrap(), "12:00");
+        assert_eq!(normalize_clock_string("99:59").unwrap(), "99:59");
+
+        for invalid in ["abc", "", "5:60", "1:2:3", "-5", "100:00", "5:"] {
+            assert!(normalize_clock_string(invalid).is_err(), "{:?} should be rejected", invalid);
+        }
+    }
+}
@@ -0,0 +1,342 @@
+// src-tauri/src/commands/serial_output.rs
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use lazy_static::lazy_static;
+use tauri::{AppHandle, Emitter, EventId, Listener, Manager};
+use tracing::{error, info, warn};
+
+use crate::commands::scoreboard::{GameState, ScoreboardState};
+use crate::commands::events::BackendEvent;
+use crate::error::AppError;
+
+const DEFAULT_REFRESH_MS: u64 = 100;
+const WRITE_TIMEOUT_MS: u64 = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerialPortDescriptor {
+    pub name: String,
+    pub port_type: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SerialProtocol {
+    // Format string with {placeholders} filled from GameState, e.g. "H{home_score:3}A{away_score:3}T{clock}P{period}"
+    Template {
+        template: String,
+        line_ending: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SerialOutputStatus {
+    pub connected: bool,
+    pub port: Option<String>,
+    pub baud_rate: u32,
+    pub refresh_ms: u64,
+    pub frames_sent: u64,
+    pub last_frame: Option<String>,
+    pub last_sent: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerialOutputErrorEvent {
+    pub port: String,
+    pub error: String,
+}
+
+enum SerialCommand {
+    Refresh,
+    Shutdown,
+}
+
+struct SerialOutputHandle {
+    sender: Sender<SerialCommand>,
+    thread: std::thread::JoinHandle<()>,
+    listener_ids: Vec<EventId>,
+}
+
+lazy_static! {
+    static ref SERIAL_OUTPUT: Mutex<Option<SerialOutputHandle>> = Mutex::new(None);
+    static ref SERIAL_STATUS: Arc<Mutex<SerialOutputStatus>> = Arc::new(Mutex::new(SerialOutputStatus::default()));
+}
+
+fn placeholder_value(name: &str, game_state: &GameState) -> Option<String> {
+    let value = match name {
+        "home_score" => game_state.home_score.to_string(),
+        "away_score" => game_state.away_score.to_string(),
+        "home" | "home_name" => game_state.home_team.abbreviation.clone()
+            .unwrap_or_else(|| game_state.home_team.name.clone()),
+        "away" | "away_name" => game_state.away_team.abbreviation.clone()
+            .unwrap_or_else(|| game_state.away_team.name.clone()),
+        "clock" => game_state.time_remaining.clone(),
+        "clock_digits" => game_state.time_remaining.chars().filter(|c| c.is_ascii_digit()).collect(),
+        "period" => game_state.period.to_string(),
+        "active" => if game_state.is_game_active { "1".to_string() } else { "0".to_string() },
+        _ => return None,
+    };
+    Some(value)
+}
+
+// Fill {name} and {name:width} placeholders; numbers are right-aligned, text left-aligned.
+// Unknown placeholders are left as-is so template typos are visible on the board.
+pub fn render_template(template: &str, game_state: &GameState) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        let Some(end) = after.find('}') else {
+            output.push_str(&rest[start..]);
+            return output;
+        };
+
+        let token = &after[..end];
+        let (name, width) = match token.split_once(':') {
+            Some((name, width)) => (name, width.parse::<usize>().ok()),
+            None => (token, None),
+        };
+
+        match placeholder_value(name, game_state) {
+            Some(value) => match width {
+                Some(width) if value.chars().all(|c| c.is_ascii_digit()) => {
+                    output.push_str(&format!("{:>width$}", value, width = width))
+                }
+                Some(width) => {
+                    let truncated: String = value.chars().take(width).collect();
+                    output.push_str(&format!("{:<width$}", truncated, width = width))
+                }
+                None => output.push_str(&value),
+            },
+            None => {
+                output.push('{');
+                output.push_str(token);
+                output.push('}');
+            }
+        }
+
+        rest = &after[end + 1..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+fn render_frame(protocol: &SerialProtocol, game_state: &GameState) -> String {
+    match protocol {
+        SerialProtocol::Template { template, line_ending } => {
+            let mut frame = render_template(template, game_state);
+            frame.push_str(line_ending.as_deref().unwrap_or("\r\n"));
+            frame
+        }
+    }
+}
+
+fn report_error(app: &AppHandle, port_name: &str, error: String) {
+    error!("Serial output error on {}: {}", port_name, error);
+    if let Ok(mut status) = SERIAL_STATUS.lock() {
+        status.connected = false;
+        status.last_error = Some(error.clone());
+    }
+    let event = SerialOutputErrorEvent {
+        port: port_name.to_string(),
+        error,
+    };
+    if let Err(e) = app.emit(BackendEvent::SerialOutputError.as_str(), &event) {
+        error!("Failed to emit {}: {}", BackendEvent::SerialOutputError, e);
+    }
+}
+
+fn run_writer(
+    app: AppHandle,
+    mut port: Box<dyn serialport::SerialPort>,
+    port_name: String,
+    protocol: SerialProtocol,
+    refresh: Duration,
+    receiver: Receiver<SerialCommand>,
+) {
+    let mut last_write: Option<Instant> = None;
+    let mut pending = true; // Push the current state as soon as we connect
+
+    loop {
+        // Wait for a change, or until the throttle window allows a pending write
+        let wait = match (pending, last_write) {
+            (true, Some(last)) => refresh.saturating_sub(last.elapsed()),
+            (true, None) => Duration::ZERO,
+            (false, _) => Duration::from_secs(1),
+        };
+
+        match receiver.recv_timeout(wait) {
+            Ok(SerialCommand::Refresh) => {
+                pending = true;
+                continue;
+            }
+            Ok(SerialCommand::Shutdown) | Err(RecvTimeoutError::Disconnected) => break,
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+
+        if !pending {
+            continue;
+        }
+        pending = false;
+
+        let game_state = match app.state::<ScoreboardState>().game_state.lock() {
+            Ok(game_state) => game_state.clone(),
+            Err(e) => {
+                report_error(&app, &port_name, format!("Failed to read game state: {}", e));
+                continue;
+            }
+        };
+
+        let Some(game_state) = game_state else {
+            continue;
+        };
+
+        let frame = render_frame(&protocol, &game_state);
+        if let Err(e) = port.write_all(frame.as_bytes()).and_then(|_| port.flush()) {
+            report_error(&app, &port_name, format!("Failed to write to serial port: {}", e));
+            break;
+        }
+
+        last_write = Some(Instant::now());
+        if let Ok(mut status) = SERIAL_STATUS.lock() {
+            status.frames_sent += 1;
+            status.last_frame = Some(frame);
+            status.last_sent = Some(chrono::Utc::now());
+        }
+    }
+
+    info!("🛑 Serial output writer stopped for {}", port_name);
+}
+
+// Stop the writer thread and detach event listeners; safe to call when nothing is connected
+pub fn shutdown_serial_output(app: &AppHandle) {
+    let handle = match SERIAL_OUTPUT.lock() {
+        Ok(mut output) => output.take(),
+        Err(e) => {
+            error!("Failed to lock serial output: {}", e);
+            return;
+        }
+    };
+
+    if let Some(handle) = handle {
+        for id in handle.listener_ids {
+            app.unlisten(id);
+        }
+        let _ = handle.sender.send(SerialCommand::Shutdown);
+        if handle.thread.join().is_err() {
+            warn!("Serial output writer thread panicked");
+        }
+    }
+
+    if let Ok(mut status) = SERIAL_STATUS.lock() {
+        status.connected = false;
+    }
+}
+
+#[tauri::command]
+pub async fn list_serial_ports() -> Result<Vec<SerialPortDescriptor>, AppError> {
+    let ports = serialport::available_ports()
+        .map_err(|e| AppError::io(format!("Failed to list serial ports: {}", e)))?;
+
+    Ok(ports.into_iter().map(|port| {
+        let (port_type, description) = match port.port_type {
+            serialport::SerialPortType::UsbPort(info) => (
+                "usb".to_string(),
+                info.product.or(info.manufacturer),
+            ),
+            serialport::SerialPortType::PciPort => ("pci".to_string(), None),
+            serialport::SerialPortType::BluetoothPort => ("bluetooth".to_string(), None),
+            serialport::SerialPortType::Unknown => ("unknown".to_string(), None),
+        };
+        SerialPortDescriptor {
+            name: port.port_name,
+            port_type,
+            description,
+        }
+    }).collect())
+}
+
+#[tauri::command]
+pub async fn connect_serial_scoreboard(
+    app: AppHandle,
+    port: String,
+    baud: u32,
+    protocol: SerialProtocol,
+    refresh_ms: Option<u64>,
+) -> Result<String, AppError> {
+    // A writer that stopped after a write error leaves its handle behind; clear it out
+    let writer_finished = SERIAL_OUTPUT.lock()?
+        .as_ref()
+        .map(|handle| handle.thread.is_finished());
+    match writer_finished {
+        Some(false) => return Err(AppError::conflict("A serial scoreboard is already connected")),
+        Some(true) => shutdown_serial_output(&app),
+        None => {}
+    }
+
+    let refresh_ms = refresh_ms.unwrap_or(DEFAULT_REFRESH_MS).max(1);
+
+    let serial_port = serialport::new(&port, baud)
+        .timeout(Duration::from_millis(WRITE_TIMEOUT_MS))
+        .open()
+        .map_err(|e| AppError::io(format!("Failed to open serial port {}: {}", port, e)))?;
+
+    let (sender, receiver) = mpsc::channel();
+
+    let listener_ids = BackendEvent::GAME_STATE_CHANGES.iter().map(|event| {
+        let sender = sender.clone();
+        app.listen_any(event.as_str(), move |_| {
+            let _ = sender.send(SerialCommand::Refresh);
+        })
+    }).collect();
+
+    {
+        let mut status = SERIAL_STATUS.lock()?;
+        *status = SerialOutputStatus {
+            connected: true,
+            port: Some(port.clone()),
+            baud_rate: baud,
+            refresh_ms,
+            ..SerialOutputStatus::default()
+        };
+    }
+
+    let writer_app = app.clone();
+    let writer_port = port.clone();
+    let thread = std::thread::spawn(move || {
+        run_writer(writer_app, serial_port, writer_port, protocol, Duration::from_millis(refresh_ms), receiver);
+    });
+
+    *SERIAL_OUTPUT.lock()? = Some(SerialOutputHandle {
+        sender,
+        thread,
+        listener_ids,
+    });
+
+    info!("🔌 Connected serial scoreboard on {} at {} baud", port, baud);
+    Ok(format!("Connected serial scoreboard on {}", port))
+}
+
+#[tauri::command]
+pub async fn disconnect_serial_scoreboard(app: AppHandle) -> Result<String, AppError> {
+    if SERIAL_OUTPUT.lock()?.is_none() {
+        return Err(AppError::not_found("Serial scoreboard connection"));
+    }
+
+    shutdown_serial_output(&app);
+    Ok("Serial scoreboard disconnected".to_string())
+}
+
+#[tauri::command]
+pub async fn get_serial_output_status() -> Result<SerialOutputStatus, AppError> {
+    let status = SERIAL_STATUS.lock()?;
+    Ok(status.clone())
+}
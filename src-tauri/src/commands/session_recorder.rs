@@ -0,0 +1,226 @@
+// src-tauri/src/commands/session_recorder.rs
+//
+// Opt-in recording of scoreboard mutations to a JSON-lines file, and replay of
+// a recording against the current state. Used for operator tutorials and for
+// reproducing bugs from a user's session.
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use lazy_static::lazy_static;
+use tauri::{AppHandle, Manager};
+use tracing::{error, info};
+
+use crate::commands::scoreboard::{
+    reset_game, set_clock_direction, toggle_game_active, update_game_state, update_period,
+    update_score, update_team_info, update_time, ClockDirection, GameState, ScoreboardState, Team,
+};
+use crate::commands::storage::sanitize_filename;
+use crate::commands::data_dir::data_dir;
+use crate::error::AppError;
+
+const SESSIONS_DIR: &str = "sessions";
+// Gaps longer than this are shortened on replay so tutorials don't stall
+const MAX_REPLAY_GAP_MS: u64 = 10_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "command", content = "args", rename_all = "snake_case")]
+pub enum RecordedCommand {
+    UpdateGameState { game_state: Box<GameState> },
+    UpdateScore { team: String, score: u32 },
+    UpdateTime { time_remaining: String },
+    UpdatePeriod { period: u32 },
+    ToggleGameActive,
+    ResetGame,
+    UpdateTeamInfo { team_side: String, team: Team },
+    SetClockDirection { direction: ClockDirection },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedEntry {
+    // Milliseconds since the recording started
+    pub offset_ms: u64,
+    pub recorded_at: chrono::DateTime<chrono::Utc>,
+    #[serde(flatten)]
+    pub command: RecordedCommand,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecordingStatus {
+    pub recording: bool,
+    pub path: Option<String>,
+    pub entries: usize,
+    pub replaying: bool,
+}
+
+struct ActiveRecording {
+    path: PathBuf,
+    file: std::fs::File,
+    started: Instant,
+    entries: usize,
+}
+
+lazy_static! {
+    static ref ACTIVE_RECORDING: Mutex<Option<ActiveRecording>> = Mutex::new(None);
+    // Commands issued by a replay aren't recorded again
+    static ref REPLAYING: Mutex<bool> = Mutex::new(false);
+}
+
+// Called by the scoreboard commands after a successful mutation; a no-op unless recording
+pub fn record_command(command: RecordedCommand) {
+    if REPLAYING.lock().map(|replaying| *replaying).unwrap_or(false) {
+        return;
+    }
+    let Ok(mut active) = ACTIVE_RECORDING.lock() else {
+        return;
+    };
+    let Some(recording) = active.as_mut() else {
+        return;
+    };
+
+    let entry = RecordedEntry {
+        offset_ms: recording.started.elapsed().as_millis() as u64,
+        recorded_at: chrono::Utc::now(),
+        command,
+    };
+    let result = serde_json::to_string(&entry)
+        .map_err(AppError::from)
+        .and_then(|line| Ok(writeln!(recording.file, "{}", line)?));
+
+    match result {
+        Ok(()) => recording.entries += 1,
+        Err(e) => error!("Failed to record session command: {}", e),
+    }
+}
+
+fn sessions_dir(app: &AppHandle) -> Result<PathBuf, AppError> {
+    let app_data_dir = data_dir(app)?;
+    Ok(app_data_dir.join(SESSIONS_DIR))
+}
+
+// Absolute paths are used as-is so a session sent in a bug report can be replayed in place
+fn session_path(app: &AppHandle, file: &str) -> Result<PathBuf, AppError> {
+    let path = PathBuf::from(file);
+    if path.is_absolute() {
+        return Ok(path);
+    }
+    let name = sanitize_filename(file.trim_end_matches(".jsonl"));
+    Ok(sessions_dir(app)?.join(format!("{}.jsonl", name)))
+}
+
+pub fn parse_session(content: &str) -> Result<Vec<RecordedEntry>, AppError> {
+    content.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| serde_json::from_str(line)
+            .map_err(|e| AppError::validation("file", format!("Invalid entry on line {}: {}", index + 1, e))))
+        .collect()
+}
+
+// Delay before each entry at the given speed, with long pauses capped
+pub fn replay_delays(entries: &[RecordedEntry], speed: f64) -> Vec<Duration> {
+    let mut previous = 0;
+    entries.iter().map(|entry| {
+        let gap = entry.offset_ms.saturating_sub(previous).min(MAX_REPLAY_GAP_MS);
+        previous = entry.offset_ms;
+        Duration::from_millis((gap as f64 / speed) as u64)
+    }).collect()
+}
+
+async fn replay_entry(app: &AppHandle, command: RecordedCommand) -> Result<(), AppError> {
+    let state = app.state::<ScoreboardState>();
+    let result = match command {
+        RecordedCommand::UpdateGameState { game_state } => update_game_state(state, app.clone(), *game_state).await,
+        RecordedCommand::UpdateScore { team, score } => update_score(state, app.clone(), team, score).await,
+        RecordedCommand::UpdateTime { time_remaining } => update_time(state, app.clone(), time_remaining).await,
+        RecordedCommand::UpdatePeriod { period } => update_period(state, app.clone(), period).await,
+        RecordedCommand::ToggleGameActive => toggle_game_active(state, app.clone()).await.map(|_| ()),
+        RecordedCommand::ResetGame => reset_game(state, app.clone()).await,
+        RecordedCommand::UpdateTeamInfo { team_side, team } => update_team_info(state, app.clone(), team_side, team).await,
+        RecordedCommand::SetClockDirection { direction } => set_clock_direction(state, app.clone(), direction).await,
+    };
+    result
+}
+
+#[tauri::command]
+pub async fn start_session_recording(app: AppHandle, name: String) -> Result<String, AppError> {
+    let mut active = ACTIVE_RECORDING.lock()?;
+    if active.is_some() {
+        return Err(AppError::conflict("A session is already being recorded"));
+    }
+
+    let dir = sessions_dir(&app)?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| AppError::io(format!("Failed to create sessions directory: {}", e)))?;
+    let path = session_path(&app, &name)?;
+    let file = std::fs::File::create(&path)
+        .map_err(|e| AppError::io(format!("Failed to create session file: {}", e)))?;
+
+    info!("⏺️ Recording session to {:?}", path);
+    let path_text = path.to_string_lossy().to_string();
+    *active = Some(ActiveRecording { path, file, started: Instant::now(), entries: 0 });
+    Ok(path_text)
+}
+
+#[tauri::command]
+pub async fn stop_session_recording() -> Result<SessionRecordingStatus, AppError> {
+    let recording = ACTIVE_RECORDING.lock()?.take()
+        .ok_or_else(|| AppError::conflict("No session is being recorded"))?;
+
+    info!("⏹️ Recorded {} command(s) to {:?}", recording.entries, recording.path);
+    Ok(SessionRecordingStatus {
+        recording: false,
+        path: Some(recording.path.to_string_lossy().to_string()),
+        entries: recording.entries,
+        replaying: *REPLAYING.lock()?,
+    })
+}
+
+#[tauri::command]
+pub async fn get_session_recording_status() -> Result<SessionRecordingStatus, AppError> {
+    let active = ACTIVE_RECORDING.lock()?;
+    Ok(SessionRecordingStatus {
+        recording: active.is_some(),
+        path: active.as_ref().map(|r| r.path.to_string_lossy().to_string()),
+        entries: active.as_ref().map(|r| r.entries).unwrap_or(0),
+        replaying: *REPLAYING.lock()?,
+    })
+}
+
+#[tauri::command]
+pub async fn replay_session(app: AppHandle, file: String, speed: Option<f64>) -> Result<usize, AppError> {
+    let speed = speed.unwrap_or(1.0);
+    if !speed.is_finite() || speed <= 0.0 {
+        return Err(AppError::validation("speed", "Replay speed must be greater than zero"));
+    }
+
+    let path = session_path(&app, &file)?;
+    let content = tokio::fs::read_to_string(&path).await
+        .map_err(|e| AppError::io(format!("Failed to read session file: {}", e)))?;
+    let entries = parse_session(&content)?;
+
+    {
+        let mut replaying = REPLAYING.lock()?;
+        if *replaying {
+            return Err(AppError::conflict("A session is already being replayed"));
+        }
+        *replaying = true;
+    }
+
+    info!("▶️ Replaying {} command(s) from {:?} at {}x", entries.len(), path, speed);
+    let delays = replay_delays(&entries, speed);
+    let mut result = Ok(entries.len());
+    for (index, (entry, delay)) in entries.into_iter().zip(delays).enumerate() {
+        tokio::time::sleep(delay).await;
+        if let Err(e) = replay_entry(&app, entry.command).await {
+            result = Err(AppError::validation("file", format!("Replay stopped at entry {}: {}", index + 1, e)));
+            break;
+        }
+    }
+
+    if let Ok(mut replaying) = REPLAYING.lock() {
+        *replaying = false;
+    }
+    result
+}
@@ -0,0 +1,164 @@
+// src-tauri/src/commands/sport_detection.rs
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tauri::command;
+use tracing::debug;
+
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SportDetection {
+    pub sport: String,
+    pub confidence: f64,
+    pub matched_fields: Vec<String>,
+}
+
+// Field names (normalized: lowercase, no underscores) that hint at each sport,
+// with a weight for how strongly they point at it
+const TENNIS_FIELDS: &[(&str, f64)] = &[
+    ("sets", 2.0),
+    ("games", 1.5),
+    ("points", 1.0),
+    ("servingplayer", 2.0),
+    ("server", 1.0),
+    ("istiebreak", 2.0),
+    ("tiebreakscore", 2.0),
+    ("player1sets", 2.0),
+    ("player1games", 2.0),
+    ("player1points", 2.0),
+    ("currentset", 2.0),
+    // IonCourt match shape
+    ("side1pointscore", 2.0),
+    ("side1tiebreakscore", 2.0),
+    ("scorestringside1", 1.5),
+];
+
+const BASKETBALL_FIELDS: &[(&str, f64)] = &[
+    ("quarter", 1.5),
+    ("period", 1.0),
+    ("fouls", 2.0),
+    ("teamfouls", 2.0),
+    ("shotclock", 2.5),
+    ("bonus", 1.5),
+    ("timeouts", 0.5),
+    ("rebounds", 2.0),
+];
+
+const SOCCER_FIELDS: &[(&str, f64)] = &[
+    ("half", 1.5),
+    ("corners", 2.0),
+    ("yellowcards", 2.5),
+    ("redcards", 2.5),
+    ("stoppagetime", 2.5),
+    ("addedtime", 2.0),
+    ("penalties", 1.0),
+    ("goals", 1.5),
+];
+
+const FOOTBALL_FIELDS: &[(&str, f64)] = &[
+    ("down", 2.5),
+    ("yardstogo", 2.5),
+    ("distance", 1.0),
+    ("possession", 1.0),
+    ("ballon", 2.0),
+    ("quarter", 1.0),
+    ("timeouts", 0.5),
+    ("touchdowns", 2.5),
+];
+
+// How deep to look into nested objects (IonCourt wraps match data in a "data" envelope)
+const MAX_DEPTH: usize = 3;
+
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+fn collect_keys(value: &serde_json::Value, depth: usize, keys: &mut HashSet<String>) {
+    if depth > MAX_DEPTH {
+        return;
+    }
+
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map {
+                keys.insert(normalize_key(key));
+                collect_keys(child, depth + 1, keys);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            // Arrays of matches share a shape, so the first few entries are enough
+            for item in items.iter().take(3) {
+                collect_keys(item, depth + 1, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn score_sport(keys: &HashSet<String>, fields: &[(&str, f64)]) -> (f64, Vec<String>) {
+    let mut score = 0.0;
+    let mut matched = Vec::new();
+
+    for (field, weight) in fields {
+        if keys.contains(*field) {
+            score += weight;
+            matched.push(field.to_string());
+        }
+    }
+
+    (score, matched)
+}
+
+pub fn detect_sport_from_value(data: &serde_json::Value) -> SportDetection {
+    let mut keys = HashSet::new();
+    collect_keys(data, 0, &mut keys);
+
+    let mut scores: Vec<(&str, f64, Vec<String>)> = [
+        ("tennis", TENNIS_FIELDS),
+        ("basketball", BASKETBALL_FIELDS),
+        ("soccer", SOCCER_FIELDS),
+        ("football", FOOTBALL_FIELDS),
+    ]
+    .iter()
+    .map(|(sport, fields)| {
+        let (score, matched) = score_sport(&keys, fields);
+        (*sport, score, matched)
+    })
+    .collect();
+
+    scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    let total: f64 = scores.iter().map(|(_, score, _)| score).sum();
+    let (best_sport, best_score, matched_fields) = scores.swap_remove(0);
+
+    if best_score <= 0.0 {
+        return SportDetection {
+            sport: "generic".to_string(),
+            confidence: 0.0,
+            matched_fields: Vec::new(),
+        };
+    }
+
+    // Share of the evidence pointing at the winner, damped when only a
+    // couple of weak fields matched
+    let share = best_score / total;
+    let strength = (best_score / 5.0).min(1.0);
+    let confidence = (share * strength * 100.0).round() / 100.0;
+
+    SportDetection {
+        sport: best_sport.to_string(),
+        confidence,
+        matched_fields,
+    }
+}
+
+#[command]
+pub async fn detect_sport(data: serde_json::Value) -> Result<SportDetection, AppError> {
+    let detection = detect_sport_from_value(&data);
+    debug!("🔎 Detected sport '{}' (confidence {:.2}) from fields {:?}",
+        detection.sport, detection.confidence, detection.matched_fields);
+    Ok(detection)
+}
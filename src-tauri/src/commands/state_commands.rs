@@ -2,6 +2,9 @@
 use crate::state::*;
 use crate::state_sync::*;
 use tauri::{command, State};
+use crate::commands::providers::check_poll_interval;
+use crate::commands::event_lock::ensure_unlocked;
+use crate::commands::scoreboard::normalize_clock_string;
 
 // ==================== APP STATE COMMANDS ====================
 
@@ -25,7 +28,7 @@ pub async fn update_app_theme(
     // Notify subscribers of the state change
     let sync_manager = state_sync.0.lock()
         .map_err(|e| format!("Failed to lock state sync: {}", e))?;
-    sync_manager.notify_app_state_change(&*app_state)?;
+    sync_manager.notify_app_state_change(&app_state)?;
 
     Ok(())
 }
@@ -42,7 +45,7 @@ pub async fn toggle_sidebar(
     // Notify subscribers of the state change
     let sync_manager = state_sync.0.lock()
         .map_err(|e| format!("Failed to lock state sync: {}", e))?;
-    sync_manager.notify_app_state_change(&*app_state)?;
+    sync_manager.notify_app_state_change(&app_state)?;
 
     Ok(())
 }
@@ -99,7 +102,7 @@ pub async fn set_monitors(
     // Notify subscribers of the state change
     let sync_manager = state_sync.0.lock()
         .map_err(|e| format!("Failed to lock state sync: {}", e))?;
-    sync_manager.notify_app_state_change(&*app_state)?;
+    sync_manager.notify_app_state_change(&app_state)?;
 
     Ok(())
 }
@@ -126,7 +129,7 @@ pub async fn select_monitor(
     // Notify subscribers of the state change
     let sync_manager = state_sync.0.lock()
         .map_err(|e| format!("Failed to lock state sync: {}", e))?;
-    sync_manager.notify_app_state_change(&*app_state)?;
+    sync_manager.notify_app_state_change(&app_state)?;
 
     Ok(())
 }
@@ -136,6 +139,7 @@ pub async fn add_scoreboard_instance(
     instance: ScoreboardInstance,
     state: State<'_, ManagedAppState>
 ) -> Result<(), String> {
+    ensure_unlocked("Adding scoreboard windows")?;
     let mut app_state = state.0.lock()
         .map_err(|e| format!("Failed to lock app state: {}", e))?;
     app_state.scoreboard_instances.push(instance);
@@ -147,6 +151,7 @@ pub async fn remove_scoreboard_instance(
     instance_id: String,
     state: State<'_, ManagedAppState>
 ) -> Result<(), String> {
+    ensure_unlocked("Removing scoreboard windows")?;
     let mut app_state = state.0.lock()
         .map_err(|e| format!("Failed to lock app state: {}", e))?;
     app_state.scoreboard_instances.retain(|i| i.id != instance_id);
@@ -160,6 +165,7 @@ pub async fn update_scoreboard_instance_position(
     offset_y: i32,
     state: State<'_, ManagedAppState>
 ) -> Result<(), String> {
+    ensure_unlocked("Moving scoreboard windows")?;
     let mut app_state = state.0.lock()
         .map_err(|e| format!("Failed to lock app state: {}", e))?;
 
@@ -177,6 +183,7 @@ pub async fn update_scoreboard_instance_size(
     height: u32,
     state: State<'_, ManagedAppState>
 ) -> Result<(), String> {
+    ensure_unlocked("Resizing scoreboard windows")?;
     let mut app_state = state.0.lock()
         .map_err(|e| format!("Failed to lock app state: {}", e))?;
 
@@ -218,6 +225,17 @@ pub async fn get_canvas_state(state: State<'_, ManagedCanvasState>) -> Result<Ca
     Ok(canvas_state.clone())
 }
 
+#[command]
+pub async fn get_canvas_view(state: State<'_, ManagedCanvasState>) -> Result<CanvasView, String> {
+    let canvas_state = state.0.lock()
+        .map_err(|e| format!("Failed to lock canvas state: {}", e))?;
+    Ok(CanvasView {
+        zoom: canvas_state.zoom,
+        pan: canvas_state.pan.clone(),
+        grid: canvas_state.grid.clone(),
+    })
+}
+
 #[command]
 pub async fn set_canvas_size(
     width: u32,
@@ -237,7 +255,7 @@ pub async fn set_canvas_zoom(
 ) -> Result<(), String> {
     let mut canvas_state = state.0.lock()
         .map_err(|e| format!("Failed to lock canvas state: {}", e))?;
-    canvas_state.zoom = zoom.max(0.1).min(5.0);
+    canvas_state.zoom = zoom.clamp(0.1, 5.0);
     Ok(())
 }
 
@@ -291,6 +309,35 @@ pub async fn toggle_alignment_snapping(state: State<'_, ManagedCanvasState>) ->
     Ok(())
 }
 
+#[command]
+pub async fn configure_canvas_grid(
+    show: bool,
+    size: u32,
+    snap: bool,
+    alignment_snap: bool,
+    state: State<'_, ManagedCanvasState>
+) -> Result<GridConfig, String> {
+    if size == 0 {
+        return Err("Grid size must be greater than zero".to_string());
+    }
+
+    // Single lock so no caller sees a half-applied preset
+    let mut canvas_state = state.0.lock()
+        .map_err(|e| format!("Failed to lock canvas state: {}", e))?;
+    canvas_state.grid.show_grid = show;
+    canvas_state.grid.size = size;
+    canvas_state.grid.snap_to_grid = snap;
+    canvas_state.alignment_snapping = alignment_snap;
+    if !alignment_snap {
+        canvas_state.alignment_guides.clear();
+    }
+
+    Ok(GridConfig {
+        grid: canvas_state.grid.clone(),
+        alignment_snapping: canvas_state.alignment_snapping,
+    })
+}
+
 #[command]
 pub async fn select_canvas_components(
     component_ids: Vec<String>,
@@ -407,7 +454,7 @@ pub async fn zoom_canvas_to_fit(
 
     let scale_x = viewport_width / canvas_width;
     let scale_y = viewport_height / canvas_height;
-    let scale = (scale_x.min(scale_y) * 0.9).max(0.1).min(5.0);
+    let scale = (scale_x.min(scale_y) * 0.9).clamp(0.1, 5.0);
 
     canvas_state.zoom = scale;
     canvas_state.pan = Position2D {
@@ -489,6 +536,7 @@ pub async fn add_image(
     image: StoredImage,
     state: State<'_, ManagedImageState>
 ) -> Result<(), String> {
+    ensure_unlocked("Adding images")?;
     let mut image_state = state.0.lock()
         .map_err(|e| format!("Failed to lock image state: {}", e))?;
     image_state.images.push(image);
@@ -501,6 +549,7 @@ pub async fn remove_image(
     image_id: String,
     state: State<'_, ManagedImageState>
 ) -> Result<(), String> {
+    ensure_unlocked("Removing images")?;
     let mut image_state = state.0.lock()
         .map_err(|e| format!("Failed to lock image state: {}", e))?;
     image_state.images.retain(|i| i.id != image_id);
@@ -543,6 +592,7 @@ pub async fn add_video(
     video: StoredVideo,
     state: State<'_, ManagedVideoState>
 ) -> Result<(), String> {
+    ensure_unlocked("Adding videos")?;
     let mut video_state = state.0.lock()
         .map_err(|e| format!("Failed to lock video state: {}", e))?;
     video_state.videos.push(video);
@@ -555,6 +605,7 @@ pub async fn remove_video(
     video_id: String,
     state: State<'_, ManagedVideoState>
 ) -> Result<(), String> {
+    ensure_unlocked("Removing videos")?;
     let mut video_state = state.0.lock()
         .map_err(|e| format!("Failed to lock video state: {}", e))?;
     video_state.videos.retain(|v| v.id != video_id);
@@ -581,11 +632,26 @@ pub async fn get_live_data_state(state: State<'_, ManagedLiveDataState>) -> Resu
     Ok(live_data_state.clone())
 }
 
+#[command]
+pub async fn get_live_data_connection(
+    connection_id: String,
+    state: State<'_, ManagedLiveDataState>
+) -> Result<LiveDataConnection, String> {
+    let live_data_state = state.0.lock()
+        .map_err(|e| format!("Failed to lock live data state: {}", e))?;
+    live_data_state.connections.iter()
+        .find(|connection| connection.id == connection_id)
+        .cloned()
+        .ok_or_else(|| format!("Live data connection {} not found", connection_id))
+}
+
 #[command]
 pub async fn add_live_data_connection(
     connection: LiveDataConnection,
     state: State<'_, ManagedLiveDataState>
 ) -> Result<(), String> {
+    // Saturate rather than truncate, so a large interval isn't wrapped into a small one
+    check_poll_interval(&connection.provider, u32::try_from(connection.poll_interval).unwrap_or(u32::MAX))?;
     let mut live_data_state = state.0.lock()
         .map_err(|e| format!("Failed to lock live data state: {}", e))?;
     live_data_state.connections.push(connection);
@@ -598,6 +664,7 @@ pub async fn update_live_data_connection(
     updates: LiveDataConnection,
     state: State<'_, ManagedLiveDataState>
 ) -> Result<(), String> {
+    check_poll_interval(&updates.provider, u32::try_from(updates.poll_interval).unwrap_or(u32::MAX))?;
     let mut live_data_state = state.0.lock()
         .map_err(|e| format!("Failed to lock live data state: {}", e))?;
 
@@ -705,11 +772,42 @@ pub async fn get_scoreboard_state(state: State<'_, ManagedScoreboardState>) -> R
     Ok(scoreboard_state.clone())
 }
 
+#[command]
+pub async fn get_scoreboard_component(
+    component_id: String,
+    state: State<'_, ManagedScoreboardState>
+) -> Result<ScoreboardComponent, String> {
+    let scoreboard_state = state.0.lock()
+        .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
+    scoreboard_state.components.iter()
+        .find(|component| component.id == component_id)
+        .cloned()
+        .ok_or_else(|| format!("Component {} not found", component_id))
+}
+
+#[command]
+pub async fn get_scoreboard_components_meta(
+    state: State<'_, ManagedScoreboardState>
+) -> Result<Vec<ScoreboardComponentMeta>, String> {
+    let scoreboard_state = state.0.lock()
+        .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
+    Ok(scoreboard_state.components.iter()
+        .map(|component| ScoreboardComponentMeta {
+            id: component.id.clone(),
+            component_type: component.component_type.clone(),
+            z_index: component.z_index,
+            visible: component.visible,
+            locked: component.locked,
+        })
+        .collect())
+}
+
 #[command]
 pub async fn set_scoreboard_config(
     config: ScoreboardConfig,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Changing the scoreboard configuration")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
     scoreboard_state.config = Some(config);
@@ -721,6 +819,7 @@ pub async fn add_scoreboard_component(
     component: ScoreboardComponent,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Adding components")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
     scoreboard_state.components.push(component);
@@ -733,6 +832,7 @@ pub async fn remove_scoreboard_component(
     component_id: String,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Removing components")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
     scoreboard_state.components.retain(|c| c.id != component_id);
@@ -746,6 +846,7 @@ pub async fn update_scoreboard_component(
     updates: ScoreboardComponent,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Editing components")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
 
@@ -763,6 +864,7 @@ pub async fn update_scoreboard_component_position(
     y: f64,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Moving components")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
 
@@ -780,6 +882,7 @@ pub async fn update_scoreboard_component_size(
     height: u32,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Resizing components")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
 
@@ -796,6 +899,7 @@ pub async fn update_scoreboard_component_style(
     style: ComponentStyle,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Restyling components")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
 
@@ -812,6 +916,7 @@ pub async fn update_scoreboard_component_data(
     data: ComponentData,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Editing component content")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
 
@@ -827,6 +932,7 @@ pub async fn bring_scoreboard_component_to_front(
     component_id: String,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Reordering components")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
 
@@ -845,6 +951,7 @@ pub async fn send_scoreboard_component_to_back(
     component_id: String,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Reordering components")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
 
@@ -864,6 +971,7 @@ pub async fn lock_scoreboard_component(
     locked: bool,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    ensure_unlocked("Locking or unlocking components")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
 
@@ -889,6 +997,143 @@ pub async fn toggle_scoreboard_component_visibility(
     Ok(())
 }
 
+#[command]
+pub async fn set_scoreboard_component_tags(
+    component_id: String,
+    tags: Vec<String>,
+    state: State<'_, ManagedScoreboardState>
+) -> Result<(), String> {
+    ensure_unlocked("Tagging components")?;
+    let mut scoreboard_state = state.0.lock()
+        .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
+
+    let mut tags: Vec<String> = tags.iter()
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    tags.sort();
+    tags.dedup();
+
+    if let Some(component) = scoreboard_state.components.iter_mut().find(|c| c.id == component_id) {
+        component.tags = tags;
+        scoreboard_state.is_dirty = true;
+    }
+    Ok(())
+}
+
+// Show or hide every component of a type or with a tag in one change, e.g. all
+// sponsor logos during play. Returns the ids whose visibility changed.
+#[command]
+pub async fn set_components_visibility_by_filter(
+    filter: ComponentFilter,
+    visible: bool,
+    state: State<'_, ManagedScoreboardState>,
+    state_sync: State<'_, ManagedStateSync>
+) -> Result<Vec<String>, String> {
+    let mut scoreboard_state = state.0.lock()
+        .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
+
+    let mut changed = Vec::new();
+    for component in scoreboard_state.components.iter_mut() {
+        if component.matches(&filter) && component.visible != visible {
+            component.visible = visible;
+            changed.push(component.id.clone());
+        }
+    }
+    if changed.is_empty() {
+        return Ok(changed);
+    }
+    scoreboard_state.is_dirty = true;
+
+    // One update for the whole group, so displays don't flicker through each change
+    let sync_manager = state_sync.0.lock()
+        .map_err(|e| format!("Failed to lock state sync: {}", e))?;
+    sync_manager.notify_scoreboard_state_change(&scoreboard_state)?;
+
+    Ok(changed)
+}
+
+// Applies every update it can and marks the board dirty if any landed;
+// unknown ids get a failed result instead of stopping the batch
+fn apply_component_updates(scoreboard_state: &mut ScoreboardState, updates: Vec<(String, ComponentUpdate)>) -> Vec<ComponentUpdateResult> {
+    let mut results = Vec::with_capacity(updates.len());
+    for (component_id, update) in updates {
+        let result = match scoreboard_state.components.iter_mut().find(|c| c.id == component_id) {
+            Some(component) => {
+                component.apply_update(update);
+                ComponentUpdateResult { component_id, applied: true, error: None }
+            }
+            None => {
+                let error = Some(format!("Component '{}' not found", component_id));
+                ComponentUpdateResult { component_id, applied: false, error }
+            }
+        };
+        results.push(result);
+    }
+    if results.iter().any(|result| result.applied) {
+        scoreboard_state.is_dirty = true;
+    }
+    results
+}
+
+// Applies a batch of changes (a theme, a nudged selection) under one lock with
+// one notification. Unknown ids are reported per update and don't stop the rest.
+#[command]
+pub async fn update_components(
+    updates: Vec<(String, ComponentUpdate)>,
+    state: State<'_, ManagedScoreboardState>,
+    state_sync: State<'_, ManagedStateSync>
+) -> Result<Vec<ComponentUpdateResult>, String> {
+    ensure_unlocked("Editing components")?;
+    let mut scoreboard_state = state.0.lock()
+        .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
+
+    let results = apply_component_updates(&mut scoreboard_state, updates);
+    if !results.iter().any(|result| result.applied) {
+        return Ok(results);
+    }
+
+    let sync_manager = state_sync.0.lock()
+        .map_err(|e| format!("Failed to lock state sync: {}", e))?;
+    sync_manager.notify_scoreboard_state_change(&scoreboard_state)?;
+
+    Ok(results)
+}
+
+#[command]
+pub async fn find_overlapping_components(
+    threshold: f64,
+    state: State<'_, ManagedScoreboardState>
+) -> Result<Vec<OverlapGroup>, String> {
+    if !(0.0..1.0).contains(&threshold) {
+        return Err("Threshold must be between 0 and 1".to_string());
+    }
+    let scoreboard_state = state.0.lock()
+        .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
+    Ok(find_overlap_groups(&scoreboard_state.components, threshold))
+}
+
+// Returns the overlaps that remain, e.g. between locked components
+#[command]
+pub async fn auto_distribute_overlaps(
+    threshold: f64,
+    state: State<'_, ManagedScoreboardState>
+) -> Result<Vec<OverlapGroup>, String> {
+    ensure_unlocked("Moving components")?;
+    if !(0.0..1.0).contains(&threshold) {
+        return Err("Threshold must be between 0 and 1".to_string());
+    }
+    let mut scoreboard_state = state.0.lock()
+        .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
+
+    let canvas = scoreboard_state.config.as_ref().map(|config| config.dimensions.clone());
+    let moved = distribute_overlaps(&mut scoreboard_state.components, threshold, canvas.as_ref());
+    if !moved.is_empty() {
+        scoreboard_state.is_dirty = true;
+    }
+    Ok(find_overlap_groups(&scoreboard_state.components, threshold))
+}
+
 #[command]
 pub async fn set_scoreboard_game_state(
     game_state: GameState,
@@ -924,6 +1169,7 @@ pub async fn update_scoreboard_time(
     time_remaining: String,
     state: State<'_, ManagedScoreboardState>
 ) -> Result<(), String> {
+    let time_remaining = normalize_clock_string(&time_remaining)?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
 
@@ -995,8 +1241,108 @@ pub async fn mark_scoreboard_saved(state: State<'_, ManagedScoreboardState>) ->
 
 #[command]
 pub async fn clear_scoreboard(state: State<'_, ManagedScoreboardState>) -> Result<(), String> {
+    ensure_unlocked("Clearing the scoreboard")?;
     let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
     *scoreboard_state = ScoreboardState::default();
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(id: &str, x: f64) -> ScoreboardComponent {
+        ScoreboardComponent {
+            id: id.to_string(),
+            component_type: ComponentType::Text,
+            position: Position2D { x, y: 0.0 },
+            size: Size { width: 100, height: 50 },
+            rotation: 0.0,
+            style: ComponentStyle {
+                background_color: None,
+                border_color: "#000000".to_string(),
+                border_width: 0,
+                border_radius: 0,
+                opacity: 1.0,
+                font_size: 16,
+                font_family: "Arial".to_string(),
+                font_weight: "normal".to_string(),
+                text_color: "#ffffff".to_string(),
+                text_align: "left".to_string(),
+                vertical_align: "top".to_string(),
+            },
+            data: ComponentData {
+                image_id: None,
+                image_url: None,
+                text: id.to_string(),
+                player_number: None,
+                set_number: None,
+                live_data_binding: None,
+            },
+            locked: false,
+            visible: true,
+            z_index: 0,
+            tags: Vec::new(),
+        }
+    }
+
+    fn nudge(id: &str, x: f64, y: f64) -> (String, ComponentUpdate) {
+        let update = ComponentUpdate { position: Some(Position2D { x, y }), ..Default::default() };
+        (id.to_string(), update)
+    }
+
+    #[test]
+    fn batch_moves_three_components_and_reports_unknown_ids() {
+        let mut state = ScoreboardState {
+            components: vec![component("a", 0.0), component("b", 100.0), component("c", 200.0)],
+            ..Default::default()
+        };
+
+        let results = apply_component_updates(&mut state, vec![
+            nudge("a", 10.0, 5.0),
+            nudge("missing", 0.0, 0.0),
+            nudge("b", 110.0, 5.0),
+            nudge("c", 210.0, 5.0),
+        ]);
+
+        assert!(state.is_dirty);
+        let positions: Vec<(f64, f64)> = state.components.iter().map(|c| (c.position.x, c.position.y)).collect();
+        assert_eq!(positions, [(10.0, 5.0), (110.0, 5.0), (210.0, 5.0)]);
+        let applied: Vec<(&str, bool)> = results.iter().map(|r| (r.component_id.as_str(), r.applied)).collect();
+        assert_eq!(applied, [("a", true), ("missing", false), ("b", true), ("c", true)]);
+        assert!(results[1].error.is_some());
+    }
+
+    #[test]
+    fn batch_of_unknown_ids_leaves_the_board_clean() {
+        let mut state = ScoreboardState { components: vec![component("a", 0.0)], ..Default::default() };
+        let results = apply_component_updates(&mut state, vec![nudge("missing", 1.0, 1.0)]);
+        assert!(!results[0].applied);
+        assert!(!state.is_dirty);
+    }
+
+
+    #[test]
+    fn overlap_groups_join_chained_overlaps_and_skip_hidden_components() {
+        let mut components = vec![component("a", 0.0), component("b", 50.0), component("c", 90.0), component("d", 400.0)];
+        let groups = find_overlap_groups(&components, 0.1);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].component_ids, ["a", "b", "c"]);
+        assert_eq!(groups[0].overlaps[0].overlap_ratio, 0.5);
+
+        components[1].visible = false;
+        assert!(find_overlap_groups(&components, 0.1).is_empty());
+    }
+
+    #[test]
+    fn distributing_moves_unlocked_components_beside_locked_ones() {
+        let mut components = vec![component("a", 0.0), component("b", 50.0)];
+        components[0].locked = true;
+
+        let moved = distribute_overlaps(&mut components, 0.1, None);
+        assert_eq!(moved, ["b"]);
+        assert_eq!((components[0].position.x, components[1].position.x), (0.0, 100.0));
+        assert!(find_overlap_groups(&components, 0.1).is_empty());
+    }
+}
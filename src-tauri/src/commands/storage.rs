@@ -1,15 +1,35 @@
 // src-tauri/src/commands/storage.rs
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use schemars::JsonSchema;
+use std::path::{Path, PathBuf};
 use std::fs;
+use std::time::Duration;
 use serde_json;
-use std::io::{Write, Read};
+use std::io::{Write, Read, Seek};
 use zip::{ZipWriter, ZipArchive};
 use zip::write::FileOptions;
 use uuid::Uuid;
+use sha2::{Digest, Sha256};
+use tracing::{debug, error, info, warn};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use crate::commands::config::{app_config, AppConfig};
+use crate::commands::media_library::{write_atomic, MediaLibraryLocks};
+use crate::commands::providers::check_poll_interval;
+use crate::commands::data_dir::data_dir;
+use crate::commands::disk_space::ensure_free_space;
+use crate::commands::events::BackendEvent;
+use crate::commands::media_sniff::detect_mime;
+use crate::commands::event_lock::ensure_unlocked;
+use crate::commands::secrets::{decrypt_secret, encrypt_secret, url_has_credentials};
+use crate::error::AppError;
+use crate::state::{
+    ManagedAppState, ManagedCanvasState, ManagedImageState, ManagedLiveDataState,
+    ManagedScoreboardState, ManagedVideoState,
+};
+use crate::commands::storage_commands::ManagedStateStorage;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ScoreboardConfig {
     pub id: String,
     pub name: String,
@@ -18,6 +38,15 @@ pub struct ScoreboardConfig {
     pub data: serde_json::Value,
     pub created_at: String,
     pub updated_at: String,
+    // Resolution the board was authored for; displays scale from this to their window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_resolution: Option<TargetResolution>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+pub struct TargetResolution {
+    pub width: u32,
+    pub height: u32,
 }
 
 #[tauri::command]
@@ -25,86 +54,109 @@ pub async fn save_scoreboard(
     app: AppHandle,
     name: String,
     data: serde_json::Value,
-) -> Result<String, String> {
-    let app_data_dir = app.path().app_data_dir()
-        .map_err(|e| e.to_string())?;
+) -> Result<String, AppError> {
+    ensure_unlocked("Saving scoreboards")?;
+    let name = validate_scoreboard_name(&name)?;
+    let app_data_dir = data_dir(&app)?;
     
     let scoreboards_dir = app_data_dir.join("scoreboards");
     
     // Create directory if it doesn't exist
-    if !scoreboards_dir.exists() {
-        fs::create_dir_all(&scoreboards_dir).map_err(|e| e.to_string())?;
-    }
+    tokio::fs::create_dir_all(&scoreboards_dir).await?;
     
-    let filename = format!("{}.json", sanitize_filename(&name));
+    let (filename, existing) = scoreboard_save_target(&scoreboards_dir, &name).await;
     let file_path = scoreboards_dir.join(&filename);
     
+    let now = chrono::Utc::now().to_rfc3339();
     let config = ScoreboardConfig {
-        id: uuid::Uuid::new_v4().to_string(),
+        id: existing.as_ref().map_or_else(|| uuid::Uuid::new_v4().to_string(), |e| e.id.clone()),
         name: name.clone(),
         filename: filename.clone(), // Store the actual filename used
         data,
-        created_at: chrono::Utc::now().to_rfc3339(),
-        updated_at: chrono::Utc::now().to_rfc3339(),
+        created_at: existing.as_ref().map_or_else(|| now.clone(), |e| e.created_at.clone()),
+        updated_at: now,
+        // The editor doesn't send the target resolution, so keep whatever was set
+        target_resolution: existing.and_then(|e| e.target_resolution),
     };
     
-    let json_data = serde_json::to_string_pretty(&config)
-        .map_err(|e| e.to_string())?;
+    let json_data = serde_json::to_string_pretty(&config)?;
     
-    fs::write(&file_path, json_data).map_err(|e| e.to_string())?;
+    ensure_free_space(&scoreboards_dir, json_data.len() as u64)?;
+    tokio::fs::write(&file_path, json_data).await?;
     
     Ok(filename)
 }
 
+// Names like "Court 1" and "Court #1" sanitize to the same file. Saving over a
+// file that belongs to a differently named scoreboard would lose it, so try
+// "_2", "_3", ... until the file is free or already holds this scoreboard.
+// Returns the filename to use and the scoreboard currently stored there.
+pub(crate) async fn scoreboard_save_target(scoreboards_dir: &Path, name: &str) -> (String, Option<ScoreboardConfig>) {
+    let base = sanitize_filename(name);
+    let mut suffix = 1;
+    loop {
+        let filename = if suffix == 1 { format!("{}.json", base) } else { format!("{}_{}.json", base, suffix) };
+        let Ok(json) = tokio::fs::read_to_string(scoreboards_dir.join(&filename)).await else {
+            return (filename, None);
+        };
+        match serde_json::from_str::<ScoreboardConfig>(&json) {
+            Ok(existing) if existing.name == name => return (filename, Some(existing)),
+            Ok(existing) => {
+                info!("Scoreboard file {} belongs to '{}', not overwriting it with '{}'", filename, existing.name, name);
+            }
+            // Not a scoreboard we can read; leave it alone rather than overwrite it
+            Err(_) => {}
+        }
+        suffix += 1;
+    }
+}
+
 #[tauri::command]
 pub async fn load_scoreboard(
     app: AppHandle,
     filename: String,
-) -> Result<ScoreboardConfig, String> {
-    let app_data_dir = app.path().app_data_dir()
-        .map_err(|e| e.to_string())?;
+) -> Result<ScoreboardConfig, AppError> {
+    let app_data_dir = data_dir(&app)?;
     
-    let file_path = app_data_dir.join("scoreboards").join(&filename);
+    let file_path = safe_join(&app_data_dir.join("scoreboards"), &filename)?;
     
     if !file_path.exists() {
-        return Err("Scoreboard file not found".to_string());
+        return Err(AppError::not_found("Scoreboard file"));
     }
     
-    let json_data = fs::read_to_string(&file_path).map_err(|e| e.to_string())?;
-    let config: ScoreboardConfig = serde_json::from_str(&json_data)
-        .map_err(|e| e.to_string())?;
+    let json_data = tokio::fs::read_to_string(&file_path).await?;
+    let config: ScoreboardConfig = serde_json::from_str(&json_data)?;
     
     Ok(config)
 }
 
 #[tauri::command]
-pub async fn list_scoreboards(app: AppHandle) -> Result<Vec<ScoreboardConfig>, String> {
-    let app_data_dir = app.path().app_data_dir()
-        .map_err(|e| e.to_string())?;
-    
-    let scoreboards_dir = app_data_dir.join("scoreboards");
-    
-    if !scoreboards_dir.exists() {
+pub async fn list_scoreboards(app: AppHandle) -> Result<Vec<ScoreboardConfig>, AppError> {
+    let app_data_dir = data_dir(&app)?;
+    read_scoreboards(&app_data_dir.join("scoreboards")).await
+}
+
+async fn read_scoreboards(scoreboards_dir: &Path) -> Result<Vec<ScoreboardConfig>, AppError> {
+    if !tokio::fs::try_exists(scoreboards_dir).await? {
         return Ok(vec![]);
     }
     
     let mut scoreboards = Vec::new();
     
-    let entries = fs::read_dir(&scoreboards_dir).map_err(|e| e.to_string())?;
+    let mut entries = tokio::fs::read_dir(scoreboards_dir).await?;
     
-    for entry in entries {
-        let entry = entry.map_err(|e| e.to_string())?;
+    while let Some(entry) = entries.next_entry().await? {
         let path = entry.path();
         
         // Only process .json files
         if path.extension().and_then(|s| s.to_str()) == Some("json") {
             // Verify the file actually exists and is readable
-            if !path.exists() {
-                println!("Warning: Skipping non-existent file: {:?}", path);
+            if !tokio::fs::try_exists(&path).await.unwrap_or(false) {
+                warn!("Skipping non-existent file: {:?}", path);
                 continue;
             }
             
-            match fs::read_to_string(&path) {
+            match tokio::fs::read_to_string(&path).await {
                 Ok(json_data) => {
                     match serde_json::from_str::<ScoreboardConfig>(&json_data) {
                         Ok(mut config) => {
@@ -113,34 +165,34 @@ pub async fn list_scoreboards(app: AppHandle) -> Result<Vec<ScoreboardConfig>, S
                                 if let Some(filename) = path.file_name().and_then(|s| s.to_str()) {
                                     config.filename = filename.to_string();
                                 } else {
-                                    println!("Warning: Could not determine filename for config");
+                                    warn!("Could not determine filename for config");
                                     continue;
                                 }
                             }
                             
                             // Double-check that the referenced file actually exists
                             let config_file_path = scoreboards_dir.join(&config.filename);
-                            if config_file_path.exists() {
+                            if tokio::fs::try_exists(&config_file_path).await.unwrap_or(false) {
                                 scoreboards.push(config);
                             } else {
-                                println!("Warning: Config references non-existent file: {}", config.filename);
+                                warn!("Config references non-existent file: {}", config.filename);
                             }
                         },
                         Err(e) => {
-                            println!("Warning: Skipping invalid JSON file {:?}: {}", path, e);
+                            warn!("Skipping invalid JSON file {:?}: {}", path, e);
                             continue;
                         }
                     }
                 }
                 Err(e) => {
-                    println!("Warning: Could not read file {:?}: {}", path, e);
+                    warn!("Could not read file {:?}: {}", path, e);
                     continue;
                 }
             }
         }
     }
     
-    println!("Returning {} valid scoreboards", scoreboards.len());
+    debug!("Returning {} valid scoreboards", scoreboards.len());
     Ok(scoreboards)
 }
 
@@ -148,17 +200,22 @@ pub async fn list_scoreboards(app: AppHandle) -> Result<Vec<ScoreboardConfig>, S
 pub async fn delete_scoreboard(
     app: AppHandle,
     filename: String,
-) -> Result<(), String> {
-    let app_data_dir = app.path().app_data_dir()
-        .map_err(|e| e.to_string())?;
+) -> Result<(), AppError> {
+    ensure_unlocked("Deleting scoreboards")?;
+    let app_data_dir = data_dir(&app)?;
     
-    let file_path = app_data_dir.join("scoreboards").join(&filename);
+    let file_path = safe_join(&app_data_dir.join("scoreboards"), &filename)?;
     
     if !file_path.exists() {
-        return Err("Scoreboard file not found".to_string());
+        return Err(AppError::not_found("Scoreboard file"));
     }
     
-    fs::remove_file(&file_path).map_err(|e| e.to_string())?;
+    tokio::fs::remove_file(&file_path).await?;
+    
+    // The board's undo history is meaningless once the board is gone
+    if let Err(e) = crate::commands::undo_history::remove_undo_history(&app, &filename).await {
+        warn!("{}", e);
+    }
     
     Ok(())
 }
@@ -168,18 +225,20 @@ pub async fn export_scoreboard(
     app: AppHandle,
     filename: String,
     export_path: String,
-) -> Result<(), String> {
-    let app_data_dir = app.path().app_data_dir()
-        .map_err(|e| e.to_string())?;
+) -> Result<(), AppError> {
+    let app_data_dir = data_dir(&app)?;
     
-    let source_path = app_data_dir.join("scoreboards").join(&filename);
+    let source_path = safe_join(&app_data_dir.join("scoreboards"), &filename)?;
     let export_path = PathBuf::from(export_path);
+    if !has_json_extension(&export_path) || export_path.is_dir() {
+        return Err(AppError::validation("export_path", "Export path must be a .json file"));
+    }
     
     if !source_path.exists() {
-        return Err("Scoreboard file not found".to_string());
+        return Err(AppError::not_found("Scoreboard file"));
     }
     
-    fs::copy(&source_path, &export_path).map_err(|e| e.to_string())?;
+    tokio::fs::copy(&source_path, &export_path).await?;
     
     Ok(())
 }
@@ -188,22 +247,26 @@ pub async fn export_scoreboard(
 pub async fn export_scoreboard_as_zip(
     app: AppHandle,
     filename: String,
-) -> Result<Vec<u8>, String> {
-    let app_data_dir = app.path().app_data_dir()
-        .map_err(|e| e.to_string())?;
+) -> Result<Vec<u8>, AppError> {
+    let app_data_dir = data_dir(&app)?;
     
-    let scoreboard_path = app_data_dir.join("scoreboards").join(&filename);
+    // Reading every image and deflating it takes seconds on big boards; keep it off the async workers
+    tokio::task::spawn_blocking(move || build_scoreboard_zip(&app_data_dir, &filename)).await?
+}
+
+fn build_scoreboard_zip(app_data_dir: &std::path::Path, filename: &str) -> Result<Vec<u8>, AppError> {
+    let scoreboard_path = safe_join(&app_data_dir.join("scoreboards"), filename)?;
     
     if !scoreboard_path.exists() {
-        return Err("Scoreboard file not found".to_string());
+        return Err(AppError::not_found("Scoreboard file"));
     }
     
     // Read the scoreboard configuration
     let scoreboard_content = fs::read_to_string(&scoreboard_path)
-        .map_err(|e| format!("Failed to read scoreboard file: {}", e))?;
+        .map_err(|e| AppError::io(format!("Failed to read scoreboard file: {}", e)))?;
     
     let scoreboard_config: serde_json::Value = serde_json::from_str(&scoreboard_content)
-        .map_err(|e| format!("Failed to parse scoreboard config: {}", e))?;
+        .map_err(|e| AppError::validation("json", format!("Failed to parse scoreboard config: {}", e)))?;
     
     // Create in-memory zip
     let mut zip_data = Vec::new();
@@ -215,9 +278,9 @@ pub async fn export_scoreboard_as_zip(
         
         // Add the scoreboard configuration
         zip.start_file("scoreboard.json", options)
-            .map_err(|e| format!("Failed to create scoreboard.json in zip: {}", e))?;
+            .map_err(|e| AppError::io(format!("Failed to create scoreboard.json in zip: {}", e)))?;
         zip.write_all(scoreboard_content.as_bytes())
-            .map_err(|e| format!("Failed to write scoreboard.json: {}", e))?;
+            .map_err(|e| AppError::io(format!("Failed to write scoreboard.json: {}", e)))?;
         
         // Collect all image IDs used in the scoreboard
         let mut used_image_ids = std::collections::HashSet::new();
@@ -229,62 +292,62 @@ pub async fn export_scoreboard_as_zip(
                     if let Some(component_data) = component.get("data") {
                         if let Some(image_id) = component_data.get("imageId").and_then(|id| id.as_str()) {
                             used_image_ids.insert(image_id.to_string());
-                            println!("Found image ID in component: {}", image_id);
+                            debug!("Found image ID in component: {}", image_id);
                         }
                     }
                 }
             }
         }
         
-        println!("Found {} image IDs in scoreboard: {:?}", used_image_ids.len(), used_image_ids);
+        info!("Found {} image IDs in scoreboard: {:?}", used_image_ids.len(), used_image_ids);
         
         // If there are images, add them to the zip
         if !used_image_ids.is_empty() {
-            println!("Attempting to add {} images to ZIP", used_image_ids.len());
+            debug!("Attempting to add {} images to ZIP", used_image_ids.len());
             // Load image metadata
             let images_dir = app_data_dir.join("images");
             let metadata_file = images_dir.join("metadata.json");
             
-            println!("Looking for image metadata at: {:?}", metadata_file);
+            debug!("Looking for image metadata at: {:?}", metadata_file);
             if metadata_file.exists() {
-                println!("Image metadata file found, reading content...");
+                debug!("Image metadata file found, reading content...");
                 let metadata_content = fs::read_to_string(&metadata_file)
-                    .map_err(|e| format!("Failed to read image metadata: {}", e))?;
+                    .map_err(|e| AppError::io(format!("Failed to read image metadata: {}", e)))?;
                 
                 let images: Vec<serde_json::Value> = serde_json::from_str(&metadata_content)
-                    .map_err(|e| format!("Failed to parse image metadata: {}", e))?;
+                    .map_err(|e| AppError::validation("json", format!("Failed to parse image metadata: {}", e)))?;
                 
-                println!("Loaded {} images from metadata", images.len());
+                debug!("Loaded {} images from metadata", images.len());
                 
                 // Add used images to zip
                 for image in &images {
                     if let Some(id) = image.get("id").and_then(|id| id.as_str()) {
                         if used_image_ids.contains(id) {
-                            println!("Processing image with ID: {}", id);
+                            debug!("Processing image with ID: {}", id);
                             if let Some(path) = image.get("path").and_then(|p| p.as_str()) {
-                                println!("Image path: {}", path);
+                                debug!("Image path: {}", path);
                                 let image_path = PathBuf::from(path);
                                 if image_path.exists() {
-                                    println!("Image file exists, reading data...");
+                                    debug!("Image file exists, reading data...");
                                     let image_data = fs::read(&image_path)
-                                        .map_err(|e| format!("Failed to read image file {}: {}", path, e))?;
+                                        .map_err(|e| AppError::io(format!("Failed to read image file {}: {}", path, e)))?;
                                     
                                     let filename = image_path.file_name()
                                         .and_then(|n| n.to_str())
                                         .unwrap_or("unknown");
                                     
-                                    println!("Adding image to ZIP: images/{}", filename);
-                                    zip.start_file(&format!("images/{}", filename), options)
-                                        .map_err(|e| format!("Failed to create image file in zip: {}", e))?;
+                                    debug!("Adding image to ZIP: images/{}", filename);
+                                    zip.start_file(format!("images/{}", filename), options)
+                                        .map_err(|e| AppError::io(format!("Failed to create image file in zip: {}", e)))?;
                                     zip.write_all(&image_data)
-                                        .map_err(|e| format!("Failed to write image data: {}", e))?;
+                                        .map_err(|e| AppError::io(format!("Failed to write image data: {}", e)))?;
                                     
-                                    println!("Successfully added image {} to ZIP", filename);
+                                    debug!("Successfully added image {} to ZIP", filename);
                                 } else {
-                                    println!("Warning: Image file does not exist at path: {}", path);
+                                    warn!("Image file does not exist at path: {}", path);
                                 }
                             } else {
-                                println!("Warning: No path found for image ID: {}", id);
+                                warn!("No path found for image ID: {}", id);
                             }
                         }
                     }
@@ -301,42 +364,835 @@ pub async fn export_scoreboard_as_zip(
                     .collect();
                 
                 if !used_images.is_empty() {
-                    println!("Adding metadata for {} used images to ZIP", used_images.len());
+                    debug!("Adding metadata for {} used images to ZIP", used_images.len());
                     let metadata_json = serde_json::to_string_pretty(&used_images)
-                        .map_err(|e| format!("Failed to serialize image metadata: {}", e))?;
+                        .map_err(|e| AppError::io(format!("Failed to serialize image metadata: {}", e)))?;
                     
                     zip.start_file("images/metadata.json", options)
-                        .map_err(|e| format!("Failed to create metadata.json in zip: {}", e))?;
+                        .map_err(|e| AppError::io(format!("Failed to create metadata.json in zip: {}", e)))?;
                     zip.write_all(metadata_json.as_bytes())
-                        .map_err(|e| format!("Failed to write metadata.json: {}", e))?;
+                        .map_err(|e| AppError::io(format!("Failed to write metadata.json: {}", e)))?;
                     
-                    println!("Successfully added image metadata to ZIP");
+                    debug!("Successfully added image metadata to ZIP");
                 } else {
-                    println!("No used images found in metadata");
+                    debug!("No used images found in metadata");
                 }
             } else {
-                println!("Image metadata file not found at: {:?}", metadata_file);
+                debug!("Image metadata file not found at: {:?}", metadata_file);
             }
         } else {
-            println!("No images found in scoreboard components");
+            debug!("No images found in scoreboard components");
         }
         
         zip.finish()
-            .map_err(|e| format!("Failed to finalize zip: {}", e))?;
+            .map_err(|e| AppError::io(format!("Failed to finalize zip: {}", e)))?;
     }
     
     Ok(zip_data)
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZipExportProgressEvent {
+    pub file: String,
+    pub added_files: usize,
+    pub total_files: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZipExportSummary {
+    pub path: String,
+    pub files: usize,
+    pub bytes: u64,
+}
+
+// One file to copy into the archive: its name inside the ZIP and where it is on disk
+struct ZipExportEntry {
+    archive_name: String,
+    source: PathBuf,
+}
+
+// Metadata entries (as raw JSON) whose ids appear in `used`, with the file each one names
+fn used_media(dir: &Path, used: &std::collections::HashSet<String>) -> Result<(Vec<serde_json::Value>, Vec<PathBuf>), AppError> {
+    let metadata_file = dir.join("metadata.json");
+    if !metadata_file.exists() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    let entries: Vec<serde_json::Value> = serde_json::from_str(&fs::read_to_string(&metadata_file)?)?;
+    let mut kept = Vec::new();
+    let mut files = Vec::new();
+    for entry in entries {
+        let id = entry.get("id").and_then(|id| id.as_str()).unwrap_or("");
+        let name = entry.get("name").and_then(|name| name.as_str()).unwrap_or("");
+        if !used.contains(id) {
+            continue;
+        }
+        match safe_join(dir, name) {
+            Ok(path) if path.is_file() => {
+                files.push(path);
+                kept.push(entry);
+            }
+            _ => warn!("Skipping {} in export: file {:?} is missing", id, name),
+        }
+    }
+    Ok((kept, files))
+}
+
+// Every board plus the images and videos they use, written straight to `target`.
+// Files are copied through a buffered writer one at a time, so memory use does
+// not grow with the size of the library. `report` is called after each file.
+fn write_all_scoreboards_zip(
+    app_data_dir: &Path,
+    target: &Path,
+    report: impl Fn(&ZipExportProgressEvent),
+) -> Result<ZipExportSummary, AppError> {
+    let scoreboards_dir = app_data_dir.join("scoreboards");
+    let mut boards: Vec<PathBuf> = if scoreboards_dir.exists() {
+        fs::read_dir(&scoreboards_dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && has_json_extension(path))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    boards.sort();
+    if boards.is_empty() {
+        return Err(AppError::not_found("Scoreboards to export"));
+    }
+
+    let mut used_images = std::collections::HashSet::new();
+    let mut used_videos = std::collections::HashSet::new();
+    let mut entries = Vec::new();
+    for board in &boards {
+        let config: serde_json::Value = serde_json::from_str(&fs::read_to_string(board)?)
+            .map_err(|e| AppError::validation("json", format!("Failed to parse {:?}: {}", board.file_name().unwrap_or_default(), e)))?;
+        let components = config.get("data").and_then(|d| d.get("components")).and_then(|c| c.as_array());
+        for component_data in components.into_iter().flatten().filter_map(|c| c.get("data")) {
+            if let Some(id) = component_data.get("imageId").and_then(|id| id.as_str()) {
+                used_images.insert(id.to_string());
+            }
+            if let Some(id) = component_data.get("videoId").and_then(|id| id.as_str()) {
+                used_videos.insert(id.to_string());
+            }
+        }
+        let name = board.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        entries.push(ZipExportEntry { archive_name: format!("scoreboards/{}", name), source: board.clone() });
+    }
+
+    let mut media_metadata = Vec::new();
+    for (folder, used) in [("images", &used_images), ("videos", &used_videos)] {
+        let (metadata, files) = used_media(&app_data_dir.join(folder), used)?;
+        for file in files {
+            let name = file.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            entries.push(ZipExportEntry { archive_name: format!("{}/{}", folder, name), source: file });
+        }
+        if !metadata.is_empty() {
+            media_metadata.push((format!("{}/metadata.json", folder), serde_json::to_string_pretty(&metadata)?));
+        }
+    }
+
+    let total_bytes: u64 = entries.iter()
+        .filter_map(|entry| fs::metadata(&entry.source).ok())
+        .map(|metadata| metadata.len())
+        .sum();
+    if let Some(parent) = target.parent() {
+        ensure_free_space(parent, total_bytes)?;
+    }
+
+    // Written beside the target and renamed over it, so a failed export leaves no half-written ZIP
+    let temp_path = target.with_extension(format!("zip.{}.tmp", Uuid::new_v4()));
+    let result = (|| -> Result<usize, AppError> {
+        let mut zip = ZipWriter::new(std::io::BufWriter::new(fs::File::create(&temp_path)?));
+        let options: FileOptions<'_, ()> = FileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .large_file(true)
+            .unix_permissions(0o644);
+
+        let total_files = entries.len() + media_metadata.len();
+        let mut added_files = 0;
+        let report = |file: &str, added_files: usize| {
+            report(&ZipExportProgressEvent { file: file.to_string(), added_files, total_files });
+        };
+
+        for entry in &entries {
+            zip.start_file(entry.archive_name.as_str(), options)?;
+            let mut source = std::io::BufReader::new(fs::File::open(&entry.source)?);
+            std::io::copy(&mut source, &mut zip)
+                .map_err(|e| AppError::io(format!("Failed to add {} to the ZIP: {}", entry.archive_name, e)))?;
+            added_files += 1;
+            report(&entry.archive_name, added_files);
+        }
+        for (archive_name, json) in &media_metadata {
+            zip.start_file(archive_name.as_str(), options)?;
+            zip.write_all(json.as_bytes())?;
+            added_files += 1;
+            report(archive_name, added_files);
+        }
+
+        let mut writer = zip.finish()?;
+        writer.flush()?;
+        Ok(added_files)
+    })();
+
+    let files = match result {
+        Ok(files) => files,
+        Err(e) => {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e);
+        }
+    };
+    fs::rename(&temp_path, target)?;
+    let bytes = fs::metadata(target)?.len();
+    Ok(ZipExportSummary { path: target.to_string_lossy().to_string(), files, bytes })
+}
+
+// For libraries too big to build in memory like export_scoreboard_as_zip does
+#[tauri::command]
+pub async fn export_all_scoreboards_to_zip(app: AppHandle, export_path: String) -> Result<ZipExportSummary, AppError> {
+    let target = PathBuf::from(export_path);
+    let is_zip = target.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case("zip"));
+    if !is_zip || target.is_dir() {
+        return Err(AppError::validation("export_path", "Export path must be a .zip file"));
+    }
+
+    let app_data_dir = data_dir(&app)?;
+    let summary = tokio::task::spawn_blocking(move || {
+        write_all_scoreboards_zip(&app_data_dir, &target, |event| {
+            if let Err(e) = app.emit(BackendEvent::ZipExportProgress.as_str(), event) {
+                error!("Failed to emit {}: {}", BackendEvent::ZipExportProgress, e);
+            }
+        })
+    }).await??;
+    info!("📦 Exported {} file(s) ({} bytes) to {}", summary.files, summary.bytes, summary.path);
+    Ok(summary)
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn svg_number(value: Option<&serde_json::Value>, default: f64) -> f64 {
+    value.and_then(|v| v.as_f64()).unwrap_or(default)
+}
+
+// Render the saved canvas as SVG. `image_sources` maps image IDs to data URIs.
+pub fn render_scoreboard_svg(
+    data: &serde_json::Value,
+    image_sources: &std::collections::HashMap<String, String>,
+) -> String {
+    let dimensions = data.get("dimensions");
+    let width = svg_number(dimensions.and_then(|d| d.get("width")), 1920.0);
+    let height = svg_number(dimensions.and_then(|d| d.get("height")), 1080.0);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+        w = width, h = height
+    );
+
+    if let Some(background) = data.get("background") {
+        if let Some(color) = background.get("color").and_then(|c| c.as_str()) {
+            let opacity = svg_number(background.get("opacity"), 1.0);
+            svg.push_str(&format!(
+                "  <rect width=\"100%\" height=\"100%\" fill=\"{}\" fill-opacity=\"{}\"/>\n",
+                escape_xml(color), opacity
+            ));
+        }
+    }
+
+    let mut components: Vec<&serde_json::Value> = data.get("components")
+        .and_then(|c| c.as_array())
+        .map(|c| c.iter().collect())
+        .unwrap_or_default();
+
+    // Hidden components are not drawn; stable sort keeps save order for equal z-index
+    components.retain(|c| c.get("visible").and_then(|v| v.as_bool()).unwrap_or(true));
+    components.sort_by_key(|c| c.get("zIndex").and_then(|z| z.as_i64()).unwrap_or(0));
+
+    for component in components {
+        let id = component.get("id").and_then(|id| id.as_str()).unwrap_or("");
+        let position = component.get("position");
+        let size = component.get("size");
+        let x = svg_number(position.and_then(|p| p.get("x")), 0.0);
+        let y = svg_number(position.and_then(|p| p.get("y")), 0.0);
+        let w = svg_number(size.and_then(|s| s.get("width")), 0.0);
+        let h = svg_number(size.and_then(|s| s.get("height")), 0.0);
+        let rotation = svg_number(component.get("rotation"), 0.0);
+        let style = component.get("style");
+        let style_str = |name: &str| style.and_then(|s| s.get(name)).and_then(|v| v.as_str());
+        let style_num = |name: &str| style.and_then(|s| s.get(name)).and_then(|v| v.as_f64());
+        let component_data = component.get("data");
+
+        let mut transform = format!("translate({} {})", x, y);
+        if rotation != 0.0 {
+            transform.push_str(&format!(" rotate({} {} {})", rotation, w / 2.0, h / 2.0));
+        }
+        let opacity = style_num("opacity")
+            .map(|o| format!(" opacity=\"{}\"", o))
+            .unwrap_or_default();
+
+        svg.push_str(&format!(
+            "  <g id=\"{}\" data-type=\"{}\" transform=\"{}\"{}>\n",
+            escape_xml(id),
+            escape_xml(component.get("type").and_then(|t| t.as_str()).unwrap_or("")),
+            transform,
+            opacity
+        ));
+
+        let fill = style_str("backgroundColor").unwrap_or("none");
+        let border_width = style_num("borderWidth").unwrap_or(0.0);
+        let stroke = if border_width > 0.0 {
+            format!(" stroke=\"{}\" stroke-width=\"{}\"",
+                escape_xml(style_str("borderColor").unwrap_or("#000000")), border_width)
+        } else {
+            String::new()
+        };
+        let radius = style_num("borderRadius").unwrap_or(0.0);
+        svg.push_str(&format!(
+            "    <rect width=\"{}\" height=\"{}\" rx=\"{}\" fill=\"{}\"{}/>\n",
+            w, h, radius, escape_xml(fill), stroke
+        ));
+
+        let image_href = component_data
+            .and_then(|d| d.get("imageId"))
+            .and_then(|id| id.as_str())
+            .and_then(|id| image_sources.get(id).cloned())
+            .or_else(|| component_data
+                .and_then(|d| d.get("imageUrl"))
+                .and_then(|url| url.as_str())
+                .map(|url| url.to_string()));
+        if let Some(href) = image_href {
+            svg.push_str(&format!(
+                "    <image width=\"{}\" height=\"{}\" preserveAspectRatio=\"xMidYMid meet\" href=\"{}\"/>\n",
+                w, h, escape_xml(&href)
+            ));
+        }
+
+        let text = component_data.and_then(|d| {
+            d.get("text").and_then(|t| t.as_str()).map(|t| t.to_string())
+                .or_else(|| d.get("value").filter(|v| !v.is_null()).map(|v| match v.as_str() {
+                    Some(s) => s.to_string(),
+                    None => v.to_string(),
+                }))
+        });
+        if let Some(text) = text.filter(|t| !t.is_empty()) {
+            let (text_x, anchor) = match style_str("textAlign") {
+                Some("left") => (0.0, "start"),
+                Some("right") => (w, "end"),
+                _ => (w / 2.0, "middle"),
+            };
+            svg.push_str(&format!(
+                "    <text x=\"{}\" y=\"{}\" text-anchor=\"{}\" dominant-baseline=\"middle\" font-size=\"{}\" font-family=\"{}\" font-weight=\"{}\" fill=\"{}\">{}</text>\n",
+                text_x,
+                h / 2.0,
+                anchor,
+                style_num("fontSize").unwrap_or(16.0),
+                escape_xml(style_str("fontFamily").unwrap_or("Arial")),
+                escape_xml(style_str("fontWeight").unwrap_or("normal")),
+                escape_xml(style_str("textColor").unwrap_or("#ffffff")),
+                escape_xml(&text)
+            ));
+        }
+
+        svg.push_str("  </g>\n");
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[tauri::command]
+pub async fn export_scoreboard_as_svg(
+    app: AppHandle,
+    filename: String,
+) -> Result<String, AppError> {
+    let app_data_dir = data_dir(&app)?;
+    
+    let scoreboard_path = safe_join(&app_data_dir.join("scoreboards"), &filename)?;
+    
+    if !scoreboard_path.exists() {
+        return Err(AppError::not_found("Scoreboard file"));
+    }
+    
+    let scoreboard_content = fs::read_to_string(&scoreboard_path)
+        .map_err(|e| AppError::io(format!("Failed to read scoreboard file: {}", e)))?;
+    
+    let scoreboard_config: serde_json::Value = serde_json::from_str(&scoreboard_content)
+        .map_err(|e| AppError::validation("json", format!("Failed to parse scoreboard config: {}", e)))?;
+    
+    let data = scoreboard_config.get("data").cloned().unwrap_or(serde_json::Value::Null);
+    
+    // Embed referenced images as data URIs so the SVG is self-contained
+    let mut image_sources = std::collections::HashMap::new();
+    let metadata_file = app_data_dir.join("images").join("metadata.json");
+    if metadata_file.exists() {
+        let metadata_content = fs::read_to_string(&metadata_file)
+            .map_err(|e| AppError::io(format!("Failed to read image metadata: {}", e)))?;
+        let images: Vec<serde_json::Value> = serde_json::from_str(&metadata_content)
+            .map_err(|e| AppError::validation("json", format!("Failed to parse image metadata: {}", e)))?;
+        
+        let used_image_ids: std::collections::HashSet<&str> = data.get("components")
+            .and_then(|c| c.as_array())
+            .map(|components| components.iter()
+                .filter_map(|c| c.get("data")?.get("imageId")?.as_str())
+                .collect())
+            .unwrap_or_default();
+        
+        for image in &images {
+            let (Some(id), Some(path)) = (
+                image.get("id").and_then(|id| id.as_str()),
+                image.get("path").and_then(|p| p.as_str()),
+            ) else {
+                continue;
+            };
+            if !used_image_ids.contains(id) {
+                continue;
+            }
+            match fs::read(path) {
+                Ok(bytes) => {
+                    let mime = image.get("type").and_then(|t| t.as_str()).unwrap_or("image/png");
+                    let encoded = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &bytes);
+                    image_sources.insert(id.to_string(), format!("data:{};base64,{}", mime, encoded));
+                }
+                Err(e) => warn!("Could not read image {} for SVG export: {}", path, e),
+            }
+        }
+    }
+    
+    Ok(render_scoreboard_svg(&data, &image_sources))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PreviewScaleMode {
+    // Uniform scale, centered with letterboxing
+    Fit,
+    // Independent horizontal/vertical scale filling the target
+    Stretch,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewComponent {
+    pub id: String,
+    pub component_type: String,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub font_size: Option<f64>,
+    pub overflows: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolutionPreview {
+    pub design_width: f64,
+    pub design_height: f64,
+    pub target_width: f64,
+    pub target_height: f64,
+    pub mode: PreviewScaleMode,
+    pub scale_x: f64,
+    pub scale_y: f64,
+    pub offset_x: f64,
+    pub offset_y: f64,
+    pub components: Vec<PreviewComponent>,
+    pub overflowing: Vec<String>,
+}
+
+pub fn scale_layout(
+    data: &serde_json::Value,
+    width: f64,
+    height: f64,
+    mode: PreviewScaleMode,
+) -> Result<ResolutionPreview, AppError> {
+    if width <= 0.0 || height <= 0.0 {
+        return Err(AppError::validation("target_resolution", "Target resolution must be positive"));
+    }
+
+    let dimensions = data.get("dimensions");
+    let design_width = svg_number(dimensions.and_then(|d| d.get("width")), 0.0);
+    let design_height = svg_number(dimensions.and_then(|d| d.get("height")), 0.0);
+    if design_width <= 0.0 || design_height <= 0.0 {
+        return Err(AppError::validation("dimensions", "Scoreboard has no design dimensions"));
+    }
+
+    let (scale_x, scale_y, offset_x, offset_y) = match mode {
+        PreviewScaleMode::Stretch => (width / design_width, height / design_height, 0.0, 0.0),
+        PreviewScaleMode::Fit => {
+            let scale = (width / design_width).min(height / design_height);
+            (scale, scale, (width - design_width * scale) / 2.0, (height - design_height * scale) / 2.0)
+        }
+    };
+    // Text can't stretch non-uniformly, so use the smaller axis
+    let font_scale = scale_x.min(scale_y);
+
+    let components: Vec<PreviewComponent> = data.get("components")
+        .and_then(|c| c.as_array())
+        .map(|components| components.iter().map(|component| {
+            let position = component.get("position");
+            let size = component.get("size");
+            let x = offset_x + svg_number(position.and_then(|p| p.get("x")), 0.0) * scale_x;
+            let y = offset_y + svg_number(position.and_then(|p| p.get("y")), 0.0) * scale_y;
+            let w = svg_number(size.and_then(|s| s.get("width")), 0.0) * scale_x;
+            let h = svg_number(size.and_then(|s| s.get("height")), 0.0) * scale_y;
+            // Half-pixel tolerance so rounding at the edges isn't reported
+            let overflows = x < -0.5 || y < -0.5 || x + w > width + 0.5 || y + h > height + 0.5;
+
+            PreviewComponent {
+                id: component.get("id").and_then(|id| id.as_str()).unwrap_or("").to_string(),
+                component_type: component.get("type").and_then(|t| t.as_str()).unwrap_or("").to_string(),
+                x,
+                y,
+                width: w,
+                height: h,
+                font_size: component.get("style")
+                    .and_then(|s| s.get("fontSize"))
+                    .and_then(|f| f.as_f64())
+                    .map(|f| f * font_scale),
+                overflows,
+            }
+        }).collect())
+        .unwrap_or_default();
+
+    let overflowing = components.iter()
+        .filter(|c| c.overflows)
+        .map(|c| c.id.clone())
+        .collect();
+
+    Ok(ResolutionPreview {
+        design_width,
+        design_height,
+        target_width: width,
+        target_height: height,
+        mode,
+        scale_x,
+        scale_y,
+        offset_x,
+        offset_y,
+        components,
+        overflowing,
+    })
+}
+
+#[tauri::command]
+pub async fn preview_scoreboard_at_resolution(
+    app: AppHandle,
+    filename: String,
+    width: f64,
+    height: f64,
+    mode: Option<PreviewScaleMode>,
+) -> Result<ResolutionPreview, AppError> {
+    let app_data_dir = data_dir(&app)?;
+    
+    let scoreboard_path = safe_join(&app_data_dir.join("scoreboards"), &filename)?;
+    
+    if !scoreboard_path.exists() {
+        return Err(AppError::not_found("Scoreboard file"));
+    }
+    
+    let scoreboard_content = fs::read_to_string(&scoreboard_path)
+        .map_err(|e| AppError::io(format!("Failed to read scoreboard file: {}", e)))?;
+    
+    let config: ScoreboardConfig = serde_json::from_str(&scoreboard_content)
+        .map_err(|e| AppError::validation("json", format!("Failed to parse scoreboard config: {}", e)))?;
+    
+    let preview = scale_layout(&config.data, width, height, mode.unwrap_or(PreviewScaleMode::Fit))?;
+    
+    if !preview.overflowing.is_empty() {
+        warn!("⚠️ {} component(s) overflow {}x{}: {:?}", preview.overflowing.len(), width, height, preview.overflowing);
+    }
+    
+    Ok(preview)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderScale {
+    pub target_width: f64,
+    pub target_height: f64,
+    pub scale: f64,
+    // Size of the scaled board inside the window
+    pub rendered_width: f64,
+    pub rendered_height: f64,
+    // Bars on each side (pillarbox) or top and bottom (letterbox)
+    pub offset_x: f64,
+    pub offset_y: f64,
+    pub letterboxed: bool,
+    pub pillarboxed: bool,
+}
+
+// Uniform scale from the board's target resolution into a window, centred
+pub fn compute_render_scale(
+    target_width: f64,
+    target_height: f64,
+    window_width: f64,
+    window_height: f64,
+) -> Result<RenderScale, AppError> {
+    if target_width <= 0.0 || target_height <= 0.0 {
+        return Err(AppError::validation("target_resolution", "Target resolution must be positive"));
+    }
+    if window_width <= 0.0 || window_height <= 0.0 {
+        return Err(AppError::validation("window_size", "Window size must be positive"));
+    }
+
+    let scale = (window_width / target_width).min(window_height / target_height);
+    let rendered_width = target_width * scale;
+    let rendered_height = target_height * scale;
+    let offset_x = (window_width - rendered_width) / 2.0;
+    let offset_y = (window_height - rendered_height) / 2.0;
+
+    Ok(RenderScale {
+        target_width,
+        target_height,
+        scale,
+        rendered_width,
+        rendered_height,
+        offset_x,
+        offset_y,
+        // Half-pixel tolerance so rounding doesn't count as a bar
+        letterboxed: offset_y >= 0.5,
+        pillarboxed: offset_x >= 0.5,
+    })
+}
+
+pub(crate) fn read_scoreboard_config(app: &AppHandle, filename: &str) -> Result<(PathBuf, ScoreboardConfig), AppError> {
+    let app_data_dir = data_dir(app)?;
+    let scoreboard_path = safe_join(&app_data_dir.join("scoreboards"), filename)?;
+    if !scoreboard_path.exists() {
+        return Err(AppError::not_found("Scoreboard file"));
+    }
+
+    let scoreboard_content = fs::read_to_string(&scoreboard_path)
+        .map_err(|e| AppError::io(format!("Failed to read scoreboard file: {}", e)))?;
+    let config: ScoreboardConfig = serde_json::from_str(&scoreboard_content)
+        .map_err(|e| AppError::validation("json", format!("Failed to parse scoreboard config: {}", e)))?;
+    Ok((scoreboard_path, config))
+}
+
+#[tauri::command]
+pub async fn set_scoreboard_target_resolution(
+    app: AppHandle,
+    filename: String,
+    target_resolution: Option<TargetResolution>,
+) -> Result<(), AppError> {
+    ensure_unlocked("Changing the target resolution")?;
+    if let Some(target) = target_resolution {
+        if target.width == 0 || target.height == 0 {
+            return Err(AppError::validation("target_resolution", "Target resolution must be positive"));
+        }
+    }
+
+    let (scoreboard_path, mut config) = read_scoreboard_config(&app, &filename)?;
+    config.target_resolution = target_resolution;
+    config.updated_at = chrono::Utc::now().to_rfc3339();
+
+    let json_data = serde_json::to_string_pretty(&config)?;
+    fs::write(&scoreboard_path, json_data)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_scoreboard_render_scale(
+    app: AppHandle,
+    filename: String,
+    window_width: f64,
+    window_height: f64,
+) -> Result<RenderScale, AppError> {
+    let (_, config) = read_scoreboard_config(&app, &filename)?;
+
+    // Boards without an explicit target were authored at their canvas size
+    let (target_width, target_height) = match config.target_resolution {
+        Some(target) => (target.width as f64, target.height as f64),
+        None => {
+            let dimensions = config.data.get("dimensions");
+            (
+                svg_number(dimensions.and_then(|d| d.get("width")), 1920.0),
+                svg_number(dimensions.and_then(|d| d.get("height")), 1080.0),
+            )
+        }
+    };
+
+    compute_render_scale(target_width, target_height, window_width, window_height)
+}
+
+// What a scoreboard ZIP would extract to, as declared in its central directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZipImportSummary {
+    pub entries: usize,
+    pub total_uncompressed_bytes: u64,
+    pub largest_entry_bytes: u64,
+}
+
+// Check an archive against the configured import limits without extracting
+// anything, so a ZIP bomb is turned away before it can fill memory or disk
+pub(crate) fn check_zip_limits<R: Read + Seek>(archive: &mut ZipArchive<R>, config: &AppConfig) -> Result<ZipImportSummary, AppError> {
+    let entries = archive.len();
+    if entries as u64 > config.zip_import_max_entries {
+        return Err(AppError::validation("zip", format!(
+            "ZIP contains {} files; at most {} are allowed", entries, config.zip_import_max_entries
+        )));
+    }
+
+    let mut total_uncompressed_bytes: u64 = 0;
+    let mut largest_entry_bytes = 0;
+    for i in 0..entries {
+        let file = archive.by_index_raw(i)
+            .map_err(|e| AppError::io(format!("Failed to read file from ZIP: {}", e)))?;
+        let size = file.size();
+        if size > config.zip_import_max_entry_bytes {
+            return Err(AppError::validation("zip", format!(
+                "'{}' is {} bytes uncompressed; the limit per file is {} bytes",
+                file.name(), size, config.zip_import_max_entry_bytes
+            )));
+        }
+        largest_entry_bytes = largest_entry_bytes.max(size);
+        total_uncompressed_bytes = total_uncompressed_bytes.saturating_add(size);
+    }
+    if total_uncompressed_bytes > config.zip_import_max_total_bytes {
+        return Err(AppError::validation("zip", format!(
+            "ZIP would extract to {} bytes; the limit is {} bytes",
+            total_uncompressed_bytes, config.zip_import_max_total_bytes
+        )));
+    }
+
+    Ok(ZipImportSummary { entries, total_uncompressed_bytes, largest_entry_bytes })
+}
+
+// Read one entry, stopping at `max_bytes`; the declared size in the header
+// can't be trusted on its own
+fn read_zip_entry<R: Read>(file: R, name: &str, max_bytes: u64) -> Result<Vec<u8>, AppError> {
+    let mut data = Vec::new();
+    file.take(max_bytes + 1).read_to_end(&mut data)
+        .map_err(|e| AppError::io(format!("Failed to read {}: {}", name, e)))?;
+    if data.len() as u64 > max_bytes {
+        return Err(AppError::validation("zip", format!(
+            "'{}' is larger than the {} byte limit per file", name, max_bytes
+        )));
+    }
+    Ok(data)
+}
+
+fn open_scoreboard_zip(zip_data: &[u8]) -> Result<ZipArchive<std::io::Cursor<&[u8]>>, AppError> {
+    ZipArchive::new(std::io::Cursor::new(zip_data))
+        .map_err(|e| AppError::io(format!("Failed to read ZIP file: {}", e)))
+}
+
+#[tauri::command]
+pub async fn validate_scoreboard_zip(zip_data: Vec<u8>) -> Result<ZipImportSummary, AppError> {
+    check_zip_limits(&mut open_scoreboard_zip(&zip_data)?, &app_config())
+}
+
+// What a ZIP import does when a scoreboard with the same name already exists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportConflictStrategy {
+    // Import as "Name (1)", "Name (2)", ...
+    #[default]
+    Rename,
+    // Overwrite the existing scoreboard
+    Replace,
+    // Leave the existing scoreboard and fail the import
+    Skip,
+}
+
+// Images already written during a batch import, keyed by the SHA-256 of their
+// bytes, so a logo shared by several boards is stored once
+#[derive(Default)]
+pub(crate) struct ImportedImageCache {
+    ids_by_hash: std::collections::HashMap<String, String>,
+}
+
+pub(crate) fn content_hash(data: &[u8]) -> String {
+    Sha256::digest(data).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// Outcome of one archive in a batch import; exactly one of the two is set
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZipBatchImportResult {
+    pub index: usize,
+    pub scoreboard: Option<ScoreboardConfig>,
+    pub error: Option<AppError>,
+}
+
 #[tauri::command]
 pub async fn import_scoreboard_from_zip(
     app: AppHandle,
     zip_data: Vec<u8>,
-) -> Result<ScoreboardConfig, String> {
-    // Create a cursor from the zip data
-    let cursor = std::io::Cursor::new(zip_data.clone());
-    let mut archive = ZipArchive::new(cursor)
-        .map_err(|e| format!("Failed to read ZIP file: {}", e))?;
+) -> Result<ScoreboardConfig, AppError> {
+    ensure_unlocked("Importing scoreboards")?;
+    import_zip(&app, &zip_data, &mut ImportedImageCache::default(), ImportConflictStrategy::Rename).await
+}
+
+// Import each archive in turn; one bad archive doesn't stop the rest
+#[tauri::command]
+pub async fn import_scoreboard_zips(
+    app: AppHandle,
+    zips: Vec<Vec<u8>>,
+) -> Result<Vec<ZipBatchImportResult>, AppError> {
+    ensure_unlocked("Importing scoreboards")?;
+    let mut cache = ImportedImageCache::default();
+    let mut results = Vec::with_capacity(zips.len());
+
+    for (index, zip_data) in zips.iter().enumerate() {
+        let result = import_zip(&app, zip_data, &mut cache, ImportConflictStrategy::Rename).await;
+        if let Err(e) = &result {
+            warn!("Failed to import scoreboard ZIP {} of {}: {}", index + 1, zips.len(), e);
+        }
+        let (scoreboard, error) = match result {
+            Ok(scoreboard) => (Some(scoreboard), None),
+            Err(e) => (None, Some(e)),
+        };
+        results.push(ZipBatchImportResult { index, scoreboard, error });
+    }
+
+    info!("Imported {} of {} scoreboard ZIPs ({} unique images)",
+        results.iter().filter(|r| r.scoreboard.is_some()).count(), zips.len(), cache.ids_by_hash.len());
+    Ok(results)
+}
+
+// Picks the file an imported board is written to, resolved the same way saving
+// does so a sanitized name that collides with a differently named board gets
+// its own file instead of overwriting it. Renames `name` under `Rename`.
+async fn import_target(scoreboards_dir: &Path, name: &mut String, conflict: ImportConflictStrategy) -> Result<String, AppError> {
+    let (filename, existing) = scoreboard_save_target(scoreboards_dir, name).await;
+    match conflict {
+        // Generate new unique name if a scoreboard with the same name exists
+        ImportConflictStrategy::Rename if existing.is_some() => {
+            let mut counter = 1;
+            loop {
+                let final_name = format!("{} ({})", name, counter);
+                let (filename, existing) = scoreboard_save_target(scoreboards_dir, &final_name).await;
+                if existing.is_none() {
+                    *name = final_name;
+                    return Ok(filename);
+                }
+                counter += 1;
+            }
+        }
+        ImportConflictStrategy::Skip if existing.is_some() => {
+            Err(AppError::conflict(format!("A scoreboard named '{}' already exists", name)))
+        }
+        _ => Ok(filename),
+    }
+}
+
+pub(crate) async fn import_zip(
+    app: &AppHandle,
+    zip_data: &[u8],
+    image_cache: &mut ImportedImageCache,
+    conflict: ImportConflictStrategy,
+) -> Result<ScoreboardConfig, AppError> {
+    let limits = app_config();
+    let mut archive = open_scoreboard_zip(zip_data)?;
+    check_zip_limits(&mut archive, &limits)?;
     
     // First pass: validate structure and read scoreboard.json
     let mut scoreboard_content = String::new();
@@ -345,44 +1201,35 @@ pub async fn import_scoreboard_from_zip(
     // Find and read scoreboard.json
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)
-            .map_err(|e| format!("Failed to read file from ZIP: {}", e))?;
+            .map_err(|e| AppError::io(format!("Failed to read file from ZIP: {}", e)))?;
         
         if file.name() == "scoreboard.json" {
-            file.read_to_string(&mut scoreboard_content)
-                .map_err(|e| format!("Failed to read scoreboard.json: {}", e))?;
+            let data = read_zip_entry(&mut file, "scoreboard.json", limits.zip_import_max_entry_bytes)?;
+            scoreboard_content = String::from_utf8(data)
+                .map_err(|_| AppError::validation("json", "scoreboard.json is not valid UTF-8"))?;
             has_scoreboard = true;
             break;
         }
     }
     
     if !has_scoreboard {
-        return Err("Invalid ZIP: missing scoreboard.json".to_string());
+        return Err(AppError::validation("zip", "Invalid ZIP: missing scoreboard.json"));
     }
     
     // Parse scoreboard configuration
     let mut scoreboard_config: ScoreboardConfig = serde_json::from_str(&scoreboard_content)
-        .map_err(|e| format!("Invalid scoreboard.json format: {}", e))?;
+        .map_err(|e| AppError::validation("json", format!("Invalid scoreboard.json format: {}", e)))?;
     
-    // Generate new unique name if a scoreboard with the same name exists
-    let app_data_dir = app.path().app_data_dir()
-        .map_err(|e| e.to_string())?;
+    let app_data_dir = data_dir(app)?;
     let scoreboards_dir = app_data_dir.join("scoreboards");
     
-    let mut final_name = scoreboard_config.name.clone();
-    let mut counter = 1;
-    while scoreboards_dir.join(&format!("{}.json", final_name)).exists() {
-        final_name = format!("{} ({})", scoreboard_config.name, counter);
-        counter += 1;
-    }
-    scoreboard_config.name = final_name;
+    let filename = import_target(&scoreboards_dir, &mut scoreboard_config.name, conflict).await?;
     
     // Second pass: handle images if they exist
     let mut imported_image_mapping = std::collections::HashMap::new();
     
     // Reset archive for second pass
-    let cursor = std::io::Cursor::new(&zip_data);
-    let mut archive = ZipArchive::new(cursor)
-        .map_err(|e| format!("Failed to re-read ZIP file: {}", e))?;
+    let mut archive = open_scoreboard_zip(zip_data)?;
     
     // Check if we have images to import
     let has_images = (0..archive.len()).any(|i| {
@@ -398,14 +1245,18 @@ pub async fn import_scoreboard_from_zip(
         let images_dir = app_data_dir.join("images");
         if !images_dir.exists() {
             fs::create_dir_all(&images_dir)
-                .map_err(|e| format!("Failed to create images directory: {}", e))?;
+                .map_err(|e| AppError::io(format!("Failed to create images directory: {}", e)))?;
         }
         
+        // Hold the image library lock until the merged metadata is saved
+        let locks = app.state::<MediaLibraryLocks>();
+        let _metadata_guard = locks.images.lock().await;
+        
         // Load existing image metadata
         let metadata_file = images_dir.join("metadata.json");
         let mut existing_images: Vec<serde_json::Value> = if metadata_file.exists() {
             let content = fs::read_to_string(&metadata_file)
-                .map_err(|e| format!("Failed to read existing image metadata: {}", e))?;
+                .map_err(|e| AppError::io(format!("Failed to read existing image metadata: {}", e)))?;
             serde_json::from_str(&content).unwrap_or_default()
         } else {
             Vec::new()
@@ -415,18 +1266,19 @@ pub async fn import_scoreboard_from_zip(
         let mut image_metadata_content = String::new();
         for i in 0..archive.len() {
             let mut file = archive.by_index(i)
-                .map_err(|e| format!("Failed to read file from ZIP: {}", e))?;
+                .map_err(|e| AppError::io(format!("Failed to read file from ZIP: {}", e)))?;
             
             if file.name() == "images/metadata.json" {
-                file.read_to_string(&mut image_metadata_content)
-                    .map_err(|e| format!("Failed to read image metadata: {}", e))?;
+                let data = read_zip_entry(&mut file, "images/metadata.json", limits.zip_import_max_entry_bytes)?;
+                image_metadata_content = String::from_utf8(data)
+                    .map_err(|_| AppError::validation("json", "Image metadata is not valid UTF-8"))?;
                 break;
             }
         }
         
         if !image_metadata_content.is_empty() {
             let zip_images: Vec<serde_json::Value> = serde_json::from_str(&image_metadata_content)
-                .map_err(|e| format!("Invalid image metadata format: {}", e))?;
+                .map_err(|e| AppError::validation("json", format!("Invalid image metadata format: {}", e)))?;
             
             // Import each image
             for zip_image in zip_images {
@@ -434,24 +1286,32 @@ pub async fn import_scoreboard_from_zip(
                     if let Some(original_name) = zip_image.get("name").and_then(|name| name.as_str()) {
                         // Generate new unique ID to avoid conflicts
                         let new_id = Uuid::new_v4().to_string();
-                        let file_extension = original_name.split('.').last().unwrap_or("png");
+                        // The name comes from the archive, so only a plain extension is carried over
+                        let file_extension = original_name.rsplit('.').next()
+                            .filter(|ext| !ext.is_empty() && ext.chars().all(|c| c.is_ascii_alphanumeric()))
+                            .unwrap_or("png");
                         let new_filename = format!("{}.{}", new_id, file_extension);
                         
                         // Find and extract the image file from ZIP
                         let zip_image_path = format!("images/{}", original_name);
                         for i in 0..archive.len() {
                             let mut file = archive.by_index(i)
-                                .map_err(|e| format!("Failed to read file from ZIP: {}", e))?;
+                                .map_err(|e| AppError::io(format!("Failed to read file from ZIP: {}", e)))?;
                             
                             if file.name() == zip_image_path {
-                                let mut image_data = Vec::new();
-                                file.read_to_end(&mut image_data)
-                                    .map_err(|e| format!("Failed to read image data: {}", e))?;
+                                let image_data = read_zip_entry(&mut file, &zip_image_path, limits.zip_import_max_entry_bytes)?;
+                                
+                                // Already stored by an earlier archive in this batch
+                                let hash = content_hash(&image_data);
+                                if let Some(existing_id) = image_cache.ids_by_hash.get(&hash) {
+                                    imported_image_mapping.insert(old_id.to_string(), existing_id.clone());
+                                    break;
+                                }
                                 
                                 // Save image to disk
-                                let new_image_path = images_dir.join(&new_filename);
+                                let new_image_path = safe_join(&images_dir, &new_filename)?;
                                 fs::write(&new_image_path, &image_data)
-                                    .map_err(|e| format!("Failed to save imported image: {}", e))?;
+                                    .map_err(|e| AppError::io(format!("Failed to save imported image: {}", e)))?;
                                 
                                 // Create new metadata entry
                                 let mut new_image_metadata = zip_image.clone();
@@ -463,6 +1323,7 @@ pub async fn import_scoreboard_from_zip(
                                 }
                                 
                                 existing_images.push(new_image_metadata);
+                                image_cache.ids_by_hash.insert(hash, new_id.clone());
                                 imported_image_mapping.insert(old_id.to_string(), new_id);
                                 break;
                             }
@@ -473,9 +1334,9 @@ pub async fn import_scoreboard_from_zip(
             
             // Save updated image metadata
             let updated_metadata = serde_json::to_string_pretty(&existing_images)
-                .map_err(|e| format!("Failed to serialize image metadata: {}", e))?;
-            fs::write(&metadata_file, updated_metadata)
-                .map_err(|e| format!("Failed to save updated image metadata: {}", e))?;
+                .map_err(|e| AppError::io(format!("Failed to serialize image metadata: {}", e)))?;
+            write_atomic(&metadata_file, updated_metadata.as_bytes()).await
+                .map_err(|e| AppError::io(format!("Failed to save updated image metadata: {}", e)))?;
         }
     }
     
@@ -495,15 +1356,16 @@ pub async fn import_scoreboard_from_zip(
     // Save the imported scoreboard
     if !scoreboards_dir.exists() {
         fs::create_dir_all(&scoreboards_dir)
-            .map_err(|e| format!("Failed to create scoreboards directory: {}", e))?;
+            .map_err(|e| AppError::io(format!("Failed to create scoreboards directory: {}", e)))?;
     }
     
-    let scoreboard_file = scoreboards_dir.join(&format!("{}.json", scoreboard_config.name));
+    let scoreboard_file = safe_join(&scoreboards_dir, &filename)?;
+    scoreboard_config.filename = filename;
     let updated_scoreboard_content = serde_json::to_string_pretty(&scoreboard_config)
-        .map_err(|e| format!("Failed to serialize updated scoreboard: {}", e))?;
+        .map_err(|e| AppError::io(format!("Failed to serialize updated scoreboard: {}", e)))?;
     
-    fs::write(&scoreboard_file, updated_scoreboard_content)
-        .map_err(|e| format!("Failed to save imported scoreboard: {}", e))?;
+    tokio::fs::write(&scoreboard_file, updated_scoreboard_content).await
+        .map_err(|e| AppError::io(format!("Failed to save imported scoreboard: {}", e)))?;
     
     Ok(scoreboard_config)
 }
@@ -512,19 +1374,28 @@ pub async fn import_scoreboard_from_zip(
 pub async fn import_scoreboard(
     app: AppHandle,
     import_path: String,
-) -> Result<ScoreboardConfig, String> {
-    let app_data_dir = app.path().app_data_dir()
-        .map_err(|e| e.to_string())?;
+) -> Result<ScoreboardConfig, AppError> {
+    ensure_unlocked("Importing scoreboards")?;
+    let app_data_dir = data_dir(&app)?;
     
     let import_path = PathBuf::from(import_path);
     
     if !import_path.exists() {
-        return Err("Import file not found".to_string());
+        return Err(AppError::not_found("Import file"));
+    }
+    // The path is chosen by the user, but don't let it be used to read arbitrary files
+    if !import_path.is_file() || !has_json_extension(&import_path) {
+        return Err(AppError::validation("import_path", "Import file must be a .json file"));
     }
     
-    let json_data = fs::read_to_string(&import_path).map_err(|e| e.to_string())?;
-    let mut config: ScoreboardConfig = serde_json::from_str(&json_data)
-        .map_err(|e| e.to_string())?;
+    let json_data = tokio::fs::read_to_string(&import_path).await?;
+    import_scoreboard_json(&app_data_dir, &json_data).await
+}
+
+// Saves an exported board under a new id, named after the board itself and
+// renamed like a ZIP import if that name is already taken
+async fn import_scoreboard_json(app_data_dir: &Path, json_data: &str) -> Result<ScoreboardConfig, AppError> {
+    let mut config: ScoreboardConfig = serde_json::from_str(json_data)?;
     
     // Generate new ID and update timestamps
     config.id = uuid::Uuid::new_v4().to_string();
@@ -533,20 +1404,131 @@ pub async fn import_scoreboard(
     
     // Save to app data directory
     let scoreboards_dir = app_data_dir.join("scoreboards");
-    fs::create_dir_all(&scoreboards_dir).map_err(|e| e.to_string())?;
+    tokio::fs::create_dir_all(&scoreboards_dir).await?;
     
-    let filename = format!("{}.json", sanitize_filename(&config.name));
-    let file_path = scoreboards_dir.join(&filename);
+    config.filename = import_target(&scoreboards_dir, &mut config.name, ImportConflictStrategy::Rename).await?;
+    let file_path = safe_join(&scoreboards_dir, &config.filename)?;
     
-    let json_data = serde_json::to_string_pretty(&config)
-        .map_err(|e| e.to_string())?;
+    let json_data = serde_json::to_string_pretty(&config)?;
     
-    fs::write(&file_path, json_data).map_err(|e| e.to_string())?;
+    ensure_free_space(&scoreboards_dir, json_data.len() as u64)?;
+    tokio::fs::write(&file_path, json_data).await?;
     
     Ok(config)
 }
 
-fn sanitize_filename(name: &str) -> String {
+async fn download_template(url: &str) -> Result<Vec<u8>, AppError> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(URL_IMPORT_TIMEOUT_SECS))
+        .build()
+        .map_err(|e| AppError::external("http", format!("Failed to create HTTP client: {}", e)))?;
+    let mut response = client.get(url).send().await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| AppError::external("http", format!("Failed to download template: {}", e)))?;
+
+    let too_large = || AppError::validation("url", format!("Template is larger than {} bytes", MAX_URL_IMPORT_BYTES));
+    if response.content_length().is_some_and(|length| length > MAX_URL_IMPORT_BYTES) {
+        return Err(too_large());
+    }
+    // The declared length can be missing or wrong, so count as it arrives too
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await
+        .map_err(|e| AppError::external("http", format!("Failed to download template: {}", e)))?
+    {
+        if (body.len() + chunk.len()) as u64 > MAX_URL_IMPORT_BYTES {
+            return Err(too_large());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+// Imports a board shared as a link, either an exported .json or a .zip. The
+// format comes from the bytes themselves, since servers often send these as
+// text/plain or application/octet-stream.
+#[tauri::command]
+pub async fn import_scoreboard_from_url(app: AppHandle, url: String) -> Result<ScoreboardConfig, AppError> {
+    ensure_unlocked("Importing scoreboards")?;
+    let parsed = url::Url::parse(url.trim())
+        .map_err(|e| AppError::validation("url", format!("Invalid URL: {}", e)))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(AppError::validation("url", "Template URL must use http or https"));
+    }
+
+    let body = download_template(parsed.as_str()).await?;
+    let config = match detect_mime(&body) {
+        "application/zip" => {
+            import_zip(&app, &body, &mut ImportedImageCache::default(), ImportConflictStrategy::Rename).await?
+        }
+        _ if body.trim_ascii_start().starts_with(b"{") => {
+            let json_data = String::from_utf8(body)
+                .map_err(|e| AppError::validation("url", format!("Template is not valid UTF-8: {}", e)))?;
+            import_scoreboard_json(&data_dir(&app)?, &json_data).await?
+        }
+        detected => return Err(AppError::unsupported_media_type(detected, "scoreboard JSON or ZIP")),
+    };
+
+    info!("Imported scoreboard '{}' from {}", config.name, parsed.host_str().unwrap_or("URL"));
+    Ok(config)
+}
+
+// Join a caller-supplied file name onto `base`, refusing anything that could
+// land outside it: separators of either platform (so no absolute paths, drive
+// letters or `..` segments), and existing symlinks that resolve elsewhere.
+pub(crate) fn safe_join(base: &Path, name: &str) -> Result<PathBuf, AppError> {
+    let invalid = |reason: &str| AppError::validation("filename", format!("Invalid file name '{}': {}", name, reason));
+
+    if name.trim().is_empty() {
+        return Err(invalid("name is empty"));
+    }
+    if name.contains(['/', '\\']) {
+        return Err(invalid("must not contain path separators"));
+    }
+    if name == "." || name == ".." {
+        return Err(invalid("must not refer to a directory"));
+    }
+    if name.contains([':', '\0']) {
+        return Err(invalid("must not contain ':' or NUL"));
+    }
+
+    let path = base.join(name);
+    // A symlink planted in the directory could still point outside it
+    if let (Ok(resolved), Ok(resolved_base)) = (path.canonicalize(), base.canonicalize()) {
+        if !resolved.starts_with(&resolved_base) {
+            return Err(invalid("resolves outside the storage directory"));
+        }
+    }
+    Ok(path)
+}
+
+fn has_json_extension(path: &Path) -> bool {
+    path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+const MAX_SCOREBOARD_NAME_CHARS: usize = 100;
+// Limits for `import_scoreboard_from_url`
+const MAX_URL_IMPORT_BYTES: u64 = 50 * 1024 * 1024;
+const URL_IMPORT_TIMEOUT_SECS: u64 = 30;
+
+// The display name shows up in lists, window titles and exports, so it has to
+// be a single non-empty line. Returns the trimmed name.
+pub(crate) fn validate_scoreboard_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::validation("name", "Scoreboard name cannot be empty"));
+    }
+    if name.chars().any(char::is_control) {
+        return Err(AppError::validation("name", "Scoreboard name cannot contain line breaks or control characters"));
+    }
+    if name.chars().count() > MAX_SCOREBOARD_NAME_CHARS {
+        return Err(AppError::validation("name", format!(
+            "Scoreboard name cannot be longer than {} characters", MAX_SCOREBOARD_NAME_CHARS
+        )));
+    }
+    Ok(name.to_string())
+}
+
+pub(crate) fn sanitize_filename(name: &str) -> String {
     name.chars()
         .map(|c| match c {
             'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
@@ -577,6 +1559,15 @@ pub struct LiveDataConnectionData {
     pub last_updated: Option<String>,
     #[serde(rename = "lastError")]
     pub last_error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transform: Option<crate::commands::message_transform::MessageTransform>,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default, rename = "updateMode")]
+    pub update_mode: crate::commands::live_data::CourtUpdateMode,
+    // Message types kept besides MATCH; empty for MATCH only
+    #[serde(default, rename = "messageTypes", skip_serializing_if = "Vec::is_empty")]
+    pub message_types: Vec<String>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -587,8 +1578,11 @@ pub struct LiveDataBinding {
     pub connection_id: String,
     #[serde(rename = "dataPath")]
     pub data_path: String,
+    // Milliseconds between refreshes of the bound value
     #[serde(rename = "updateInterval")]
     pub update_interval: Option<u32>,
+    #[serde(default)]
+    pub priority: crate::commands::binding_resolver::BindingPriority,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -599,36 +1593,42 @@ pub struct LiveDataState {
 }
 
 #[tauri::command]
-pub async fn save_live_data_connections(app: AppHandle, connections_data: LiveDataState) -> Result<(), String> {
-    use tauri::path::BaseDirectory;
+pub async fn save_live_data_connections(app: AppHandle, mut connections_data: LiveDataState) -> Result<(), AppError> {
+    for connection in &connections_data.connections {
+        check_poll_interval(&connection.provider, connection.poll_interval)?;
+    }
+    // Also encrypts any plaintext tokens left by older versions. A URL with a
+    // token in it is as much a secret as the token field.
+    for connection in &mut connections_data.connections {
+        connection.token = encrypt_secret(&connection.token);
+        if url_has_credentials(&connection.api_url) {
+            connection.api_url = encrypt_secret(&connection.api_url);
+        }
+    }
     
-    let app_data_dir = app.path().resolve("", BaseDirectory::AppData)
-        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+    let app_data_dir = data_dir(&app)?;
     let live_data_dir = app_data_dir.join("live_data");
     
     // Create live_data directory if it doesn't exist
     if !live_data_dir.exists() {
         fs::create_dir_all(&live_data_dir)
-            .map_err(|e| format!("Failed to create live_data directory: {}", e))?;
+            .map_err(|e| AppError::io(format!("Failed to create live_data directory: {}", e)))?;
     }
     
     let file_path = live_data_dir.join("connections.json");
     let json_data = serde_json::to_string_pretty(&connections_data)
-        .map_err(|e| format!("Failed to serialize live data connections: {}", e))?;
+        .map_err(|e| AppError::io(format!("Failed to serialize live data connections: {}", e)))?;
     
     fs::write(&file_path, json_data)
-        .map_err(|e| format!("Failed to write live data connections file: {}", e))?;
+        .map_err(|e| AppError::io(format!("Failed to write live data connections file: {}", e)))?;
     
-    println!("Live data connections saved to: {:?}", file_path);
+    debug!("Live data connections saved to: {:?}", file_path);
     Ok(())
 }
 
 #[tauri::command]
-pub async fn load_live_data_connections(app: AppHandle) -> Result<LiveDataState, String> {
-    use tauri::path::BaseDirectory;
-    
-    let app_data_dir = app.path().resolve("", BaseDirectory::AppData)
-        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+pub async fn load_live_data_connections(app: AppHandle) -> Result<LiveDataState, AppError> {
+    let app_data_dir = data_dir(&app)?;
     let file_path = app_data_dir.join("live_data").join("connections.json");
     
     if !file_path.exists() {
@@ -640,28 +1640,368 @@ pub async fn load_live_data_connections(app: AppHandle) -> Result<LiveDataState,
     }
     
     let json_data = fs::read_to_string(&file_path)
-        .map_err(|e| format!("Failed to read live data connections file: {}", e))?;
-    
-    let connections_data: LiveDataState = serde_json::from_str(&json_data)
-        .map_err(|e| format!("Failed to parse live data connections: {}", e))?;
+        .map_err(|e| AppError::io(format!("Failed to read live data connections file: {}", e)))?;
+    
+    let mut connections_data: LiveDataState = serde_json::from_str(&json_data)
+        .map_err(|e| AppError::validation("json", format!("Failed to parse live data connections: {}", e)))?;
+    
+    // A token or URL that can't be decrypted is cleared rather than failing the whole load
+    for connection in &mut connections_data.connections {
+        match decrypt_secret(&connection.token) {
+            Ok(token) => connection.token = token,
+            Err(e) => {
+                warn!("Could not decrypt token for connection {}: {}", connection.id, e);
+                connection.token = String::new();
+            }
+        }
+        match decrypt_secret(&connection.api_url) {
+            Ok(api_url) => connection.api_url = api_url,
+            Err(e) => {
+                warn!("Could not decrypt API URL for connection {}: {}", connection.id, e);
+                connection.api_url = String::new();
+            }
+        }
+    }
     
-    println!("Live data connections loaded from: {:?}", file_path);
+    debug!("Live data connections loaded from: {:?}", file_path);
     Ok(connections_data)
 }
 
 #[tauri::command]
-pub async fn delete_live_data_connections(app: AppHandle) -> Result<(), String> {
-    use tauri::path::BaseDirectory;
-    
-    let app_data_dir = app.path().resolve("", BaseDirectory::AppData)
-        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+pub async fn delete_live_data_connections(app: AppHandle) -> Result<(), AppError> {
+    let app_data_dir = data_dir(&app)?;
     let file_path = app_data_dir.join("live_data").join("connections.json");
     
     if file_path.exists() {
         fs::remove_file(&file_path)
-            .map_err(|e| format!("Failed to delete live data connections file: {}", e))?;
-        println!("Live data connections file deleted");
+            .map_err(|e| AppError::io(format!("Failed to delete live data connections file: {}", e)))?;
+        info!("Live data connections file deleted");
     }
     
     Ok(())
-} 
\ No newline at end of file
+} 
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StateDifference {
+    // Dotted path to the differing value, e.g. "settings.auto_save" or "components[2].position.x"
+    pub path: String,
+    // None when the value only exists on the other side
+    pub disk: Option<serde_json::Value>,
+    pub memory: Option<serde_json::Value>,
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() { key.to_string() } else { format!("{}.{}", path, key) }
+}
+
+// Leaf-level differences between two JSON values, appended to `out`
+fn diff_json(path: &str, disk: Option<&serde_json::Value>, memory: Option<&serde_json::Value>, out: &mut Vec<StateDifference>) {
+    use serde_json::Value;
+
+    match (disk, memory) {
+        (Some(Value::Object(disk)), Some(Value::Object(memory))) => {
+            let mut keys: Vec<&String> = disk.keys().chain(memory.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                diff_json(&child_path(path, key), disk.get(key), memory.get(key), out);
+            }
+        }
+        (Some(Value::Array(disk)), Some(Value::Array(memory))) => {
+            for index in 0..disk.len().max(memory.len()) {
+                diff_json(&format!("{}[{}]", path, index), disk.get(index), memory.get(index), out);
+            }
+        }
+        (disk, memory) if disk != memory => out.push(StateDifference {
+            path: path.to_string(),
+            disk: disk.cloned(),
+            memory: memory.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+fn diff_state<T: Serialize>(disk: &T, memory: &T) -> Result<Vec<StateDifference>, AppError> {
+    let disk = serde_json::to_value(disk)?;
+    let memory = serde_json::to_value(memory)?;
+    let mut differences = Vec::new();
+    diff_json("", Some(&disk), Some(&memory), &mut differences);
+    Ok(differences)
+}
+
+// Compare what's persisted for `state_type` with what's in memory. An empty
+// list means the last save captured the current state.
+#[tauri::command]
+pub async fn diff_state_vs_disk(app: AppHandle, state_type: String) -> Result<Vec<StateDifference>, AppError> {
+    let storage = &app.state::<ManagedStateStorage>().0;
+    match state_type.as_str() {
+        "app" => diff_state(&storage.load_app_state().map_err(AppError::io)?, &*app.state::<ManagedAppState>().0.lock()?),
+        "canvas" => diff_state(&storage.load_canvas_state().map_err(AppError::io)?, &*app.state::<ManagedCanvasState>().0.lock()?),
+        "image" => diff_state(&storage.load_image_state().map_err(AppError::io)?, &*app.state::<ManagedImageState>().0.lock()?),
+        "video" => diff_state(&storage.load_video_state().map_err(AppError::io)?, &*app.state::<ManagedVideoState>().0.lock()?),
+        "live_data" => diff_state(&storage.load_live_data_state().map_err(AppError::io)?, &*app.state::<ManagedLiveDataState>().0.lock()?),
+        "scoreboard" => diff_state(&storage.load_scoreboard_state().map_err(AppError::io)?, &*app.state::<ManagedScoreboardState>().0.lock()?),
+        other => Err(AppError::validation(
+            "state_type",
+            format!("Unknown state type '{}'; expected app, canvas, image, video, live_data or scoreboard", other),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svg_has_a_rect_and_text_per_component_in_z_order() {
+        let data = serde_json::json!({
+            "dimensions": { "width": 800, "height": 200 },
+            "components": [
+                { "id": "top", "type": "text", "zIndex": 3, "position": { "x": 10, "y": 10 }, "size": { "width": 100, "height": 40 }, "data": { "text": "Top" } },
+                { "id": "bottom", "type": "text", "zIndex": 1, "position": { "x": 0, "y": 0 }, "size": { "width": 800, "height": 200 }, "data": { "text": "Bottom" } },
+                { "id": "middle", "type": "text", "zIndex": 2, "position": { "x": 50, "y": 50 }, "size": { "width": 200, "height": 60 }, "data": { "text": "Middle & more" } },
+            ]
+        });
+        let svg = render_scoreboard_svg(&data, &std::collections::HashMap::new());
+
+        assert!(svg.starts_with("<svg") && svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<rect").count(), 3);
+        assert_eq!(svg.matches("<text").count(), 3);
+        let order: Vec<usize> = ["id=\"bottom\"", "id=\"middle\"", "id=\"top\""].iter()
+            .map(|id| svg.find(id).expect("component group"))
+            .collect();
+        assert!(order.windows(2).all(|pair| pair[0] < pair[1]), "{}", svg);
+        assert!(svg.contains(">Middle &amp; more</text>"));
+    }
+
+
+    #[test]
+    fn render_scale_for_a_1080p_board_in_a_4k_window() {
+        let scale = compute_render_scale(1920.0, 1080.0, 3840.0, 2160.0).unwrap();
+        assert_eq!(scale.scale, 2.0);
+        assert_eq!((scale.rendered_width, scale.rendered_height), (3840.0, 2160.0));
+        assert_eq!((scale.offset_x, scale.offset_y), (0.0, 0.0));
+        assert!(!scale.letterboxed && !scale.pillarboxed);
+    }
+
+    #[test]
+    fn render_scale_pillarboxes_a_wider_window() {
+        let scale = compute_render_scale(1920.0, 1080.0, 3840.0, 1600.0).unwrap();
+        assert!((scale.scale - 1600.0 / 1080.0).abs() < 1e-9);
+        assert!(scale.pillarboxed && !scale.letterboxed);
+        assert!((scale.offset_x * 2.0 + scale.rendered_width - 3840.0).abs() < 1e-9);
+        assert!(compute_render_scale(0.0, 1080.0, 3840.0, 2160.0).is_err());
+    }
+
+
+    #[test]
+    fn safe_join_rejects_traversal_and_separators() {
+        let base = std::env::temp_dir();
+        for name in ["..", ".", "../escape.json", "..\\escape.json", "/etc/passwd", "C:\\Windows\\win.ini", "C:evil.json", "nested/board.json", "", "  "] {
+            assert!(safe_join(&base, name).is_err(), "{:?} should be rejected", name);
+        }
+        assert_eq!(safe_join(&base, "board.json").unwrap(), base.join("board.json"));
+        assert_eq!(safe_join(&base, "..board.json").unwrap(), base.join("..board.json"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn safe_join_rejects_symlinks_out_of_the_directory() {
+        let base = std::env::temp_dir().join(format!("safe_join_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&base).unwrap();
+        std::os::unix::fs::symlink(std::env::temp_dir(), base.join("link.json")).unwrap();
+
+        assert!(safe_join(&base, "link.json").is_err());
+
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+
+    #[test]
+    fn scoreboard_names_are_single_trimmed_lines() {
+        assert_eq!(validate_scoreboard_name("  Center Court  ").unwrap(), "Center Court");
+        assert!(validate_scoreboard_name("").is_err());
+        assert!(validate_scoreboard_name(" \t ").is_err());
+        assert!(validate_scoreboard_name("Center\nCourt").is_err());
+        assert!(validate_scoreboard_name("Center\r\nCourt").is_err());
+        assert!(validate_scoreboard_name(&"x".repeat(MAX_SCOREBOARD_NAME_CHARS + 1)).is_err());
+        assert!(validate_scoreboard_name(&"é".repeat(MAX_SCOREBOARD_NAME_CHARS)).is_ok());
+    }
+
+
+    #[test]
+    fn exports_all_boards_to_a_zip_file_that_opens() {
+        let dir = std::env::temp_dir().join(format!("zip_export_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(dir.join("scoreboards")).unwrap();
+        fs::create_dir_all(dir.join("images")).unwrap();
+        let board = serde_json::json!({
+            "data": { "components": [{ "id": "logo", "data": { "imageId": "img-1" } }] }
+        });
+        fs::write(dir.join("scoreboards/court_1.json"), board.to_string()).unwrap();
+        fs::write(dir.join("scoreboards/court_2.json"), r#"{"data":{"components":[]}}"#).unwrap();
+        fs::write(dir.join("images/logo.png"), b"\x89PNG\r\n\x1a\n").unwrap();
+        fs::write(
+            dir.join("images/metadata.json"),
+            r#"[{"id":"img-1","name":"logo.png"},{"id":"unused","name":"other.png"}]"#,
+        ).unwrap();
+
+        let target = dir.join("export.zip");
+        let progress = std::cell::RefCell::new(Vec::new());
+        let summary = write_all_scoreboards_zip(&dir, &target, |event| {
+            progress.borrow_mut().push(event.added_files);
+        }).unwrap();
+
+        assert_eq!(summary.files, 4);
+        assert_eq!(progress.into_inner(), vec![1, 2, 3, 4]);
+        let mut archive = ZipArchive::new(fs::File::open(&target).unwrap()).unwrap();
+        let mut names: Vec<String> = archive.file_names().map(|name| name.to_string()).collect();
+        names.sort();
+        assert_eq!(names, [
+            "images/logo.png",
+            "images/metadata.json",
+            "scoreboards/court_1.json",
+            "scoreboards/court_2.json",
+        ]);
+        let mut metadata = String::new();
+        archive.by_name("images/metadata.json").unwrap().read_to_string(&mut metadata).unwrap();
+        assert!(!metadata.contains("unused"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+
+    // Serves one canned HTTP response per connection; returns the base URL
+    async fn mock_server(response: Vec<u8>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut request = [0u8; 4096];
+                let _ = socket.read(&mut request).await;
+                let _ = socket.write_all(&response).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn imports_a_board_served_over_http() {
+        let board = serde_json::json!({
+            "id": "original-id",
+            "name": "Shared Court",
+            "data": { "components": [] },
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }).to_string();
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            board.len(), board
+        );
+        let base = mock_server(response.into_bytes()).await;
+
+        let body = download_template(&format!("{}/boards/shared.json", base)).await.unwrap();
+        assert_eq!(detect_mime(&body), "text/plain");
+
+        let dir = std::env::temp_dir().join(format!("url_import_{}", uuid::Uuid::new_v4()));
+        let config = import_scoreboard_json(&dir, std::str::from_utf8(&body).unwrap()).await.unwrap();
+        assert_eq!(config.name, "Shared Court");
+        assert_ne!(config.id, "original-id");
+        let saved: ScoreboardConfig = serde_json::from_str(
+            &fs::read_to_string(dir.join("scoreboards").join(&config.filename)).unwrap()
+        ).unwrap();
+        assert_eq!(saved.id, config.id);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn oversized_and_failed_downloads_are_rejected() {
+        let oversized = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            MAX_URL_IMPORT_BYTES + 1
+        );
+        let base = mock_server(oversized.into_bytes()).await;
+        assert!(matches!(download_template(&base).await, Err(AppError::Validation { .. })));
+
+        let base = mock_server(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_vec()).await;
+        assert!(matches!(download_template(&base).await, Err(AppError::External { .. })));
+    }
+
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn listing_does_not_stall_behind_a_large_export() {
+        let dir = std::env::temp_dir().join(format!("export_stress_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(dir.join("scoreboards")).unwrap();
+        fs::create_dir_all(dir.join("images")).unwrap();
+
+        // Incompressible, so deflating it keeps the export busy
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let image: Vec<u8> = (0..16 * 1024 * 1024)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed as u8
+            })
+            .collect();
+        let image_path = dir.join("images/big.png");
+        fs::write(&image_path, &image).unwrap();
+        fs::write(
+            dir.join("images/metadata.json"),
+            serde_json::json!([{ "id": "big", "path": image_path }]).to_string(),
+        ).unwrap();
+        for i in 0..50 {
+            let board = serde_json::json!({
+                "id": format!("board-{}", i),
+                "name": format!("Court {}", i),
+                "filename": format!("court_{}.json", i),
+                "data": { "components": [{ "id": "logo", "data": { "imageId": "big" } }] },
+                "created_at": "2024-01-01T00:00:00Z",
+                "updated_at": "2024-01-01T00:00:00Z"
+            });
+            fs::write(dir.join(format!("scoreboards/court_{}.json", i)), board.to_string()).unwrap();
+        }
+
+        let export_dir = dir.clone();
+        let export = tokio::task::spawn_blocking(move || build_scoreboard_zip(&export_dir, "court_0.json"));
+
+        let listings: Vec<_> = (0..12)
+            .map(|_| {
+                let scoreboards_dir = dir.join("scoreboards");
+                tokio::spawn(async move {
+                    let started = std::time::Instant::now();
+                    let boards = read_scoreboards(&scoreboards_dir).await.unwrap();
+                    (boards.len(), started.elapsed())
+                })
+            })
+            .collect();
+        for listing in listings {
+            let (count, took) = listing.await.unwrap();
+            assert_eq!(count, 50);
+            assert!(took < Duration::from_secs(2), "listing took {:?}", took);
+        }
+
+        let zip_data = export.await.unwrap().unwrap();
+        let archive = ZipArchive::new(std::io::Cursor::new(zip_data)).unwrap();
+        assert!(archive.file_names().any(|name| name == "images/big.png"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn diff_json_reports_changed_added_and_removed_leaves() {
+        let disk = serde_json::json!({ "zoom": 1.0, "pan": { "x": 0, "y": 0 }, "selected": ["a"] });
+        let memory = serde_json::json!({ "zoom": 1.5, "pan": { "x": 0, "y": 0 }, "selected": ["a", "b"], "hovered": "b" });
+        let mut differences = Vec::new();
+        diff_json("", Some(&disk), Some(&memory), &mut differences);
+
+        let paths: Vec<&str> = differences.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(paths, ["hovered", "selected[1]", "zoom"]);
+        assert_eq!(differences[0].disk, None);
+        assert_eq!(differences[2].memory, Some(serde_json::json!(1.5)));
+    }
+}
@@ -1,7 +1,9 @@
 // src-tauri/src/commands/storage_commands.rs
 use crate::state::*;
 use crate::storage::StateStorage;
-use tauri::{command, AppHandle, State, Emitter};
+use crate::commands::events::BackendEvent;
+use tauri::{command, AppHandle, Emitter, Manager, State};
+use tracing::{error, warn};
 
 // Managed state for the storage layer
 pub struct ManagedStateStorage(pub StateStorage);
@@ -15,7 +17,7 @@ pub async fn save_app_state(
 ) -> Result<(), String> {
     let app_state = state.0.lock()
         .map_err(|e| format!("Failed to lock app state: {}", e))?;
-    storage.0.save_app_state(&*app_state)?;
+    storage.0.save_app_state(&app_state)?;
     Ok(())
 }
 
@@ -38,7 +40,7 @@ pub async fn save_canvas_state(
 ) -> Result<(), String> {
     let canvas_state = state.0.lock()
         .map_err(|e| format!("Failed to lock canvas state: {}", e))?;
-    storage.0.save_canvas_state(&*canvas_state)?;
+    storage.0.save_canvas_state(&canvas_state)?;
     Ok(())
 }
 
@@ -61,7 +63,7 @@ pub async fn save_image_state(
 ) -> Result<(), String> {
     let image_state = state.0.lock()
         .map_err(|e| format!("Failed to lock image state: {}", e))?;
-    storage.0.save_image_state(&*image_state)?;
+    storage.0.save_image_state(&image_state)?;
     Ok(())
 }
 
@@ -84,7 +86,7 @@ pub async fn save_video_state(
 ) -> Result<(), String> {
     let video_state = state.0.lock()
         .map_err(|e| format!("Failed to lock video state: {}", e))?;
-    storage.0.save_video_state(&*video_state)?;
+    storage.0.save_video_state(&video_state)?;
     Ok(())
 }
 
@@ -107,7 +109,7 @@ pub async fn save_live_data_state(
 ) -> Result<(), String> {
     let live_data_state = state.0.lock()
         .map_err(|e| format!("Failed to lock live data state: {}", e))?;
-    storage.0.save_live_data_state(&*live_data_state)?;
+    storage.0.save_live_data_state(&live_data_state)?;
     Ok(())
 }
 
@@ -128,9 +130,10 @@ pub async fn save_scoreboard_state(
     state: State<'_, ManagedScoreboardState>,
     storage: State<'_, ManagedStateStorage>
 ) -> Result<(), String> {
-    let scoreboard_state = state.0.lock()
+    let mut scoreboard_state = state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
-    storage.0.save_scoreboard_state(&*scoreboard_state)?;
+    storage.0.save_scoreboard_state(&scoreboard_state)?;
+    scoreboard_state.is_dirty = false;
     Ok(())
 }
 
@@ -166,10 +169,11 @@ pub async fn save_all_states(
         .map_err(|e| format!("Failed to lock video state: {}", e))?;
     let live_data = live_data_state.0.lock()
         .map_err(|e| format!("Failed to lock live data state: {}", e))?;
-    let scoreboard = scoreboard_state.0.lock()
+    let mut scoreboard = scoreboard_state.0.lock()
         .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?;
 
-    storage.0.save_all_states(&*app, &*canvas, &*image, &*video, &*live_data, &*scoreboard)?;
+    storage.0.save_all_states(&app, &canvas, &image, &video, &live_data, &scoreboard)?;
+    scoreboard.is_dirty = false;
     Ok(())
 }
 
@@ -235,34 +239,144 @@ pub async fn clear_old_state_backups(
     Ok(())
 }
 
-// ==================== AUTO-SAVE SETUP ====================
+// ==================== UNSAVED CHANGES ====================
 
-pub fn setup_auto_save(
-    app_handle: &AppHandle,
-    storage: &StateStorage,
-    interval_seconds: u64,
-) -> Result<(), String> {
-    let app_handle = app_handle.clone();
-    let storage = storage.clone();
+#[derive(serde::Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsavedChanges {
+    pub has_unsaved_changes: bool,
+    // The designer's own flag, set by every component edit
+    pub scoreboard_dirty: bool,
+    // State types whose in-memory fingerprint differs from the saved file
+    pub changed_states: Vec<String>,
+}
+
+fn fingerprint<T: serde::Serialize>(value: &T) -> Result<String, String> {
+    use sha2::{Digest, Sha256};
 
-    std::thread::spawn(move || {
-        let rt = tokio::runtime::Runtime::new().unwrap();
-        rt.block_on(async {
-            let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_seconds));
+    let bytes = serde_json::to_vec(value)
+        .map_err(|e| format!("Failed to serialize state: {}", e))?;
+    Ok(Sha256::digest(&bytes).iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+fn differs<T: serde::Serialize>(disk: &T, memory: &T) -> Result<bool, String> {
+    Ok(fingerprint(disk)? != fingerprint(memory)?)
+}
 
-            loop {
-                interval.tick().await;
+// Shared by the command and the close handler, which has no `State` arguments
+pub fn unsaved_changes(app: &AppHandle) -> Result<UnsavedChanges, String> {
+    let storage = &app.state::<ManagedStateStorage>().0;
+    let mut changed_states = Vec::new();
 
-                // Get all current states and save them
-                // Note: This is a simplified version. In production, you'd want to
-                // emit events to the main thread to handle the state access safely
-                let _ = app_handle.emit("request_state_save", ());
+    if differs(&storage.load_app_state()?, &*app.state::<ManagedAppState>().0.lock()
+        .map_err(|e| format!("Failed to lock app state: {}", e))?)? {
+        changed_states.push("app".to_string());
+    }
+    if differs(&storage.load_canvas_state()?, &*app.state::<ManagedCanvasState>().0.lock()
+        .map_err(|e| format!("Failed to lock canvas state: {}", e))?)? {
+        changed_states.push("canvas".to_string());
+    }
+    if differs(&storage.load_image_state()?, &*app.state::<ManagedImageState>().0.lock()
+        .map_err(|e| format!("Failed to lock image state: {}", e))?)? {
+        changed_states.push("image".to_string());
+    }
+    if differs(&storage.load_video_state()?, &*app.state::<ManagedVideoState>().0.lock()
+        .map_err(|e| format!("Failed to lock video state: {}", e))?)? {
+        changed_states.push("video".to_string());
+    }
+    if differs(&storage.load_live_data_state()?, &*app.state::<ManagedLiveDataState>().0.lock()
+        .map_err(|e| format!("Failed to lock live data state: {}", e))?)? {
+        changed_states.push("live_data".to_string());
+    }
+
+    let disk = storage.load_scoreboard_state()?;
+    let memory = app.state::<ManagedScoreboardState>().0.lock()
+        .map_err(|e| format!("Failed to lock scoreboard state: {}", e))?
+        .clone();
+    scoreboard_unsaved_changes(disk, memory, changed_states)
+}
+
+// Adds the scoreboard's own dirty flag and fingerprint to the other state types' changes
+fn scoreboard_unsaved_changes(
+    mut disk: ScoreboardState,
+    mut memory: ScoreboardState,
+    mut changed_states: Vec<String>,
+) -> Result<UnsavedChanges, String> {
+    // The dirty flag itself is saved too, so it's left out of the comparison
+    let scoreboard_dirty = memory.is_dirty;
+    disk.is_dirty = false;
+    memory.is_dirty = false;
+    if differs(&disk, &memory)? {
+        changed_states.push("scoreboard".to_string());
+    }
+
+    Ok(UnsavedChanges {
+        has_unsaved_changes: scoreboard_dirty || !changed_states.is_empty(),
+        scoreboard_dirty,
+        changed_states,
+    })
+}
 
-                // Small delay to allow the main thread to handle the event
-                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+#[command]
+pub async fn has_unsaved_changes(app: AppHandle) -> Result<UnsavedChanges, String> {
+    unsaved_changes(&app)
+}
+
+// For `on_window_event`: holds a close back while anything is unsaved and
+// emits `unsaved_changes_on_close` so the frontend can ask the operator.
+// Closing after that goes through `destroy()`, which doesn't come back here.
+pub fn handle_close_requested(window: &tauri::Window, api: &tauri::CloseRequestApi) {
+    match unsaved_changes(window.app_handle()) {
+        Ok(changes) if changes.has_unsaved_changes => {
+            api.prevent_close();
+            if let Err(e) = window.emit(BackendEvent::UnsavedChangesOnClose.as_str(), &changes) {
+                error!("Failed to emit {}: {}", BackendEvent::UnsavedChangesOnClose, e);
             }
-        });
-    });
+        }
+        Ok(_) => {}
+        // Better to let the window close than trap the operator on an error
+        Err(e) => warn!("Could not check for unsaved changes: {}", e),
+    }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // What save_scoreboard_state leaves behind: the file matches memory and the flag is cleared
+    fn save(memory: &mut ScoreboardState) -> ScoreboardState {
+        let json = serde_json::to_string(&*memory).unwrap();
+        memory.is_dirty = false;
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn dirtying_sets_the_flag_and_saving_clears_it() {
+        let mut memory = ScoreboardState::default();
+        let disk = save(&mut memory);
+        assert!(!scoreboard_unsaved_changes(disk.clone(), memory.clone(), Vec::new()).unwrap().has_unsaved_changes);
+
+        memory.selected_template = Some("tennis".to_string());
+        memory.is_dirty = true;
+        let changes = scoreboard_unsaved_changes(disk, memory.clone(), Vec::new()).unwrap();
+        assert!(changes.has_unsaved_changes);
+        assert!(changes.scoreboard_dirty);
+        assert_eq!(changes.changed_states, ["scoreboard"]);
+
+        let disk = save(&mut memory);
+        let changes = scoreboard_unsaved_changes(disk, memory, Vec::new()).unwrap();
+        assert!(!changes.has_unsaved_changes);
+        assert!(changes.changed_states.is_empty());
+    }
+
+    #[test]
+    fn other_changed_states_count_as_unsaved() {
+        let changes = scoreboard_unsaved_changes(
+            ScoreboardState::default(),
+            ScoreboardState::default(),
+            vec!["canvas".to_string()],
+        ).unwrap();
+        assert!(changes.has_unsaved_changes);
+        assert!(!changes.scoreboard_dirty);
+    }
 }
@@ -1,10 +1,11 @@
 // src-tauri/src/commands/tennis_processor.rs
 use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
 use std::collections::HashMap;
 use tauri::command;
 
 // Data structures for tennis match processing
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RawTennisData {
     pub id: Option<String>,
     pub match_id: Option<String>,
@@ -15,46 +16,58 @@ pub struct RawTennisData {
     pub score: Option<RawScoreData>,
     pub sets: Option<HashMap<String, RawSetData>>,
     pub serving_player: Option<i32>,
-    pub servingPlayer: Option<i32>,
+    #[serde(rename = "servingPlayer")]
+    pub legacy_serving_player: Option<i32>,
     pub current_set: Option<i32>,
-    pub currentSet: Option<i32>,
+    #[serde(rename = "currentSet")]
+    pub legacy_current_set: Option<i32>,
     pub is_tiebreak: Option<bool>,
-    pub isTiebreak: Option<bool>,
+    #[serde(rename = "isTiebreak")]
+    pub legacy_is_tiebreak: Option<bool>,
     pub match_status: Option<String>,
-    pub matchStatus: Option<String>,
+    #[serde(rename = "matchStatus")]
+    pub legacy_match_status: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RawPlayerData {
     pub name: Option<String>,
     pub country: Option<String>,
     pub seed: Option<i32>,
+    // Doubles partner; its presence on either side marks the match as doubles
+    pub partner: Option<Box<RawPlayerData>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RawScoreData {
     pub player1_sets: Option<i32>,
-    pub player1Sets: Option<i32>,
+    #[serde(rename = "player1Sets")]
+    pub legacy_player1_sets: Option<i32>,
     pub player2_sets: Option<i32>,
-    pub player2Sets: Option<i32>,
+    #[serde(rename = "player2Sets")]
+    pub legacy_player2_sets: Option<i32>,
     pub player1_games: Option<i32>,
-    pub player1Games: Option<i32>,
+    #[serde(rename = "player1Games")]
+    pub legacy_player1_games: Option<i32>,
     pub player2_games: Option<i32>,
-    pub player2Games: Option<i32>,
+    #[serde(rename = "player2Games")]
+    pub legacy_player2_games: Option<i32>,
     pub player1_points: Option<String>,
-    pub player1Points: Option<String>,
+    #[serde(rename = "player1Points")]
+    pub legacy_player1_points: Option<String>,
     pub player2_points: Option<String>,
-    pub player2Points: Option<String>,
+    #[serde(rename = "player2Points")]
+    pub legacy_player2_points: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RawSetData {
     pub player1: Option<i32>,
     pub player2: Option<i32>,
 }
 
 // Processed data structures
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProcessedTennisMatch {
     pub match_id: String,
     pub player1: ProcessedPlayerData,
@@ -65,21 +78,47 @@ pub struct ProcessedTennisMatch {
     pub current_set: i32,
     pub is_tiebreak: bool,
     pub match_status: String,
+    // "singles" or "doubles"; in doubles, player1/player2 describe the teams
+    pub match_type: String,
+    pub doubles_players: Option<ProcessedDoublesPlayers>,
     // Legacy properties for compatibility
-    pub servingPlayer: i32,
-    pub currentSet: i32,
-    pub isTiebreak: bool,
-    pub matchStatus: String,
+    #[serde(rename = "servingPlayer")]
+    pub legacy_serving_player: i32,
+    #[serde(rename = "currentSet")]
+    pub legacy_current_set: i32,
+    #[serde(rename = "isTiebreak")]
+    pub legacy_is_tiebreak: bool,
+    #[serde(rename = "matchStatus")]
+    pub legacy_match_status: String,
+    #[serde(rename = "matchType")]
+    pub legacy_match_type: String,
+    #[serde(rename = "doublesPlayers")]
+    pub legacy_doubles_players: Option<ProcessedDoublesPlayers>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessedTeamPlayers {
+    pub player1: ProcessedPlayerData,
+    pub player2: ProcessedPlayerData,
+}
+
+// Serving player numbers 1-2 are team1's players and 3-4 are team2's
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ProcessedDoublesPlayers {
+    pub team1: ProcessedTeamPlayers,
+    pub team2: ProcessedTeamPlayers,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProcessedPlayerData {
     pub name: String,
     pub country: Option<String>,
+    // Country exactly as the feed sent it, before normalization
+    pub original_country: Option<String>,
     pub seed: Option<i32>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProcessedScoreData {
     // New property names
     pub player1_sets: i32,
@@ -89,20 +128,102 @@ pub struct ProcessedScoreData {
     pub player1_points: String,
     pub player2_points: String,
     // Legacy property names for compatibility
-    pub player1Sets: i32,
-    pub player2Sets: i32,
-    pub player1Games: i32,
-    pub player2Games: i32,
-    pub player1Points: String,
-    pub player2Points: String,
+    #[serde(rename = "player1Sets")]
+    pub legacy_player1_sets: i32,
+    #[serde(rename = "player2Sets")]
+    pub legacy_player2_sets: i32,
+    #[serde(rename = "player1Games")]
+    pub legacy_player1_games: i32,
+    #[serde(rename = "player2Games")]
+    pub legacy_player2_games: i32,
+    #[serde(rename = "player1Points")]
+    pub legacy_player1_points: String,
+    #[serde(rename = "player2Points")]
+    pub legacy_player2_points: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ProcessedSetData {
     pub player1: i32,
     pub player2: i32,
 }
 
+// One problem found by validate_tennis_data_strict; `field` names the feed field involved
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TennisValidationWarning {
+    pub field: String,
+    pub message: String,
+}
+
+// Who (1 or 2) would win the game, set or match by winning the current point.
+// Each is None when the point doesn't decide it for either side.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CriticalPoints {
+    pub game_point: Option<i32>,
+    // Game point for the receiving side
+    pub break_point: Option<i32>,
+    pub set_point: Option<i32>,
+    pub match_point: Option<i32>,
+    pub deuce: bool,
+}
+
+const DEFAULT_BEST_OF: i32 = 3;
+const TIEBREAK_POINTS_TO_WIN: i32 = 7;
+
+// Point values a standard (non-tiebreak) game can show, after normalize_points
+const GAME_POINT_VALUES: [&str; 5] = ["0", "15", "30", "40", "AD"];
+// Best of five is the longest format played
+const MAX_SETS_TO_WIN: i32 = 3;
+
+// Country variants seen in feeds (ISO alpha-2, IOC codes, English names) mapped
+// to ISO 3166 alpha-3. Keys are uppercase with whitespace collapsed.
+const COUNTRY_ALIASES: &[(&str, &str)] = &[
+    ("US", "USA"), ("UNITED STATES", "USA"), ("UNITED STATES OF AMERICA", "USA"), ("U.S.A.", "USA"), ("U.S.", "USA"),
+    ("GB", "GBR"), ("UK", "GBR"), ("UNITED KINGDOM", "GBR"), ("GREAT BRITAIN", "GBR"), ("ENGLAND", "GBR"),
+    ("CA", "CAN"), ("CANADA", "CAN"),
+    ("MX", "MEX"), ("MEXICO", "MEX"),
+    ("AU", "AUS"), ("AUSTRALIA", "AUS"),
+    ("NZ", "NZL"), ("NEW ZEALAND", "NZL"),
+    ("FR", "FRA"), ("FRANCE", "FRA"),
+    ("DE", "DEU"), ("GER", "DEU"), ("GERMANY", "DEU"),
+    ("ES", "ESP"), ("SPAIN", "ESP"),
+    ("IT", "ITA"), ("ITALY", "ITA"),
+    ("PT", "PRT"), ("POR", "PRT"), ("PORTUGAL", "PRT"),
+    ("NL", "NLD"), ("NED", "NLD"), ("NETHERLANDS", "NLD"), ("HOLLAND", "NLD"),
+    ("BE", "BEL"), ("BELGIUM", "BEL"),
+    ("CH", "CHE"), ("SUI", "CHE"), ("SWITZERLAND", "CHE"),
+    ("AT", "AUT"), ("AUSTRIA", "AUT"),
+    ("SE", "SWE"), ("SWEDEN", "SWE"),
+    ("NO", "NOR"), ("NORWAY", "NOR"),
+    ("DK", "DNK"), ("DEN", "DNK"), ("DENMARK", "DNK"),
+    ("FI", "FIN"), ("FINLAND", "FIN"),
+    ("PL", "POL"), ("POLAND", "POL"),
+    ("CZ", "CZE"), ("CZECHIA", "CZE"), ("CZECH REPUBLIC", "CZE"),
+    ("SK", "SVK"), ("SLOVAKIA", "SVK"),
+    ("HU", "HUN"), ("HUNGARY", "HUN"),
+    ("HR", "HRV"), ("CRO", "HRV"), ("CROATIA", "HRV"),
+    ("RS", "SRB"), ("SERBIA", "SRB"),
+    ("SI", "SVN"), ("SLO", "SVN"), ("SLOVENIA", "SVN"),
+    ("BG", "BGR"), ("BUL", "BGR"), ("BULGARIA", "BGR"),
+    ("RO", "ROU"), ("ROMANIA", "ROU"),
+    ("GR", "GRC"), ("GRE", "GRC"), ("GREECE", "GRC"),
+    ("RU", "RUS"), ("RUSSIA", "RUS"),
+    ("UA", "UKR"), ("UKRAINE", "UKR"),
+    ("BY", "BLR"), ("BELARUS", "BLR"),
+    ("KZ", "KAZ"), ("KAZAKHSTAN", "KAZ"),
+    ("AR", "ARG"), ("ARGENTINA", "ARG"),
+    ("BR", "BRA"), ("BRAZIL", "BRA"),
+    ("CL", "CHL"), ("CHI", "CHL"), ("CHILE", "CHL"),
+    ("CO", "COL"), ("COLOMBIA", "COL"),
+    ("JP", "JPN"), ("JAPAN", "JPN"),
+    ("CN", "CHN"), ("CHINA", "CHN"),
+    ("KR", "KOR"), ("SOUTH KOREA", "KOR"), ("KOREA", "KOR"),
+    ("IN", "IND"), ("INDIA", "IND"),
+    ("ZA", "ZAF"), ("RSA", "ZAF"), ("SOUTH AFRICA", "ZAF"),
+    ("TN", "TUN"), ("TUNISIA", "TUN"),
+];
+
 // Tennis data processor
 pub struct TennisDataProcessor;
 
@@ -115,14 +236,27 @@ impl TennisDataProcessor {
             .unwrap_or_else(|| "unknown".to_string());
 
         // Process player data
-        let player1 = Self::process_player_data(
-            raw_data.player1.or(raw_data.team1),
-            "Player 1"
-        );
-        let player2 = Self::process_player_data(
-            raw_data.player2.or(raw_data.team2),
-            "Player 2"
-        );
+        let side1 = raw_data.player1.or(raw_data.team1);
+        let side2 = raw_data.player2.or(raw_data.team2);
+        let is_doubles = [&side1, &side2].iter()
+            .any(|side| side.as_ref().is_some_and(|player| player.partner.is_some()));
+
+        let (player1, player2, doubles_players) = if is_doubles {
+            let team1 = Self::process_team_data(side1, ["Player 1", "Player 2"]);
+            let team2 = Self::process_team_data(side2, ["Player 3", "Player 4"]);
+            let doubles_players = ProcessedDoublesPlayers {
+                team1: team1.clone(),
+                team2: team2.clone(),
+            };
+            (Self::team_summary(&team1), Self::team_summary(&team2), Some(doubles_players))
+        } else {
+            (
+                Self::process_player_data(side1, "Player 1"),
+                Self::process_player_data(side2, "Player 2"),
+                None,
+            )
+        };
+        let match_type = if is_doubles { "doubles" } else { "singles" }.to_string();
 
         // Process score data
         let score = Self::process_score_data(raw_data.score);
@@ -132,12 +266,12 @@ impl TennisDataProcessor {
 
         // Extract serving and match state information
         let serving_player = Self::normalize_serving_player(
-            raw_data.serving_player.or(raw_data.servingPlayer)
+            raw_data.serving_player.or(raw_data.legacy_serving_player)
         );
-        let current_set = raw_data.current_set.or(raw_data.currentSet).unwrap_or(1);
-        let is_tiebreak = raw_data.is_tiebreak.or(raw_data.isTiebreak).unwrap_or(false);
+        let current_set = raw_data.current_set.or(raw_data.legacy_current_set).unwrap_or(1);
+        let is_tiebreak = raw_data.is_tiebreak.or(raw_data.legacy_is_tiebreak).unwrap_or(false);
         let match_status = raw_data.match_status
-            .or(raw_data.matchStatus)
+            .or(raw_data.legacy_match_status)
             .unwrap_or_else(|| "in_progress".to_string());
 
         Ok(ProcessedTennisMatch {
@@ -150,24 +284,51 @@ impl TennisDataProcessor {
             current_set,
             is_tiebreak,
             match_status: match_status.clone(),
+            match_type: match_type.clone(),
+            doubles_players: doubles_players.clone(),
             // Legacy properties
-            servingPlayer: serving_player,
-            currentSet: current_set,
-            isTiebreak: is_tiebreak,
-            matchStatus: match_status,
+            legacy_serving_player: serving_player,
+            legacy_current_set: current_set,
+            legacy_is_tiebreak: is_tiebreak,
+            legacy_match_status: match_status,
+            legacy_match_type: match_type,
+            legacy_doubles_players: doubles_players,
         })
     }
 
+    fn process_team_data(raw_player: Option<RawPlayerData>, default_names: [&str; 2]) -> ProcessedTeamPlayers {
+        let partner = raw_player.as_ref()
+            .and_then(|player| player.partner.as_deref())
+            .cloned();
+        ProcessedTeamPlayers {
+            player1: Self::process_player_data(raw_player, default_names[0]),
+            player2: Self::process_player_data(partner, default_names[1]),
+        }
+    }
+
+    // Singles-style entry for a doubles team, e.g. "Smith / Jones", so
+    // displays that only read player1/player2 still show both names
+    fn team_summary(team: &ProcessedTeamPlayers) -> ProcessedPlayerData {
+        ProcessedPlayerData {
+            name: format!("{} / {}", team.player1.name, team.player2.name),
+            country: team.player1.country.clone(),
+            original_country: team.player1.original_country.clone(),
+            seed: team.player1.seed,
+        }
+    }
+
     fn process_player_data(raw_player: Option<RawPlayerData>, default_name: &str) -> ProcessedPlayerData {
         match raw_player {
             Some(player) => ProcessedPlayerData {
                 name: player.name.unwrap_or_else(|| default_name.to_string()),
-                country: player.country,
+                country: player.country.as_deref().map(Self::normalize_country),
+                original_country: player.country,
                 seed: player.seed,
             },
             None => ProcessedPlayerData {
                 name: default_name.to_string(),
                 country: None,
+                original_country: None,
                 seed: None,
             }
         }
@@ -176,34 +337,34 @@ impl TennisDataProcessor {
     fn process_score_data(raw_score: Option<RawScoreData>) -> ProcessedScoreData {
         let default_score = RawScoreData {
             player1_sets: Some(0),
-            player1Sets: Some(0),
+            legacy_player1_sets: Some(0),
             player2_sets: Some(0),
-            player2Sets: Some(0),
+            legacy_player2_sets: Some(0),
             player1_games: Some(0),
-            player1Games: Some(0),
+            legacy_player1_games: Some(0),
             player2_games: Some(0),
-            player2Games: Some(0),
+            legacy_player2_games: Some(0),
             player1_points: Some("0".to_string()),
-            player1Points: Some("0".to_string()),
+            legacy_player1_points: Some("0".to_string()),
             player2_points: Some("0".to_string()),
-            player2Points: Some("0".to_string()),
+            legacy_player2_points: Some("0".to_string()),
         };
 
         let score = raw_score.unwrap_or(default_score);
 
-        let player1_sets = score.player1_sets.or(score.player1Sets).unwrap_or(0);
-        let player2_sets = score.player2_sets.or(score.player2Sets).unwrap_or(0);
-        let player1_games = score.player1_games.or(score.player1Games).unwrap_or(0);
-        let player2_games = score.player2_games.or(score.player2Games).unwrap_or(0);
+        let player1_sets = score.player1_sets.or(score.legacy_player1_sets).unwrap_or(0);
+        let player2_sets = score.player2_sets.or(score.legacy_player2_sets).unwrap_or(0);
+        let player1_games = score.player1_games.or(score.legacy_player1_games).unwrap_or(0);
+        let player2_games = score.player2_games.or(score.legacy_player2_games).unwrap_or(0);
         let player1_points = Self::normalize_points(
             score.player1_points.as_ref()
-                .or(score.player1Points.as_ref())
+                .or(score.legacy_player1_points.as_ref())
                 .map(|s| s.as_str())
                 .unwrap_or("0")
         );
         let player2_points = Self::normalize_points(
             score.player2_points.as_ref()
-                .or(score.player2Points.as_ref())
+                .or(score.legacy_player2_points.as_ref())
                 .map(|s| s.as_str())
                 .unwrap_or("0")
         );
@@ -216,12 +377,12 @@ impl TennisDataProcessor {
             player1_points: player1_points.clone(),
             player2_points: player2_points.clone(),
             // Legacy properties
-            player1Sets: player1_sets,
-            player2Sets: player2_sets,
-            player1Games: player1_games,
-            player2Games: player2_games,
-            player1Points: player1_points,
-            player2Points: player2_points,
+            legacy_player1_sets: player1_sets,
+            legacy_player2_sets: player2_sets,
+            legacy_player1_games: player1_games,
+            legacy_player2_games: player2_games,
+            legacy_player1_points: player1_points,
+            legacy_player2_points: player2_points,
         }
     }
 
@@ -249,9 +410,221 @@ impl TennisDataProcessor {
         }
     }
 
+    /// Map a feed's country representation to its ISO 3166 alpha-3 code,
+    /// leaving values we don't recognize untouched
+    pub fn normalize_country(country: &str) -> String {
+        let key = country
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_uppercase();
+
+        if let Some((_, code)) = COUNTRY_ALIASES.iter().find(|(alias, _)| *alias == key) {
+            return code.to_string();
+        }
+
+        if COUNTRY_ALIASES.iter().any(|(_, code)| *code == key) {
+            return key;
+        }
+
+        country.to_string()
+    }
+
     fn normalize_serving_player(serving_player: Option<i32>) -> i32 {
         serving_player.unwrap_or(1).clamp(1, 4)
     }
+
+    // A set is over at six games with a two-game lead, or 7-6 after a tiebreak
+    fn set_winner(player1: i32, player2: i32) -> Option<i32> {
+        let (high, low) = (player1.max(player2), player1.min(player2));
+        let finished = high >= 6 && (high - low >= 2 || (high == 7 && low == 6));
+        finished.then_some(if player1 > player2 { 1 } else { 2 })
+    }
+
+    // Games that can't occur in one set, e.g. 8-5: it would have ended at 7-5
+    fn impossible_games(player1: i32, player2: i32) -> bool {
+        let (high, low) = (player1.max(player2), player1.min(player2));
+        high >= 7 && high - low > 2
+    }
+
+    // Whether `player` wins the current game by taking the next point
+    fn wins_game_with_next_point(player_points: &str, opponent_points: &str, is_tiebreak: bool) -> bool {
+        if is_tiebreak {
+            let (Ok(mine), Ok(theirs)) = (player_points.parse::<i32>(), opponent_points.parse::<i32>()) else {
+                return false;
+            };
+            return mine + 1 >= TIEBREAK_POINTS_TO_WIN && mine + 1 - theirs >= 2;
+        }
+        match player_points {
+            "AD" => true,
+            "40" => matches!(opponent_points, "0" | "15" | "30"),
+            _ => false,
+        }
+    }
+
+    /// Game, break, set and match point for the current point, using standard
+    /// scoring: games to six with a 7-6 tiebreak, sets to `best_of`
+    pub fn critical_points(tennis_match: &ProcessedTennisMatch, best_of: i32) -> CriticalPoints {
+        let score = &tennis_match.score;
+        let points = [
+            Self::normalize_points(&score.player1_points),
+            Self::normalize_points(&score.player2_points),
+        ];
+        let games = [score.player1_games, score.player2_games];
+        let sets = [score.player1_sets, score.player2_sets];
+        let sets_to_win = best_of / 2 + 1;
+        // Servers 1-2 are the first side (team1 in doubles), 3-4 the second
+        let serving_side = match (tennis_match.doubles_players.is_some(), tennis_match.serving_player) {
+            (true, 1 | 2) | (false, 1) => 1,
+            _ => 2,
+        };
+
+        let mut result = CriticalPoints {
+            deuce: !tennis_match.is_tiebreak && points[0] == "40" && points[1] == "40",
+            ..Default::default()
+        };
+        if sets[0] >= sets_to_win || sets[1] >= sets_to_win {
+            return result;
+        }
+
+        for (index, player) in [1, 2].into_iter().enumerate() {
+            let other = 1 - index;
+            if !Self::wins_game_with_next_point(&points[index], &points[other], tennis_match.is_tiebreak) {
+                continue;
+            }
+            result.game_point = Some(player);
+            if !tennis_match.is_tiebreak && player != serving_side {
+                result.break_point = Some(player);
+            }
+
+            // A tiebreak decides the set; otherwise the set is won at six games with a two-game lead
+            let wins_set = tennis_match.is_tiebreak || {
+                let (mine, theirs) = (games[index] + 1, games[other]);
+                mine >= 6 && mine - theirs >= 2
+            };
+            if wins_set {
+                result.set_point = Some(player);
+                if sets[index] + 1 >= sets_to_win {
+                    result.match_point = Some(player);
+                }
+            }
+        }
+        result
+    }
+
+    /// Check a feed's match state for values that can't happen in tennis,
+    /// returning every problem found rather than stopping at the first
+    pub fn validate_strict(raw_data: &RawTennisData) -> Vec<TennisValidationWarning> {
+        let mut warnings = Vec::new();
+        let mut warn = |field: &str, message: String| {
+            warnings.push(TennisValidationWarning { field: field.to_string(), message });
+        };
+
+        if raw_data.id.is_none() && raw_data.match_id.is_none() {
+            warn("match_id", "Match has no id".to_string());
+        }
+        if raw_data.player1.is_none() && raw_data.team1.is_none() {
+            warn("player1", "Match has no first player or team".to_string());
+        }
+
+        let is_tiebreak = raw_data.is_tiebreak.or(raw_data.legacy_is_tiebreak).unwrap_or(false);
+        let score = raw_data.score.as_ref();
+        let player1_sets = score.and_then(|s| s.player1_sets.or(s.legacy_player1_sets)).unwrap_or(0);
+        let player2_sets = score.and_then(|s| s.player2_sets.or(s.legacy_player2_sets)).unwrap_or(0);
+        let player1_games = score.and_then(|s| s.player1_games.or(s.legacy_player1_games)).unwrap_or(0);
+        let player2_games = score.and_then(|s| s.player2_games.or(s.legacy_player2_games)).unwrap_or(0);
+
+        for (field, value) in [
+            ("score.player1_sets", player1_sets),
+            ("score.player2_sets", player2_sets),
+            ("score.player1_games", player1_games),
+            ("score.player2_games", player2_games),
+        ] {
+            if value < 0 {
+                warn(field, format!("Score is negative ({})", value));
+            }
+        }
+        for (field, value) in [("score.player1_sets", player1_sets), ("score.player2_sets", player2_sets)] {
+            if value > MAX_SETS_TO_WIN {
+                warn(field, format!("{} sets won is more than any match format allows", value));
+            }
+        }
+        if player1_sets >= MAX_SETS_TO_WIN && player2_sets >= MAX_SETS_TO_WIN {
+            warn("score", format!("Both sides have won {} sets", MAX_SETS_TO_WIN));
+        }
+        if Self::impossible_games(player1_games, player2_games) {
+            warn("score", format!("Games {}-{} can't occur in a set", player1_games, player2_games));
+        }
+
+        // Points: game values normally, plain counts in a tiebreak
+        let points = |first: Option<&String>, second: Option<&String>| {
+            Self::normalize_points(first.or(second).map(|p| p.as_str()).unwrap_or("0"))
+        };
+        let player1_points = points(score.and_then(|s| s.player1_points.as_ref()), score.and_then(|s| s.legacy_player1_points.as_ref()));
+        let player2_points = points(score.and_then(|s| s.player2_points.as_ref()), score.and_then(|s| s.legacy_player2_points.as_ref()));
+        for (field, value) in [("score.player1_points", &player1_points), ("score.player2_points", &player2_points)] {
+            let valid = if is_tiebreak {
+                value.parse::<u32>().is_ok()
+            } else {
+                GAME_POINT_VALUES.contains(&value.as_str())
+            };
+            if !valid {
+                let expected = if is_tiebreak { "a tiebreak point count" } else { "0, 15, 30, 40 or AD" };
+                warn(field, format!("'{}' is not {}", value, expected));
+            }
+        }
+        if !is_tiebreak {
+            let advantage = [(&player1_points, &player2_points), (&player2_points, &player1_points)]
+                .into_iter()
+                .any(|(mine, theirs)| mine == "AD" && theirs != "40");
+            if advantage {
+                warn("score", format!("Advantage needs the other side on 40, got {}-{}", player1_points, player2_points));
+            }
+        }
+
+        // Completed sets in the set breakdown should agree with the sets-won totals
+        if let Some(sets) = raw_data.sets.as_ref().filter(|sets| !sets.is_empty()) {
+            let mut won = [0, 0];
+            let mut keys: Vec<&String> = sets.keys().collect();
+            keys.sort();
+            for key in keys {
+                let set = &sets[key];
+                let (games1, games2) = (set.player1.unwrap_or(0), set.player2.unwrap_or(0));
+                if games1 < 0 || games2 < 0 {
+                    warn(&format!("sets.{}", key), format!("Set score {}-{} is negative", games1, games2));
+                } else if Self::impossible_games(games1, games2) {
+                    warn(&format!("sets.{}", key), format!("Set score {}-{} can't occur", games1, games2));
+                } else if let Some(winner) = Self::set_winner(games1, games2) {
+                    won[(winner - 1) as usize] += 1;
+                }
+            }
+            if won != [player1_sets, player2_sets] {
+                warn("sets", format!(
+                    "Set scores show {}-{} in sets won, but the score says {}-{}",
+                    won[0], won[1], player1_sets, player2_sets
+                ));
+            }
+        }
+
+        if let Some(current_set) = raw_data.current_set.or(raw_data.legacy_current_set) {
+            let max_set = player1_sets.max(0) + player2_sets.max(0) + 1;
+            if current_set < 1 || current_set > max_set {
+                warn("current_set", format!("Current set {} doesn't follow {} completed set(s)", current_set, max_set - 1));
+            }
+        }
+
+        // Players 1-2 in singles; 1-4 in doubles, where 3-4 are the second team
+        if let Some(serving) = raw_data.serving_player.or(raw_data.legacy_serving_player) {
+            let is_doubles = [&raw_data.player1, &raw_data.player2, &raw_data.team1, &raw_data.team2].iter()
+                .any(|side| side.as_ref().is_some_and(|player| player.partner.is_some()));
+            let max_server = if is_doubles { 4 } else { 2 };
+            if !(1..=max_server).contains(&serving) {
+                warn("serving_player", format!("Serving player {} is outside 1-{}", serving, max_server));
+            }
+        }
+
+        warnings
+    }
 }
 
 // Batch processing for multiple tennis matches
@@ -288,6 +661,22 @@ pub async fn process_tennis_data_batch(raw_data_batch: Vec<RawTennisData>) -> Re
     BatchTennisProcessor::process_batch(raw_data_batch)
 }
 
+// Game/break/set/match point for the current point; best_of defaults to 3
+#[command]
+pub async fn compute_critical_points(tennis_match: ProcessedTennisMatch, best_of: Option<i32>) -> Result<CriticalPoints, String> {
+    let best_of = best_of.unwrap_or(DEFAULT_BEST_OF);
+    if best_of < 1 || best_of % 2 == 0 {
+        return Err(format!("best_of must be an odd number of sets, got {}", best_of));
+    }
+    Ok(TennisDataProcessor::critical_points(&tennis_match, best_of))
+}
+
+// Like validate_tennis_data, but also checks the match state is possible; an empty list means it is
+#[command]
+pub async fn validate_tennis_data_strict(raw_data: RawTennisData) -> Result<Vec<TennisValidationWarning>, String> {
+    Ok(TennisDataProcessor::validate_strict(&raw_data))
+}
+
 #[command]
 pub async fn validate_tennis_data(raw_data: RawTennisData) -> Result<bool, String> {
     // Basic validation - check if required fields are present
@@ -302,3 +691,109 @@ pub async fn validate_tennis_data(raw_data: RawTennisData) -> Result<bool, Strin
 
     Ok(true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn country_variants_map_to_alpha3() {
+        for variant in ["US", "usa", "United States", "  united   states of america ", "U.S.A."] {
+            assert_eq!(TennisDataProcessor::normalize_country(variant), "USA", "{}", variant);
+        }
+        for variant in ["GER", "DE", "Germany", "DEU"] {
+            assert_eq!(TennisDataProcessor::normalize_country(variant), "DEU", "{}", variant);
+        }
+        assert_eq!(TennisDataProcessor::normalize_country("sui"), "CHE");
+    }
+
+    #[test]
+    fn unknown_country_is_left_untouched() {
+        assert_eq!(TennisDataProcessor::normalize_country("Atlantis"), "Atlantis");
+    }
+
+    #[test]
+    fn processed_player_keeps_the_original_country() {
+        let raw: RawTennisData = serde_json::from_value(serde_json::json!({
+            "player1": { "name": "A. Player", "country": "Great Britain" },
+        })).unwrap();
+        let processed = TennisDataProcessor::process_data(raw).unwrap();
+        assert_eq!(processed.player1.country.as_deref(), Some("GBR"));
+        assert_eq!(processed.player1.original_country.as_deref(), Some("Great Britain"));
+    }
+
+    #[test]
+    fn doubles_payload_yields_four_named_players() {
+        let raw: RawTennisData = serde_json::from_value(serde_json::json!({
+            "team1": { "name": "Smith", "country": "US", "partner": { "name": "Jones", "country": "CA" } },
+            "team2": { "name": "Garcia", "partner": { "name": "Rossi" } },
+            "servingPlayer": 3,
+        })).unwrap();
+        let processed = TennisDataProcessor::process_data(raw).unwrap();
+
+        assert_eq!(processed.match_type, "doubles");
+        let players = processed.doubles_players.expect("doubles players");
+        let names: Vec<&str> = [&players.team1.player1, &players.team1.player2, &players.team2.player1, &players.team2.player2]
+            .iter()
+            .map(|player| player.name.as_str())
+            .collect();
+        assert_eq!(names, ["Smith", "Jones", "Garcia", "Rossi"]);
+        assert_eq!(players.team1.player2.country.as_deref(), Some("CAN"));
+        assert_eq!(processed.player1.name, "Smith / Jones");
+        assert_eq!(processed.player2.name, "Garcia / Rossi");
+        assert_eq!(processed.serving_player, 3);
+    }
+
+    #[test]
+    fn singles_payload_has_no_doubles_players() {
+        let raw: RawTennisData = serde_json::from_value(serde_json::json!({
+            "player1": { "name": "Smith" },
+            "player2": { "name": "Garcia" },
+        })).unwrap();
+        let processed = TennisDataProcessor::process_data(raw).unwrap();
+        assert_eq!(processed.match_type, "singles");
+        assert!(processed.doubles_players.is_none());
+    }
+
+    fn match_at(points: [&str; 2], games: [i32; 2], sets: [i32; 2], serving_player: i32) -> ProcessedTennisMatch {
+        let raw: RawTennisData = serde_json::from_value(serde_json::json!({
+            "score": {
+                "player1Points": points[0], "player2Points": points[1],
+                "player1Games": games[0], "player2Games": games[1],
+                "player1Sets": sets[0], "player2Sets": sets[1],
+            },
+            "servingPlayer": serving_player,
+        })).unwrap();
+        TennisDataProcessor::process_data(raw).unwrap()
+    }
+
+    #[test]
+    fn forty_thirty_on_serve_is_game_point() {
+        let points = TennisDataProcessor::critical_points(&match_at(["40", "30"], [2, 2], [0, 0], 1), 3);
+        assert_eq!(points.game_point, Some(1));
+        assert_eq!(points.break_point, None);
+        assert_eq!(points.set_point, None);
+    }
+
+    #[test]
+    fn forty_thirty_returning_is_break_point() {
+        let points = TennisDataProcessor::critical_points(&match_at(["40", "30"], [2, 2], [0, 0], 2), 3);
+        assert_eq!(points.game_point, Some(1));
+        assert_eq!(points.break_point, Some(1));
+    }
+
+    #[test]
+    fn game_point_at_five_four_in_the_deciding_set_is_match_point() {
+        let points = TennisDataProcessor::critical_points(&match_at(["AD", "40"], [5, 4], [1, 1], 1), 3);
+        assert_eq!(points.set_point, Some(1));
+        assert_eq!(points.match_point, Some(1));
+        assert!(!points.deuce);
+    }
+
+    #[test]
+    fn deuce_has_no_game_point() {
+        let points = TennisDataProcessor::critical_points(&match_at(["40", "40"], [0, 0], [0, 0], 1), 3);
+        assert!(points.deuce);
+        assert_eq!(points.game_point, None);
+    }
+}
@@ -0,0 +1,125 @@
+// src-tauri/src/commands/theme.rs
+//
+// Recolor a whole board at once. Components carry an optional `role` tag and a
+// theme assigns a color to each role; untagged components keep their colors.
+use serde::{Deserialize, Serialize};
+use std::fs;
+use tauri::AppHandle;
+use tracing::info;
+
+use crate::commands::storage::read_scoreboard_config;
+use crate::error::AppError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentRole {
+    Background,
+    Accent,
+    Text,
+}
+
+// Roles left as None are not changed
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Theme {
+    pub background: Option<String>,
+    pub accent: Option<String>,
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeResult {
+    pub recolored: Vec<String>,
+    pub untagged: usize,
+}
+
+impl Theme {
+    fn validate(&self) -> Result<(), AppError> {
+        let colors = [("background", &self.background), ("accent", &self.accent), ("text", &self.text)];
+        if colors.iter().all(|(_, color)| color.is_none()) {
+            return Err(AppError::validation("theme", "Theme has no colors"));
+        }
+        for (role, color) in colors {
+            if color.as_deref().is_some_and(|c| c.trim().is_empty()) {
+                return Err(AppError::validation(role, format!("Theme color for {} is empty", role)));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn set_style(component: &mut serde_json::Value, key: &str, color: &str) {
+    let Some(component) = component.as_object_mut() else {
+        return;
+    };
+    let style = component.entry("style").or_insert_with(|| serde_json::json!({}));
+    if let Some(style) = style.as_object_mut() {
+        style.insert(key.to_string(), serde_json::json!(color));
+    }
+}
+
+// Background and accent components are filled with their color; accents also
+// take it as their border if they have one. Text components get the text color.
+pub fn apply_theme(data: &mut serde_json::Value, theme: &Theme) -> ThemeResult {
+    let mut result = ThemeResult::default();
+
+    if let Some(color) = &theme.background {
+        if let Some(background) = data.get_mut("background").and_then(|b| b.as_object_mut()) {
+            background.insert("color".to_string(), serde_json::json!(color));
+        }
+    }
+
+    let components = data.get_mut("components").and_then(|c| c.as_array_mut());
+    for component in components.into_iter().flatten() {
+        let role = component.get("role")
+            .and_then(|role| serde_json::from_value::<ComponentRole>(role.clone()).ok());
+        let Some(role) = role else {
+            result.untagged += 1;
+            continue;
+        };
+
+        let changed = match role {
+            ComponentRole::Background => theme.background.as_deref().map(|color| {
+                set_style(component, "backgroundColor", color);
+            }),
+            ComponentRole::Accent => theme.accent.as_deref().map(|color| {
+                set_style(component, "backgroundColor", color);
+                let has_border = component.get("style").and_then(|s| s.get("borderColor")).is_some();
+                if has_border {
+                    set_style(component, "borderColor", color);
+                }
+            }),
+            ComponentRole::Text => theme.text.as_deref().map(|color| {
+                set_style(component, "textColor", color);
+            }),
+        };
+
+        if changed.is_some() {
+            if let Some(id) = component.get("id").and_then(|id| id.as_str()) {
+                result.recolored.push(id.to_string());
+            }
+        }
+    }
+
+    result
+}
+
+#[tauri::command]
+pub async fn apply_theme_to_scoreboard(
+    app: AppHandle,
+    filename: String,
+    theme: Theme,
+) -> Result<ThemeResult, AppError> {
+    theme.validate()?;
+
+    let (scoreboard_path, mut config) = read_scoreboard_config(&app, &filename)?;
+    let result = apply_theme(&mut config.data, &theme);
+    config.updated_at = chrono::Utc::now().to_rfc3339();
+
+    let json_data = serde_json::to_string_pretty(&config)?;
+    fs::write(&scoreboard_path, json_data)?;
+
+    info!("🎨 Recolored {} component(s) in {}", result.recolored.len(), filename);
+    Ok(result)
+}
@@ -0,0 +1,39 @@
+// src-tauri/src/commands/type_schema.rs
+//
+// JSON Schemas for the structs the frontend mirrors by hand, so its
+// TypeScript types can be generated (e.g. with json-schema-to-typescript)
+// instead of drifting from the Rust side.
+use schemars::{schema_for, JsonSchema};
+use serde_json::{Map, Value};
+use tauri::command;
+
+use crate::commands::config::AppConfig;
+use crate::commands::images::StoredImage;
+use crate::commands::scoreboard::GameState;
+use crate::commands::storage::ScoreboardConfig;
+use crate::commands::tennis_processor::{ProcessedTennisMatch, RawTennisData};
+use crate::commands::videos::StoredVideo;
+use crate::error::AppError;
+
+fn insert_schema<T: JsonSchema>(schemas: &mut Map<String, Value>, name: &str) -> Result<(), AppError> {
+    schemas.insert(name.to_string(), serde_json::to_value(schema_for!(T))?);
+    Ok(())
+}
+
+// Schemas keyed by type name; nested types are in each schema's `$defs`
+pub fn type_schemas() -> Result<Value, AppError> {
+    let mut schemas = Map::new();
+    insert_schema::<GameState>(&mut schemas, "GameState")?;
+    insert_schema::<RawTennisData>(&mut schemas, "RawTennisData")?;
+    insert_schema::<ProcessedTennisMatch>(&mut schemas, "ProcessedTennisMatch")?;
+    insert_schema::<ScoreboardConfig>(&mut schemas, "ScoreboardConfig")?;
+    insert_schema::<StoredImage>(&mut schemas, "StoredImage")?;
+    insert_schema::<StoredVideo>(&mut schemas, "StoredVideo")?;
+    insert_schema::<AppConfig>(&mut schemas, "AppConfig")?;
+    Ok(Value::Object(schemas))
+}
+
+#[command]
+pub async fn export_type_schema() -> Result<Value, AppError> {
+    type_schemas()
+}
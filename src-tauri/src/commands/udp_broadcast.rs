@@ -0,0 +1,297 @@
+// src-tauri/src/commands/udp_broadcast.rs
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::UdpSocket;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::interval;
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use tauri::State;
+use tracing::{error, info, warn};
+
+use crate::commands::live_data::active_court_snapshot;
+use crate::commands::scoreboard::{GameState, ScoreboardState};
+use crate::error::AppError;
+
+// Keep datagrams below a typical Ethernet MTU so they are never fragmented
+const DEFAULT_MAX_PAYLOAD_BYTES: usize = 1400;
+// How often to check for changes when broadcasting on change only (interval_ms == 0)
+const CHANGE_POLL_MS: u64 = 250;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UdpBroadcastStatus {
+    pub is_running: bool,
+    pub port: u16,
+    pub destination: Option<String>,
+    pub interval_ms: u64,
+    pub max_payload_bytes: usize,
+    pub packets_sent: u64,
+    pub truncated_count: u64,
+    pub last_sent: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+lazy_static! {
+    static ref UDP_BROADCAST_STATUS: Arc<Mutex<UdpBroadcastStatus>> = Arc::new(Mutex::new(UdpBroadcastStatus::default()));
+    static ref UDP_BROADCAST_TASK: Arc<Mutex<Option<JoinHandle<()>>>> = Arc::new(Mutex::new(None));
+}
+
+// Progressive truncation steps, applied until the payload fits
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum Detail {
+    Full,
+    NoPlayers,
+    NoSets,
+    NoMetadata,
+}
+
+fn compact_game_state(game_state: &GameState, detail: Detail) -> serde_json::Value {
+    let mut compact = serde_json::json!({
+        "home": game_state.home_team.abbreviation.clone().unwrap_or_else(|| game_state.home_team.name.clone()),
+        "away": game_state.away_team.abbreviation.clone().unwrap_or_else(|| game_state.away_team.name.clone()),
+        "homeScore": game_state.home_score,
+        "awayScore": game_state.away_score,
+        "period": game_state.period,
+        "clock": game_state.time_remaining,
+        "active": game_state.is_game_active,
+        "sport": game_state.sport,
+    });
+
+    if detail < Detail::NoMetadata && !game_state.metadata.is_empty() {
+        compact["metadata"] = serde_json::json!(game_state.metadata);
+    }
+
+    compact
+}
+
+fn side_names(match_data: &serde_json::Value) -> Option<Vec<String>> {
+    let sides = match_data.get("sides")?.as_array()?;
+
+    Some(sides.iter().map(|side| {
+        side.get("players")
+            .and_then(|p| p.as_array())
+            .map(|players| {
+                players.iter()
+                    .filter_map(|player| player.get("participant")?.get("last_name")?.as_str())
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .unwrap_or_default()
+    }).collect())
+}
+
+fn compact_court(match_data: &serde_json::Value, detail: Detail) -> serde_json::Value {
+    let score = match_data.get("score");
+    let score_field = |name: &str| score.and_then(|s| s.get(name)).cloned().unwrap_or(serde_json::Value::Null);
+
+    let mut compact = serde_json::json!({
+        "matchId": match_data.get("matchId").cloned().unwrap_or(serde_json::Value::Null),
+        "status": match_data.get("matchStatus").cloned().unwrap_or(serde_json::Value::Null),
+        "score1": score_field("scoreStringSide1"),
+        "score2": score_field("scoreStringSide2"),
+        "points1": score_field("side1PointScore"),
+        "points2": score_field("side2PointScore"),
+    });
+
+    if detail < Detail::NoSets {
+        if let Some(sets) = score.and_then(|s| s.get("sets")).and_then(|s| s.as_array()) {
+            let sets: Vec<serde_json::Value> = sets.iter().map(|set| {
+                serde_json::json!([
+                    set.get("side1Score").cloned().unwrap_or(serde_json::Value::Null),
+                    set.get("side2Score").cloned().unwrap_or(serde_json::Value::Null),
+                ])
+            }).collect();
+            compact["sets"] = serde_json::Value::Array(sets);
+        }
+    }
+
+    if detail < Detail::NoPlayers {
+        if let Some(names) = side_names(match_data) {
+            compact["players"] = serde_json::json!(names);
+        }
+    }
+
+    compact
+}
+
+fn encode_snapshot(
+    seq: u64,
+    game_state: Option<&GameState>,
+    court_names: &[&String],
+    courts: &serde_json::Map<String, serde_json::Value>,
+    detail: Detail,
+) -> Result<Vec<u8>, AppError> {
+    let mut compact_courts = serde_json::Map::new();
+    for court_name in court_names {
+        if let Some(match_data) = courts.get(*court_name) {
+            compact_courts.insert((*court_name).clone(), compact_court(match_data, detail));
+        }
+    }
+
+    let snapshot = serde_json::json!({
+        "seq": seq,
+        "ts": Utc::now().timestamp_millis(),
+        "game": game_state.map(|g| compact_game_state(g, detail)),
+        "courts": compact_courts,
+    });
+
+    Ok(serde_json::to_vec(&snapshot)?)
+}
+
+// Returns the encoded datagram and whether anything had to be dropped to fit
+fn build_payload(
+    seq: u64,
+    game_state: Option<&GameState>,
+    courts: &serde_json::Map<String, serde_json::Value>,
+    max_payload_bytes: usize,
+) -> Result<(Vec<u8>, bool), AppError> {
+    let mut court_names: Vec<&String> = courts.keys().collect();
+    court_names.sort();
+
+    for detail in [Detail::Full, Detail::NoPlayers, Detail::NoSets, Detail::NoMetadata] {
+        let payload = encode_snapshot(seq, game_state, &court_names, courts, detail)?;
+        if payload.len() <= max_payload_bytes {
+            return Ok((payload, detail != Detail::Full));
+        }
+    }
+
+    // Still too large: drop whole courts from the end of the list
+    while !court_names.is_empty() {
+        court_names.pop();
+        let payload = encode_snapshot(seq, game_state, &court_names, courts, Detail::NoMetadata)?;
+        if payload.len() <= max_payload_bytes {
+            return Ok((payload, true));
+        }
+    }
+
+    Err(AppError::validation(
+        "maxPayloadBytes",
+        format!("UDP snapshot does not fit in {} bytes even after truncation", max_payload_bytes),
+    ))
+}
+
+fn resolve_destination(port: u16, target: Option<&str>) -> String {
+    match target.map(|t| t.trim()).filter(|t| !t.is_empty()) {
+        // Accept either a bare host or a host:port pair
+        Some(target) if target.parse::<std::net::SocketAddr>().is_ok() => target.to_string(),
+        Some(target) => format!("{}:{}", target, port),
+        None => format!("255.255.255.255:{}", port),
+    }
+}
+
+#[tauri::command]
+pub async fn start_udp_broadcast(
+    state: State<'_, ScoreboardState>,
+    port: u16,
+    target: Option<String>,
+    interval_ms: u64,
+    max_payload_bytes: Option<usize>,
+) -> Result<String, AppError> {
+    let mut task = UDP_BROADCAST_TASK.lock().await;
+    if task.as_ref().map(|handle| !handle.is_finished()).unwrap_or(false) {
+        return Err(AppError::conflict("UDP broadcast already running"));
+    }
+
+    let max_payload_bytes = max_payload_bytes.unwrap_or(DEFAULT_MAX_PAYLOAD_BYTES);
+    let destination = resolve_destination(port, target.as_deref());
+    let is_broadcast = target.is_none();
+
+    let socket = UdpSocket::bind("0.0.0.0:0").await
+        .map_err(|e| AppError::io(format!("Failed to bind UDP socket: {}", e)))?;
+    if is_broadcast {
+        socket.set_broadcast(true)
+            .map_err(|e| AppError::io(format!("Failed to enable UDP broadcast: {}", e)))?;
+    }
+
+    {
+        let mut status = UDP_BROADCAST_STATUS.lock().await;
+        *status = UdpBroadcastStatus {
+            is_running: true,
+            port,
+            destination: Some(destination.clone()),
+            interval_ms,
+            max_payload_bytes,
+            ..UdpBroadcastStatus::default()
+        };
+    }
+
+    let game_state = Arc::clone(&state.game_state);
+    let task_destination = destination.clone();
+
+    let handle = tokio::spawn(async move {
+        let on_change = interval_ms == 0;
+        let mut ticker = interval(Duration::from_millis(if on_change { CHANGE_POLL_MS } else { interval_ms }));
+        let mut seq: u64 = 0;
+        let mut last_sent_state: Option<(Option<String>, serde_json::Map<String, serde_json::Value>)> = None;
+
+        loop {
+            ticker.tick().await;
+
+            let current_game = game_state.lock().ok().and_then(|g| g.clone());
+            let courts = active_court_snapshot().await;
+
+            if on_change {
+                // Compare the serialized game state since GameState has no PartialEq
+                let game_key = current_game.as_ref().and_then(|g| serde_json::to_string(g).ok());
+                let current = (game_key, courts.clone());
+                if last_sent_state.as_ref() == Some(&current) {
+                    continue;
+                }
+                last_sent_state = Some(current);
+            }
+
+            seq += 1;
+            let (payload, truncated) = match build_payload(seq, current_game.as_ref(), &courts, max_payload_bytes) {
+                Ok(result) => result,
+                Err(e) => {
+                    error!("UDP broadcast error: {}", e);
+                    UDP_BROADCAST_STATUS.lock().await.last_error = Some(e.to_string());
+                    continue;
+                }
+            };
+
+            let send_result = socket.send_to(&payload, &task_destination).await;
+
+            let mut status = UDP_BROADCAST_STATUS.lock().await;
+            if truncated {
+                status.truncated_count += 1;
+                warn!("⚠️ [UDP] Snapshot truncated to fit {} bytes", max_payload_bytes);
+            }
+            match send_result {
+                Ok(_) => {
+                    status.packets_sent += 1;
+                    status.last_sent = Some(Utc::now());
+                }
+                Err(e) => {
+                    error!("UDP broadcast send error: {}", e);
+                    status.last_error = Some(format!("Failed to send to {}: {}", task_destination, e));
+                }
+            }
+        }
+    });
+
+    *task = Some(handle);
+    info!("📡 Started UDP broadcast to {} (interval: {}ms)", destination, interval_ms);
+    Ok(format!("UDP broadcast started to {}", destination))
+}
+
+#[tauri::command]
+pub async fn stop_udp_broadcast() -> Result<String, AppError> {
+    let mut task = UDP_BROADCAST_TASK.lock().await;
+
+    if let Some(handle) = task.take() {
+        handle.abort();
+        UDP_BROADCAST_STATUS.lock().await.is_running = false;
+        info!("🛑 Stopped UDP broadcast");
+        Ok("UDP broadcast stopped".to_string())
+    } else {
+        Err(AppError::conflict("UDP broadcast is not running"))
+    }
+}
+
+#[tauri::command]
+pub async fn get_udp_broadcast_status() -> Result<UdpBroadcastStatus, AppError> {
+    Ok(UDP_BROADCAST_STATUS.lock().await.clone())
+}
@@ -0,0 +1,123 @@
+// src-tauri/src/commands/undo_history.rs
+//
+// Persists the designer's undo/redo stacks per board so undo survives a restart.
+// The stacks themselves are owned by the editor; this module only stores them.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use lazy_static::lazy_static;
+use tauri::AppHandle;
+use tracing::error;
+
+use crate::commands::storage::sanitize_filename;
+use crate::commands::data_dir::data_dir;
+use crate::error::AppError;
+
+// Snapshots kept per stack; older entries are dropped first
+const MAX_HISTORY_ENTRIES: usize = 50;
+// Writes are coalesced so rapid edits don't hit the disk on every change
+const FLUSH_DELAY_MS: u64 = 2000;
+
+// Both stacks are ordered oldest first; the last element is the next to undo/redo
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UndoHistory {
+    pub past: Vec<serde_json::Value>,
+    pub future: Vec<serde_json::Value>,
+}
+
+lazy_static! {
+    static ref PENDING_HISTORY: Arc<Mutex<HashMap<String, UndoHistory>>> = Arc::new(Mutex::new(HashMap::new()));
+    static ref FLUSH_SCHEDULED: Arc<Mutex<bool>> = Arc::new(Mutex::new(false));
+}
+
+fn bound_stack(stack: &mut Vec<serde_json::Value>) {
+    if stack.len() > MAX_HISTORY_ENTRIES {
+        stack.drain(..stack.len() - MAX_HISTORY_ENTRIES);
+    }
+}
+
+fn history_path(app: &AppHandle, filename: &str) -> Result<PathBuf, AppError> {
+    let app_data_dir = data_dir(app)?;
+    let name = sanitize_filename(filename.trim_end_matches(".json"));
+    Ok(app_data_dir.join("undo_history").join(format!("{}.json", name)))
+}
+
+async fn flush_pending(app: &AppHandle) {
+    let pending: HashMap<String, UndoHistory> = std::mem::take(&mut *PENDING_HISTORY.lock().await);
+
+    for (filename, history) in pending {
+        let result = async {
+            let path = history_path(app, &filename)?;
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            let json = serde_json::to_string(&history)?;
+            tokio::fs::write(&path, json).await?;
+            Ok::<(), AppError>(())
+        }.await;
+
+        if let Err(e) = result {
+            error!("Failed to persist undo history for {}: {}", filename, e);
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn save_undo_history(app: AppHandle, filename: String, history: UndoHistory) -> Result<(), AppError> {
+    let mut history = history;
+    bound_stack(&mut history.past);
+    bound_stack(&mut history.future);
+
+    PENDING_HISTORY.lock().await.insert(filename, history);
+
+    let mut scheduled = FLUSH_SCHEDULED.lock().await;
+    if !*scheduled {
+        *scheduled = true;
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(FLUSH_DELAY_MS)).await;
+            *FLUSH_SCHEDULED.lock().await = false;
+            flush_pending(&app).await;
+        });
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn load_undo_history(app: AppHandle, filename: String) -> Result<UndoHistory, AppError> {
+    // An unflushed save is newer than whatever is on disk
+    if let Some(history) = PENDING_HISTORY.lock().await.get(&filename) {
+        return Ok(history.clone());
+    }
+
+    let path = history_path(&app, &filename)?;
+    if !path.exists() {
+        return Ok(UndoHistory::default());
+    }
+
+    let json = tokio::fs::read_to_string(&path).await
+        .map_err(|e| AppError::io(format!("Failed to read undo history: {}", e)))?;
+    let mut history: UndoHistory = serde_json::from_str(&json)
+        .map_err(|e| AppError::validation("json", format!("Failed to parse undo history: {}", e)))?;
+    bound_stack(&mut history.past);
+    bound_stack(&mut history.future);
+    Ok(history)
+}
+
+pub(crate) async fn remove_undo_history(app: &AppHandle, filename: &str) -> Result<(), AppError> {
+    PENDING_HISTORY.lock().await.remove(filename);
+
+    let path = history_path(app, filename)?;
+    if path.exists() {
+        tokio::fs::remove_file(&path).await
+            .map_err(|e| AppError::io(format!("Failed to delete undo history: {}", e)))?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn clear_undo_history(app: AppHandle, filename: String) -> Result<(), AppError> {
+    remove_undo_history(&app, &filename).await
+}
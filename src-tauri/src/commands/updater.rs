@@ -0,0 +1,249 @@
+// src-tauri/src/commands/updater.rs
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use lazy_static::lazy_static;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_updater::{Update, UpdaterExt};
+use tracing::{error, info};
+
+use crate::commands::events::BackendEvent;
+use crate::commands::monitor::scoreboard_window_counts;
+use crate::commands::data_dir::data_dir;
+use crate::error::AppError;
+
+const SETTINGS_FILE: &str = "updater_settings.json";
+const STABLE_ENDPOINT: &str = "https://github.com/Coderinsightmaven/tempuz-scoreboard/releases/latest/download/latest.json";
+const BETA_ENDPOINT: &str = "https://github.com/Coderinsightmaven/tempuz-scoreboard/releases/download/beta/latest.json";
+// Minisign public key for release signatures, supplied at build time
+const UPDATER_PUBKEY: Option<&str> = option_env!("TEMPUZ_UPDATER_PUBKEY");
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    Beta,
+}
+
+impl UpdateChannel {
+    fn endpoint(&self) -> &'static str {
+        match self {
+            UpdateChannel::Stable => STABLE_ENDPOINT,
+            UpdateChannel::Beta => BETA_ENDPOINT,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdaterSettings {
+    pub channel: UpdateChannel,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateState {
+    #[default]
+    Idle,
+    Checking,
+    UpToDate,
+    Available,
+    Downloading,
+    Installing,
+    Error,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateStatus {
+    pub state: UpdateState,
+    pub channel: UpdateChannel,
+    pub current_version: String,
+    pub available_version: Option<String>,
+    pub release_notes: Option<String>,
+    pub last_checked: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateProgressEvent {
+    pub downloaded: u64,
+    // None when the server doesn't send a content length
+    pub total: Option<u64>,
+}
+
+lazy_static! {
+    static ref UPDATE_STATUS: Arc<Mutex<UpdateStatus>> = Arc::new(Mutex::new(UpdateStatus::default()));
+}
+
+fn settings_path(app: &AppHandle) -> Result<std::path::PathBuf, AppError> {
+    let app_data_dir = data_dir(app)?;
+    Ok(app_data_dir.join(SETTINGS_FILE))
+}
+
+fn load_settings(app: &AppHandle) -> UpdaterSettings {
+    settings_path(app).ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+async fn set_state(app: &AppHandle, update: impl FnOnce(&mut UpdateStatus)) {
+    let status = {
+        let mut status = UPDATE_STATUS.lock().await;
+        update(&mut status);
+        status.clone()
+    };
+    if let Err(e) = app.emit(BackendEvent::UpdateStatusChanged.as_str(), &status) {
+        error!("Failed to emit {}: {}", BackendEvent::UpdateStatusChanged, e);
+    }
+}
+
+async fn find_update(app: &AppHandle) -> Result<Option<Update>, AppError> {
+    let pubkey = UPDATER_PUBKEY
+        .ok_or_else(|| AppError::conflict("Updater signing key is not configured for this build"))?;
+    let channel = load_settings(app).channel;
+    let endpoint = url::Url::parse(channel.endpoint())
+        .map_err(|e| AppError::validation("channel", format!("Invalid update endpoint: {}", e)))?;
+
+    let updater = app.updater_builder()
+        .pubkey(pubkey)
+        .endpoints(vec![endpoint])
+        .and_then(|builder| builder.build())
+        .map_err(|e| AppError::external("updater", format!("Failed to create updater: {}", e)))?;
+
+    updater.check().await
+        .map_err(|e| AppError::external("updater", format!("Failed to check for updates: {}", e)))
+}
+
+// Checks the configured channel and records the result in the shared status
+async fn check_and_record(app: &AppHandle) -> Result<Option<Update>, AppError> {
+    let channel = load_settings(app).channel;
+    let current_version = app.package_info().version.to_string();
+    set_state(app, |status| {
+        status.state = UpdateState::Checking;
+        status.channel = channel;
+        status.current_version = current_version;
+    }).await;
+
+    let result = find_update(app).await;
+    let checked_at = chrono::Utc::now();
+
+    match &result {
+        Ok(Some(update)) => {
+            info!("⬆️ Update {} available on {:?} channel", update.version, channel);
+            let (version, notes) = (update.version.clone(), update.body.clone());
+            set_state(app, |status| {
+                status.state = UpdateState::Available;
+                status.available_version = Some(version);
+                status.release_notes = notes;
+                status.last_checked = Some(checked_at);
+                status.last_error = None;
+            }).await;
+        }
+        Ok(None) => set_state(app, |status| {
+            status.state = UpdateState::UpToDate;
+            status.available_version = None;
+            status.release_notes = None;
+            status.last_checked = Some(checked_at);
+            status.last_error = None;
+        }).await,
+        Err(e) => {
+            error!("{}", e);
+            let error = e.to_string();
+            set_state(app, |status| {
+                status.state = UpdateState::Error;
+                status.last_checked = Some(checked_at);
+                status.last_error = Some(error);
+            }).await;
+        }
+    }
+
+    result
+}
+
+#[tauri::command]
+pub async fn check_for_updates(app: AppHandle) -> Result<UpdateStatus, AppError> {
+    check_and_record(&app).await?;
+    Ok(UPDATE_STATUS.lock().await.clone())
+}
+
+#[tauri::command]
+pub async fn download_and_install_update(app: AppHandle, force: Option<bool>) -> Result<(), AppError> {
+    // Installing restarts the app; never do that under a live display unless asked to
+    let (open_displays, _) = scoreboard_window_counts(&app);
+    if open_displays > 0 && !force.unwrap_or(false) {
+        return Err(AppError::conflict(format!(
+            "{} scoreboard display(s) are open; close them or pass force to install",
+            open_displays
+        )));
+    }
+
+    let update = check_and_record(&app).await?
+        .ok_or_else(|| AppError::not_found("Update"))?;
+
+    set_state(&app, |status| status.state = UpdateState::Downloading).await;
+
+    let progress_app = app.clone();
+    let mut downloaded: u64 = 0;
+    let result = update.download_and_install(
+        |chunk_length, content_length| {
+            downloaded += chunk_length as u64;
+            let event = UpdateProgressEvent { downloaded, total: content_length };
+            if let Err(e) = progress_app.emit(BackendEvent::UpdateProgress.as_str(), &event) {
+                error!("Failed to emit {}: {}", BackendEvent::UpdateProgress, e);
+            }
+        },
+        || info!("⬆️ Update downloaded, installing..."),
+    ).await;
+
+    if let Err(e) = result {
+        let error = format!("Failed to install update: {}", e);
+        error!("{}", error);
+        set_state(&app, |status| {
+            status.state = UpdateState::Error;
+            status.last_error = Some(error.clone());
+        }).await;
+        return Err(AppError::external("updater", error));
+    }
+
+    set_state(&app, |status| status.state = UpdateState::Installing).await;
+    info!("⬆️ Update {} installed, restarting", update.version);
+    app.restart();
+}
+
+#[tauri::command]
+pub async fn get_update_status(app: AppHandle) -> Result<UpdateStatus, AppError> {
+    let mut status = UPDATE_STATUS.lock().await.clone();
+    // Before the first check the stored status has no version or channel yet
+    status.channel = load_settings(&app).channel;
+    status.current_version = app.package_info().version.to_string();
+    Ok(status)
+}
+
+#[tauri::command]
+pub async fn get_update_channel(app: AppHandle) -> Result<UpdateChannel, AppError> {
+    Ok(load_settings(&app).channel)
+}
+
+#[tauri::command]
+pub async fn set_update_channel(app: AppHandle, channel: UpdateChannel) -> Result<(), AppError> {
+    let path = settings_path(&app)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| AppError::io(format!("Failed to create settings directory: {}", e)))?;
+    }
+    let json = serde_json::to_string_pretty(&UpdaterSettings { channel })?;
+    std::fs::write(&path, json)
+        .map_err(|e| AppError::io(format!("Failed to write updater settings: {}", e)))?;
+
+    // A check on the old channel no longer applies
+    set_state(&app, |status| {
+        status.channel = channel;
+        status.state = UpdateState::Idle;
+        status.available_version = None;
+        status.release_notes = None;
+    }).await;
+
+    info!("⬆️ Update channel set to {:?}", channel);
+    Ok(())
+}
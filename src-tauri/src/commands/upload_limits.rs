@@ -0,0 +1,119 @@
+// src-tauri/src/commands/upload_limits.rs
+//
+// Size checks for base64 uploads, done before decoding so an oversized payload
+// is rejected without allocating the decoded buffer.
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle};
+
+use crate::commands::config::{app_config, update_app_config};
+use crate::error::AppError;
+
+// Allowed difference between decoded and declared size, for clients that report
+// sizes slightly differently (at least this many bytes, or 0.1% of the file)
+const SIZE_TOLERANCE_BYTES: u64 = 16;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct UploadLimits {
+    pub max_image_bytes: u64,
+    pub max_video_bytes: u64,
+}
+
+// The limits live in the app config so they survive restarts
+pub fn upload_limits() -> UploadLimits {
+    let config = app_config();
+    UploadLimits {
+        max_image_bytes: config.max_image_bytes,
+        max_video_bytes: config.max_video_bytes,
+    }
+}
+
+fn size_tolerance(declared_size: u64) -> u64 {
+    SIZE_TOLERANCE_BYTES.max(declared_size / 1000)
+}
+
+// Check the encoded payload against the declared size and the configured cap
+pub fn validate_base64_payload(file_data: &str, declared_size: u64, max_bytes: u64) -> Result<(), AppError> {
+    if declared_size > max_bytes {
+        return Err(AppError::validation(
+            "file_size",
+            format!("File is too large: {} bytes (limit {} bytes)", declared_size, max_bytes),
+        ));
+    }
+
+    // Standard padded base64 encodes every 3 bytes as 4 characters
+    let expected_len = declared_size.div_ceil(3) * 4;
+    let max_len = (declared_size + size_tolerance(declared_size)).div_ceil(3) * 4;
+    let actual_len = file_data.len() as u64;
+
+    if actual_len > max_len {
+        return Err(AppError::validation("file_data", format!(
+            "Upload data is larger than the declared size: {} base64 characters for {} bytes (expected about {})",
+            actual_len, declared_size, expected_len
+        )));
+    }
+    Ok(())
+}
+
+// After decoding, make sure the content roughly matches what the client declared
+pub fn verify_decoded_size(decoded_len: usize, declared_size: u64) -> Result<(), AppError> {
+    let decoded_len = decoded_len as u64;
+    if decoded_len.abs_diff(declared_size) > size_tolerance(declared_size) {
+        return Err(AppError::validation("file_size", format!(
+            "Decoded size {} bytes does not match declared size {} bytes",
+            decoded_len, declared_size
+        )));
+    }
+    Ok(())
+}
+
+#[command]
+pub async fn get_upload_limits() -> Result<UploadLimits, String> {
+    Ok(upload_limits())
+}
+
+#[command]
+pub async fn set_upload_limits(app: AppHandle, limits: UploadLimits) -> Result<(), String> {
+    if limits.max_image_bytes == 0 || limits.max_video_bytes == 0 {
+        return Err("Upload limits must be greater than zero".to_string());
+    }
+    let patch = serde_json::json!({
+        "maxImageBytes": limits.max_image_bytes,
+        "maxVideoBytes": limits.max_video_bytes,
+    });
+    update_app_config(app, patch).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose, Engine as _};
+
+    #[test]
+    fn oversized_payload_is_rejected_before_decoding() {
+        let data = general_purpose::STANDARD.encode(vec![0u8; 2048]);
+        let result = validate_base64_payload(&data, 2048, 1024);
+        assert!(matches!(result, Err(AppError::Validation { ref field, .. }) if field == "file_size"), "{:?}", result);
+    }
+
+    #[test]
+    fn payload_larger_than_declared_is_rejected() {
+        let data = general_purpose::STANDARD.encode(vec![0u8; 4096]);
+        let result = validate_base64_payload(&data, 100, 1_000_000);
+        assert!(matches!(result, Err(AppError::Validation { ref field, .. }) if field == "file_data"), "{:?}", result);
+    }
+
+    #[test]
+    fn decoded_size_mismatch_is_rejected() {
+        assert!(verify_decoded_size(1000, 1000).is_ok());
+        assert!(verify_decoded_size(1010, 1000).is_ok());
+        let result = verify_decoded_size(500, 1000);
+        assert!(matches!(result, Err(AppError::Validation { ref field, .. }) if field == "file_size"), "{:?}", result);
+    }
+
+    #[test]
+    fn payload_matching_its_declared_size_is_accepted() {
+        let data = general_purpose::STANDARD.encode(vec![7u8; 1000]);
+        assert!(validate_base64_payload(&data, 1000, 1000).is_ok());
+    }
+}
@@ -1,12 +1,23 @@
 // src-tauri/src/commands/videos.rs
 use std::path::PathBuf;
-use std::fs;
+use tokio::fs;
 use tauri::{AppHandle, Manager, command};
 use serde::{Deserialize, Serialize};
+use schemars::JsonSchema;
 use uuid::Uuid;
 use base64::{Engine as _, engine::general_purpose};
+use tracing::warn;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+use crate::commands::media_library::{read_metadata_file, update_metadata_file, MediaLibraryLocks};
+use crate::commands::media_sniff::{detect_mime, verify_media_type, ALLOWED_VIDEO_TYPES};
+use crate::commands::storage::safe_join;
+use crate::commands::upload_limits::{upload_limits, validate_base64_payload, verify_decoded_size};
+use crate::commands::data_dir::data_dir;
+use crate::commands::disk_space::ensure_free_space;
+use crate::commands::event_lock::ensure_unlocked;
+use crate::error::AppError;
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct StoredVideo {
     pub id: String,
@@ -18,41 +29,64 @@ pub struct StoredVideo {
     pub duration: Option<f64>, // in seconds
     pub uploaded_at: chrono::DateTime<chrono::Utc>,
     pub thumbnail: Option<String>,
+    // None until probed, or when ffprobe isn't installed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playability: Option<VideoPlayability>,
+    // Last strip extracted by ffmpeg, reused while the frame count matches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail_strip: Option<VideoThumbnailStrip>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoThumbnailStrip {
+    // Seconds into the video of each frame
+    pub timestamps: Vec<f64>,
+    // Base64 PNGs, one per timestamp
+    pub frames: Vec<String>,
+    // True when ffmpeg couldn't run and `frames` is just the upload thumbnail
+    #[serde(default)]
+    pub poster_fallback: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoPlayability {
+    pub playable: bool,
+    pub container: Option<String>,
+    pub video_codec: Option<String>,
+    pub audio_codec: Option<String>,
+    // Why the webview can't play it; None when playable
+    pub reason: Option<String>,
+    pub checked_at: chrono::DateTime<chrono::Utc>,
 }
 
-fn get_videos_dir(app: &AppHandle) -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let app_data_dir = app.path().app_data_dir()?;
+pub(crate) async fn get_videos_dir(app: &AppHandle) -> Result<PathBuf, AppError> {
+    let app_data_dir = data_dir(app)?;
     let videos_dir = app_data_dir.join("videos");
     
-    if !videos_dir.exists() {
-        fs::create_dir_all(&videos_dir)?;
-    }
+    fs::create_dir_all(&videos_dir).await?;
     
     Ok(videos_dir)
 }
 
-fn get_metadata_file(app: &AppHandle) -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let videos_dir = get_videos_dir(app)?;
+async fn get_metadata_file(app: &AppHandle) -> Result<PathBuf, AppError> {
+    let videos_dir = get_videos_dir(app).await?;
     Ok(videos_dir.join("metadata.json"))
 }
 
-fn load_video_metadata(app: &AppHandle) -> Result<Vec<StoredVideo>, Box<dyn std::error::Error>> {
-    let metadata_file = get_metadata_file(app)?;
-    
-    if !metadata_file.exists() {
-        return Ok(Vec::new());
-    }
-    
-    let content = fs::read_to_string(metadata_file)?;
-    let videos: Vec<StoredVideo> = serde_json::from_str(&content)?;
-    Ok(videos)
+pub(crate) async fn load_video_metadata(app: &AppHandle) -> Result<Vec<StoredVideo>, AppError> {
+    read_metadata_file(&get_metadata_file(app).await?).await
 }
 
-fn save_video_metadata(app: &AppHandle, videos: &[StoredVideo]) -> Result<(), Box<dyn std::error::Error>> {
-    let metadata_file = get_metadata_file(app)?;
-    let content = serde_json::to_string_pretty(videos)?;
-    fs::write(metadata_file, content)?;
-    Ok(())
+// Every change to metadata.json goes through here so concurrent uploads and deletes can't drop entries
+async fn update_video_metadata<R>(
+    app: &AppHandle,
+    mutate: impl FnOnce(&mut Vec<StoredVideo>) -> Result<R, AppError>,
+) -> Result<R, AppError> {
+    let metadata_file = get_metadata_file(app).await?;
+    let locks = app.state::<MediaLibraryLocks>();
+    update_metadata_file(&locks.videos, &metadata_file, mutate).await
 }
 
 fn create_video_thumbnail(video_data: &[u8], _video_type: &str) -> Option<String> {
@@ -67,6 +101,132 @@ fn create_video_thumbnail(video_data: &[u8], _video_type: &str) -> Option<String
     }
 }
 
+const WEBVIEW_VIDEO_CODECS: [&str; 3] = ["h264", "vp8", "vp9"];
+const WEBVIEW_AUDIO_CODECS: [&str; 5] = ["aac", "mp3", "opus", "vorbis", "flac"];
+const PROBE_TIMEOUT_SECS: u64 = 15;
+const FRAME_TIMEOUT_SECS: u64 = 15;
+const MAX_STRIP_FRAMES: usize = 24;
+const STRIP_FRAME_WIDTH: u32 = 320;
+
+fn first_codec(probe: &serde_json::Value, codec_type: &str) -> Option<String> {
+    probe.get("streams")?.as_array()?.iter()
+        .find(|stream| stream.get("codec_type").and_then(|t| t.as_str()) == Some(codec_type))
+        .and_then(|stream| stream.get("codec_name")?.as_str())
+        .map(|codec| codec.to_lowercase())
+}
+
+// Decide from `ffprobe -of json -show_format -show_streams` output whether the
+// webview can play the file: H.264/VP8/VP9 video in an MP4 or WebM container
+pub fn playability_from_probe(probe: &serde_json::Value) -> VideoPlayability {
+    let format_name = probe.get("format")
+        .and_then(|f| f.get("format_name"))
+        .and_then(|f| f.as_str())
+        .unwrap_or("")
+        .to_lowercase();
+    let video_codec = first_codec(probe, "video");
+    let audio_codec = first_codec(probe, "audio");
+
+    let formats: Vec<&str> = format_name.split(',').collect();
+    // ffprobe reports MKV and WebM alike as "matroska,webm"; WebM only allows VP8/VP9 video
+    let container = if formats.contains(&"mp4") {
+        Some("mp4")
+    } else if formats.contains(&"webm") && matches!(video_codec.as_deref(), Some("vp8" | "vp9")) {
+        Some("webm")
+    } else if formats.contains(&"matroska") {
+        Some("matroska")
+    } else {
+        formats.first().filter(|f| !f.is_empty()).copied()
+    };
+
+    let reason = match (container, video_codec.as_deref()) {
+        (_, None) => Some("No video stream found".to_string()),
+        (Some("mp4" | "webm"), Some(codec)) if !WEBVIEW_VIDEO_CODECS.contains(&codec) => {
+            Some(format!("Video codec {} is not supported by the webview", codec))
+        }
+        (Some("mp4" | "webm"), _) => audio_codec.as_deref()
+            .filter(|codec| !WEBVIEW_AUDIO_CODECS.contains(codec))
+            .map(|codec| format!("Audio codec {} is not supported by the webview", codec)),
+        (container, _) => Some(format!(
+            "Container {} is not supported by the webview; convert to MP4 or WebM",
+            container.unwrap_or("unknown")
+        )),
+    };
+
+    VideoPlayability {
+        playable: reason.is_none(),
+        container: container.map(|c| c.to_string()),
+        video_codec,
+        audio_codec,
+        reason,
+        checked_at: chrono::Utc::now(),
+    }
+}
+
+// Returns None when ffprobe isn't installed or can't read the file
+async fn probe_video(path: &str) -> Option<(VideoPlayability, Option<f64>)> {
+    let output = tokio::process::Command::new("ffprobe")
+        .args(["-v", "error", "-of", "json", "-show_format", "-show_streams", path])
+        .kill_on_drop(true)
+        .output();
+    let output = match tokio::time::timeout(std::time::Duration::from_secs(PROBE_TIMEOUT_SECS), output).await {
+        Ok(Ok(output)) if output.status.success() => output,
+        Ok(Ok(output)) => {
+            warn!("⚠️ ffprobe failed for {}: {}", path, String::from_utf8_lossy(&output.stderr).trim());
+            return None;
+        }
+        Ok(Err(e)) => {
+            warn!("⚠️ Could not run ffprobe, skipping playability check: {}", e);
+            return None;
+        }
+        Err(_) => {
+            warn!("⚠️ ffprobe timed out for {}", path);
+            return None;
+        }
+    };
+
+    let probe: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+    let duration = probe.get("format")
+        .and_then(|f| f.get("duration"))
+        .and_then(|d| d.as_str())
+        .and_then(|d| d.parse::<f64>().ok());
+    Some((playability_from_probe(&probe), duration))
+}
+
+// Frame times for a strip: the middle of each of `count` equal slices, so the
+// first and last frames stay clear of the black lead-in and the end of stream
+pub fn strip_timestamps(duration: f64, count: usize) -> Vec<f64> {
+    if count == 0 || !duration.is_finite() || duration <= 0.0 {
+        return Vec::new();
+    }
+    let slice = duration / count as f64;
+    (0..count).map(|i| (i as f64 + 0.5) * slice).collect()
+}
+
+// One PNG frame at `timestamp`, scaled down for the strip. None when ffmpeg
+// isn't installed or can't decode that point
+async fn extract_frame(path: &str, timestamp: f64) -> Option<Vec<u8>> {
+    let output = tokio::process::Command::new("ffmpeg")
+        .args(["-v", "error", "-ss", &format!("{:.3}", timestamp), "-i", path, "-frames:v", "1"])
+        .args(["-vf", &format!("scale={}:-2", STRIP_FRAME_WIDTH), "-f", "image2pipe", "-vcodec", "png", "-"])
+        .kill_on_drop(true)
+        .output();
+    match tokio::time::timeout(std::time::Duration::from_secs(FRAME_TIMEOUT_SECS), output).await {
+        Ok(Ok(output)) if output.status.success() && !output.stdout.is_empty() => Some(output.stdout),
+        Ok(Ok(output)) => {
+            warn!("⚠️ ffmpeg could not extract a frame at {:.3}s from {}: {}", timestamp, path, String::from_utf8_lossy(&output.stderr).trim());
+            None
+        }
+        Ok(Err(e)) => {
+            warn!("⚠️ Could not run ffmpeg, falling back to the poster thumbnail: {}", e);
+            None
+        }
+        Err(_) => {
+            warn!("⚠️ ffmpeg timed out extracting a frame from {}", path);
+            None
+        }
+    }
+}
+
 #[command]
 pub async fn upload_video(
     app: AppHandle,
@@ -74,105 +234,302 @@ pub async fn upload_video(
     file_data: String,
     file_type: String,
     file_size: u64,
-) -> Result<StoredVideo, String> {
+) -> Result<StoredVideo, AppError> {
+    ensure_unlocked("Uploading videos")?;
+    // Reject oversized or mismatched payloads before decoding
+    validate_base64_payload(&file_data, file_size, upload_limits().max_video_bytes)?;
+    
     // Decode base64 data
     let video_data = general_purpose::STANDARD
         .decode(&file_data)
-        .map_err(|e| format!("Failed to decode video data: {}", e))?;
+        .map_err(|e| AppError::validation("file_data", format!("Failed to decode video data: {}", e)))?;
+    verify_decoded_size(video_data.len(), file_size)?;
+    
+    // The stored type and extension come from the content, not the client's claim
+    let file_type = verify_media_type(&video_data, &file_type, &ALLOWED_VIDEO_TYPES)?.to_string();
     
     // Generate unique ID and filename
     let id = Uuid::new_v4().to_string();
-    let file_extension = file_name.split('.').last().unwrap_or("mp4");
+    let file_extension = match file_type.as_str() {
+        "video/webm" => "webm",
+        "video/quicktime" => "mov",
+        _ => "mp4",
+    };
     let stored_filename = format!("{}.{}", id, file_extension);
     
     // Get videos directory
-    let videos_dir = get_videos_dir(&app)
-        .map_err(|e| format!("Failed to get videos directory: {}", e))?;
+    let videos_dir = get_videos_dir(&app).await
+        .map_err(|e| AppError::io(format!("Failed to get videos directory: {}", e)))?;
     
     // Save video file
+    ensure_free_space(&videos_dir, video_data.len() as u64)?;
     let file_path = videos_dir.join(&stored_filename);
-    fs::write(&file_path, &video_data)
-        .map_err(|e| format!("Failed to save video file: {}", e))?;
+    fs::write(&file_path, &video_data).await
+        .map_err(|e| AppError::io(format!("Failed to save video file: {}", e)))?;
     
     // Create thumbnail
     let thumbnail = create_video_thumbnail(&video_data, &file_type);
     
+    // Unplayable files are still stored; the result is surfaced so the UI can warn
+    let path = file_path.to_string_lossy().to_string();
+    let (playability, duration) = probe_video(&path).await.unzip();
+    if let Some(reason) = playability.as_ref().and_then(|p| p.reason.as_ref()) {
+        warn!("⚠️ Uploaded video {} may not play: {}", file_name, reason);
+    }
+    
     // Create metadata entry
     let stored_video = StoredVideo {
         id: id.clone(),
         name: stored_filename.clone(),
         original_name: file_name,
-        path: file_path.to_string_lossy().to_string(),
+        path,
         size: file_size,
         r#type: file_type,
-        duration: None, // TODO: Extract actual duration if needed
+        duration: duration.flatten(),
         uploaded_at: chrono::Utc::now(),
         thumbnail,
+        playability,
+        thumbnail_strip: None,
     };
     
-    // Load existing metadata
-    let mut videos = load_video_metadata(&app)
-        .map_err(|e| format!("Failed to load metadata: {}", e))?;
-    
     // Add new video
-    videos.push(stored_video.clone());
-    
-    // Save updated metadata
-    save_video_metadata(&app, &videos)
-        .map_err(|e| format!("Failed to save metadata: {}", e))?;
+    update_video_metadata(&app, |videos| {
+        videos.push(stored_video.clone());
+        Ok(())
+    }).await?;
     
     Ok(stored_video)
 }
 
 #[command]
-pub async fn get_stored_videos(app: AppHandle) -> Result<Vec<StoredVideo>, String> {
-    load_video_metadata(&app)
-        .map_err(|e| format!("Failed to load videos: {}", e))
+pub async fn get_stored_videos(app: AppHandle) -> Result<Vec<StoredVideo>, AppError> {
+    load_video_metadata(&app).await
+        .map_err(|e| AppError::io(format!("Failed to load videos: {}", e)))
 }
 
 #[command]
-pub async fn delete_video(app: AppHandle, video_id: String) -> Result<(), String> {
-    // Load existing metadata
-    let mut videos = load_video_metadata(&app)
-        .map_err(|e| format!("Failed to load metadata: {}", e))?;
+pub async fn delete_video(app: AppHandle, video_id: String) -> Result<(), AppError> {
+    ensure_unlocked("Deleting videos")?;
+    let videos_dir = get_videos_dir(&app).await?;
     
-    // Find the video to delete
-    let video_index = videos.iter()
-        .position(|video| video.id == video_id)
-        .ok_or("Video not found")?;
+    // Remove from metadata first; a leftover file is harmless, an entry without one isn't
+    let video = update_video_metadata(&app, |videos| {
+        let video_index = videos.iter()
+            .position(|video| video.id == video_id)
+            .ok_or_else(|| AppError::not_found("Video"))?;
+        Ok(videos.remove(video_index))
+    }).await?;
     
-    let video = &videos[video_index];
+    // Resolve from the stored name so an edited metadata file can't point the delete elsewhere
+    let video_path = safe_join(&videos_dir, &video.name)?;
     
     // Delete the actual file
-    if let Err(e) = fs::remove_file(&video.path) {
+    if let Err(e) = fs::remove_file(&video_path).await {
         eprintln!("Warning: Failed to delete video file {}: {}", video.path, e);
     }
     
-    // Remove from metadata
-    videos.remove(video_index);
-    
-    // Save updated metadata
-    save_video_metadata(&app, &videos)
-        .map_err(|e| format!("Failed to save metadata: {}", e))?;
-    
     Ok(())
 }
 
+// Drop the metadata entry for a video whose file is gone; used by the integrity check
+pub(crate) async fn prune_video_metadata(app: &AppHandle, video_id: &str) -> Result<(), AppError> {
+    update_video_metadata(app, |videos| {
+        let before = videos.len();
+        videos.retain(|video| video.id != video_id);
+        if videos.len() == before {
+            return Err(AppError::not_found("Video"));
+        }
+        Ok(())
+    }).await
+}
+
+// Record a file already in the videos folder that has no metadata entry,
+// keeping a `<uuid>` file stem as the id like adopt_image_file does
+pub(crate) async fn adopt_video_file(app: &AppHandle, file_name: &str) -> Result<StoredVideo, AppError> {
+    let video_path = safe_join(&get_videos_dir(app).await?, file_name)?;
+    let video_data = fs::read(&video_path).await?;
+    let file_type = verify_media_type(&video_data, detect_mime(&video_data), &ALLOWED_VIDEO_TYPES)?.to_string();
+
+    let id = std::path::Path::new(file_name).file_stem()
+        .and_then(|stem| stem.to_str())
+        .filter(|stem| Uuid::parse_str(stem).is_ok())
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+    let modified = fs::metadata(&video_path).await?.modified().ok();
+
+    let path = video_path.to_string_lossy().to_string();
+    let (playability, duration) = probe_video(&path).await.unzip();
+    let stored_video = StoredVideo {
+        id: id.clone(),
+        name: file_name.to_string(),
+        original_name: file_name.to_string(),
+        path,
+        size: video_data.len() as u64,
+        thumbnail: create_video_thumbnail(&video_data, &file_type),
+        r#type: file_type,
+        duration: duration.flatten(),
+        uploaded_at: modified.map(chrono::DateTime::<chrono::Utc>::from).unwrap_or_else(chrono::Utc::now),
+        playability,
+        thumbnail_strip: None,
+    };
+
+    update_video_metadata(app, |videos| {
+        if videos.iter().any(|video| video.id == id || video.name == stored_video.name) {
+            return Err(AppError::conflict(format!("{} is already in the video library", file_name)));
+        }
+        videos.push(stored_video.clone());
+        Ok(())
+    }).await?;
+    Ok(stored_video)
+}
+
 #[command]
-pub async fn get_video_data(app: AppHandle, video_id: String) -> Result<String, String> {
+pub async fn get_video_data(app: AppHandle, video_id: String) -> Result<String, AppError> {
     // Load metadata to find the video
-    let videos = load_video_metadata(&app)
-        .map_err(|e| format!("Failed to load metadata: {}", e))?;
+    let videos = load_video_metadata(&app).await
+        .map_err(|e| AppError::io(format!("Failed to load metadata: {}", e)))?;
     
     let video = videos.iter()
         .find(|video| video.id == video_id)
-        .ok_or("Video not found")?;
+        .ok_or_else(|| AppError::not_found("Video"))?;
     
     // Read the video file
-    let video_data = fs::read(&video.path)
-        .map_err(|e| format!("Failed to read video file: {}", e))?;
+    let video_path = safe_join(&get_videos_dir(&app).await?, &video.name)?;
+    let video_data = fs::read(&video_path).await
+        .map_err(|e| AppError::io(format!("Failed to read video file: {}", e)))?;
     
     // Encode as base64
     let base64_data = general_purpose::STANDARD.encode(&video_data);
     Ok(format!("data:{};base64,{}", video.r#type, base64_data))
-}
\ No newline at end of file
+}
+
+#[command]
+pub async fn check_video_playability(app: AppHandle, video_id: String) -> Result<VideoPlayability, AppError> {
+    let videos = load_video_metadata(&app).await
+        .map_err(|e| AppError::io(format!("Failed to load metadata: {}", e)))?;
+    
+    let video = videos.iter()
+        .find(|video| video.id == video_id)
+        .ok_or_else(|| AppError::not_found("Video"))?;
+    
+    // Probing can take seconds, so it runs before taking the metadata lock
+    let (playability, duration) = probe_video(&video.path).await
+        .ok_or_else(|| AppError::external("ffprobe", "Could not probe video; is ffprobe installed?"))?;
+    
+    update_video_metadata(&app, |videos| {
+        let video = videos.iter_mut()
+            .find(|video| video.id == video_id)
+            .ok_or_else(|| AppError::not_found("Video"))?;
+        video.playability = Some(playability.clone());
+        if video.duration.is_none() {
+            video.duration = duration;
+        }
+        Ok(())
+    }).await?;
+    
+    Ok(playability)
+}
+
+// `count` evenly spaced frames for scrubbing. Falls back to the upload
+// thumbnail when ffmpeg is unavailable; only real strips are cached.
+#[command]
+pub async fn get_video_thumbnail_strip(app: AppHandle, video_id: String, count: usize) -> Result<VideoThumbnailStrip, AppError> {
+    if count == 0 || count > MAX_STRIP_FRAMES {
+        return Err(AppError::validation("count", format!("Frame count must be between 1 and {}", MAX_STRIP_FRAMES)));
+    }
+
+    let videos = load_video_metadata(&app).await?;
+    let video = videos.into_iter()
+        .find(|video| video.id == video_id)
+        .ok_or_else(|| AppError::not_found("Video"))?;
+
+    if let Some(strip) = video.thumbnail_strip.as_ref().filter(|strip| strip.frames.len() == count) {
+        return Ok(strip.clone());
+    }
+
+    let poster = || VideoThumbnailStrip {
+        timestamps: vec![0.0],
+        frames: video.thumbnail.iter().cloned().collect(),
+        poster_fallback: true,
+    };
+
+    let duration = match video.duration {
+        Some(duration) => Some(duration),
+        None => probe_video(&video.path).await.and_then(|(_, duration)| duration),
+    };
+    let Some(duration) = duration else {
+        return Ok(poster());
+    };
+
+    // Extraction can take seconds per frame, so it runs before taking the metadata lock
+    let timestamps = strip_timestamps(duration, count);
+    let mut frames = Vec::with_capacity(timestamps.len());
+    for timestamp in &timestamps {
+        match extract_frame(&video.path, *timestamp).await {
+            Some(png) => frames.push(general_purpose::STANDARD.encode(png)),
+            None => return Ok(poster()),
+        }
+    }
+    let strip = VideoThumbnailStrip { timestamps, frames, poster_fallback: false };
+
+    update_video_metadata(&app, |videos| {
+        let video = videos.iter_mut()
+            .find(|video| video.id == video_id)
+            .ok_or_else(|| AppError::not_found("Video"))?;
+        video.thumbnail_strip = Some(strip.clone());
+        if video.duration.is_none() {
+            video.duration = Some(duration);
+        }
+        Ok(())
+    }).await?;
+
+    Ok(strip)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(format_name: &str, video: &str, audio: &str) -> serde_json::Value {
+        serde_json::json!({
+            "format": { "format_name": format_name, "duration": "12.5" },
+            "streams": [
+                { "codec_type": "video", "codec_name": video },
+                { "codec_type": "audio", "codec_name": audio }
+            ]
+        })
+    }
+
+    #[test]
+    fn playability_follows_container_and_codecs() {
+        let mp4 = playability_from_probe(&probe("mov,mp4,m4a,3gp,3g2,mj2", "h264", "aac"));
+        assert!(mp4.playable);
+        assert_eq!(mp4.container.as_deref(), Some("mp4"));
+
+        let webm = playability_from_probe(&probe("matroska,webm", "vp9", "opus"));
+        assert!(webm.playable);
+        assert_eq!(webm.container.as_deref(), Some("webm"));
+
+        let mkv = playability_from_probe(&probe("matroska,webm", "h264", "aac"));
+        assert!(!mkv.playable);
+        assert_eq!(mkv.container.as_deref(), Some("matroska"));
+
+        let avi = playability_from_probe(&probe("avi", "mpeg4", "mp3"));
+        assert!(!avi.playable);
+        assert!(avi.reason.unwrap().contains("avi"));
+
+        let hevc = playability_from_probe(&probe("mov,mp4,m4a,3gp,3g2,mj2", "hevc", "aac"));
+        assert!(!hevc.playable);
+        assert!(hevc.reason.unwrap().contains("hevc"));
+    }
+
+
+    #[test]
+    fn strip_timestamps_are_evenly_spaced() {
+        assert_eq!(strip_timestamps(10.0, 5), [1.0, 3.0, 5.0, 7.0, 9.0]);
+        assert_eq!(strip_timestamps(60.0, 1), [30.0]);
+        assert!(strip_timestamps(10.0, 0).is_empty());
+        assert!(strip_timestamps(0.0, 4).is_empty());
+        assert!(strip_timestamps(f64::NAN, 4).is_empty());
+    }
+}
@@ -0,0 +1,396 @@
+// src-tauri/src/commands/vmix.rs
+//
+// Flat data sources for production switchers (vMix and similar), which can poll a
+// URL but cannot navigate nested JSON. Endpoints:
+//
+//   GET /vmix/game/:id.xml     - current GameState (`id` is accepted for URL stability;
+//                                there is a single game state, use "current")
+//   GET /vmix/court/:name.json - latest IonCourt match data for a court
+//
+// Field names are part of the contract with operators' data mappings; add new
+// fields rather than renaming existing ones.
+//
+// Game fields:  homeName, homeAbbr, awayName, awayAbbr, homeScore, awayScore,
+//               clock, period, active ("1"/"0"), sport
+// Court fields: court, matchId, status, matchType, p1Name, p2Name,
+//               p1Sets, p2Sets (sets won), p1Games, p2Games (current set),
+//               p1Points, p2Points, p1Tiebreak, p2Tiebreak (current set, "" if none),
+//               currentSet, p1Set1..p1Set5, p2Set1..p2Set5 ("" for unplayed sets)
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use lazy_static::lazy_static;
+use tauri::State;
+use tracing::{error, info, warn};
+
+use crate::commands::live_data::court_data;
+use crate::commands::scoreboard::{GameState, ScoreboardState};
+use crate::error::AppError;
+
+const MAX_SETS: usize = 5;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VmixServerStatus {
+    pub is_running: bool,
+    pub port: u16,
+    pub requests_served: u64,
+    pub last_error: Option<String>,
+}
+
+lazy_static! {
+    static ref VMIX_SERVER_STATUS: Arc<Mutex<VmixServerStatus>> = Arc::new(Mutex::new(VmixServerStatus::default()));
+    static ref VMIX_SERVER_TASK: Arc<Mutex<Option<JoinHandle<()>>>> = Arc::new(Mutex::new(None));
+}
+
+fn field(name: &str, value: impl ToString) -> (String, String) {
+    (name.to_string(), value.to_string())
+}
+
+pub fn flatten_game_state(game_state: &GameState) -> Vec<(String, String)> {
+    vec![
+        field("homeName", &game_state.home_team.name),
+        field("homeAbbr", game_state.home_team.abbreviation.as_deref().unwrap_or("")),
+        field("awayName", &game_state.away_team.name),
+        field("awayAbbr", game_state.away_team.abbreviation.as_deref().unwrap_or("")),
+        field("homeScore", game_state.home_score),
+        field("awayScore", game_state.away_score),
+        field("clock", &game_state.time_remaining),
+        field("period", game_state.period),
+        field("active", if game_state.is_game_active { "1" } else { "0" }),
+        field("sport", &game_state.sport),
+    ]
+}
+
+fn value_text(value: Option<&serde_json::Value>) -> String {
+    match value {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(serde_json::Value::Null) | None => String::new(),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn side_name(match_data: &serde_json::Value, side_index: usize) -> String {
+    match_data.get("sides")
+        .and_then(|sides| sides.get(side_index))
+        .and_then(|side| side.get("players"))
+        .and_then(|players| players.as_array())
+        .map(|players| {
+            players.iter()
+                .filter_map(|player| player.get("participant")?.get("last_name")?.as_str())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .unwrap_or_default()
+}
+
+// Flatten IonCourt match data (the `data` object of a MATCH message)
+pub fn flatten_court_data(court_name: &str, match_data: &serde_json::Value) -> Vec<(String, String)> {
+    let score = match_data.get("score");
+    let sets: Vec<&serde_json::Value> = score
+        .and_then(|s| s.get("sets"))
+        .and_then(|s| s.as_array())
+        .map(|sets| sets.iter().collect())
+        .unwrap_or_default();
+
+    let set_score = |set: &serde_json::Value, key: &str| set.get(key).and_then(|v| v.as_i64());
+
+    let (mut p1_sets, mut p2_sets) = (0, 0);
+    for set in sets.iter().filter(|set| set.get("isCompleted").and_then(|c| c.as_bool()).unwrap_or(false)) {
+        match (set_score(set, "side1Score"), set_score(set, "side2Score")) {
+            (Some(a), Some(b)) if a > b => p1_sets += 1,
+            (Some(a), Some(b)) if b > a => p2_sets += 1,
+            _ => {}
+        }
+    }
+
+    let current = sets.last();
+    let current_value = |key: &str| value_text(current.and_then(|set| set.get(key)));
+
+    let mut fields = vec![
+        field("court", court_name),
+        field("matchId", value_text(match_data.get("matchId"))),
+        field("status", value_text(match_data.get("matchStatus"))),
+        field("matchType", value_text(match_data.get("matchType"))),
+        field("p1Name", side_name(match_data, 0)),
+        field("p2Name", side_name(match_data, 1)),
+        field("p1Sets", p1_sets),
+        field("p2Sets", p2_sets),
+        field("p1Games", current_value("side1Score")),
+        field("p2Games", current_value("side2Score")),
+        field("p1Points", value_text(score.and_then(|s| s.get("side1PointScore")))),
+        field("p2Points", value_text(score.and_then(|s| s.get("side2PointScore")))),
+        field("p1Tiebreak", current_value("side1TiebreakScore")),
+        field("p2Tiebreak", current_value("side2TiebreakScore")),
+        field("currentSet", sets.len()),
+    ];
+
+    for side in 1..=2 {
+        for set_number in 1..=MAX_SETS {
+            let value = sets.get(set_number - 1)
+                .map(|set| value_text(set.get(format!("side{}Score", side).as_str())))
+                .unwrap_or_default();
+            fields.push(field(&format!("p{}Set{}", side, set_number), value));
+        }
+    }
+
+    fields
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+pub fn fields_to_xml(root: &str, fields: &[(String, String)]) -> String {
+    let mut xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<{}>\n", root);
+    for (name, value) in fields {
+        xml.push_str(&format!("  <{0}>{1}</{0}>\n", name, escape_xml(value)));
+    }
+    xml.push_str(&format!("</{}>\n", root));
+    xml
+}
+
+pub fn fields_to_json(fields: &[(String, String)]) -> serde_json::Value {
+    serde_json::Value::Object(
+        fields.iter()
+            .map(|(name, value)| (name.clone(), serde_json::Value::String(value.clone())))
+            .collect()
+    )
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+                match u8::from_str_radix(hex, 16) {
+                    Ok(byte) => {
+                        decoded.push(byte);
+                        i += 3;
+                        continue;
+                    }
+                    Err(_) => decoded.push(b'%'),
+                }
+            }
+            b'+' => decoded.push(b' '),
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+// Returns (status line, content type, body)
+async fn route(path: &str, game_state: &Arc<std::sync::Mutex<Option<GameState>>>) -> (&'static str, &'static str, String) {
+    let path = path.split('?').next().unwrap_or(path);
+
+    if let Some(id) = path.strip_prefix("/vmix/game/").and_then(|rest| rest.strip_suffix(".xml")) {
+        if id.is_empty() {
+            return ("404 Not Found", "text/plain", "Game not found".to_string());
+        }
+        let current = game_state.lock().ok().and_then(|g| g.clone());
+        return match current {
+            Some(game) => ("200 OK", "application/xml", fields_to_xml("game", &flatten_game_state(&game))),
+            None => ("404 Not Found", "text/plain", "No game state".to_string()),
+        };
+    }
+
+    if let Some(name) = path.strip_prefix("/vmix/court/").and_then(|rest| rest.strip_suffix(".json")) {
+        let court_name = percent_decode(name);
+        return match court_data(&court_name).await {
+            Some(match_data) => (
+                "200 OK",
+                "application/json",
+                fields_to_json(&flatten_court_data(&court_name, &match_data)).to_string(),
+            ),
+            None => ("404 Not Found", "text/plain", format!("No data for court: {}", court_name)),
+        };
+    }
+
+    ("404 Not Found", "text/plain", "Not found".to_string())
+}
+
+async fn handle_connection(mut stream: TcpStream, game_state: Arc<std::sync::Mutex<Option<GameState>>>) -> Result<(), AppError> {
+    let mut buffer = [0u8; 4096];
+    let read = stream.read(&mut buffer).await
+        .map_err(|e| AppError::io(format!("Failed to read request: {}", e)))?;
+    let request = String::from_utf8_lossy(&buffer[..read]);
+
+    let mut parts = request.lines().next().unwrap_or("").split_whitespace();
+    let (status, content_type, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some(path)) => route(path, &game_state).await,
+        _ => ("405 Method Not Allowed", "text/plain", "Only GET is supported".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\nAccess-Control-Allow-Origin: *\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{}",
+        status, content_type, body.len(), body
+    );
+    stream.write_all(response.as_bytes()).await
+        .map_err(|e| AppError::io(format!("Failed to write response: {}", e)))?;
+    stream.shutdown().await.ok();
+    Ok(())
+}
+
+// Port the data server is listening on, if it is running
+pub(crate) async fn vmix_server_port() -> Option<u16> {
+    let status = VMIX_SERVER_STATUS.lock().await;
+    status.is_running.then_some(status.port)
+}
+
+#[tauri::command]
+pub async fn start_vmix_server(state: State<'_, ScoreboardState>, port: u16) -> Result<String, AppError> {
+    let mut task = VMIX_SERVER_TASK.lock().await;
+    if task.as_ref().map(|handle| !handle.is_finished()).unwrap_or(false) {
+        return Err(AppError::conflict("vMix data server already running"));
+    }
+
+    let listener = TcpListener::bind(("0.0.0.0", port)).await
+        .map_err(|e| AppError::io(format!("Failed to bind vMix data server on port {}: {}", port, e)))?;
+
+    *VMIX_SERVER_STATUS.lock().await = VmixServerStatus {
+        is_running: true,
+        port,
+        ..VmixServerStatus::default()
+    };
+
+    let game_state = Arc::clone(&state.game_state);
+    let handle = tokio::spawn(async move {
+        loop {
+            let (stream, _) = match listener.accept().await {
+                Ok(connection) => connection,
+                Err(e) => {
+                    error!("vMix data server accept error: {}", e);
+                    VMIX_SERVER_STATUS.lock().await.last_error = Some(e.to_string());
+                    continue;
+                }
+            };
+
+            let game_state = Arc::clone(&game_state);
+            tokio::spawn(async move {
+                let result = handle_connection(stream, game_state).await;
+                let mut status = VMIX_SERVER_STATUS.lock().await;
+                match result {
+                    Ok(_) => status.requests_served += 1,
+                    Err(e) => {
+                        warn!("vMix data server error: {}", e);
+                        status.last_error = Some(e.to_string());
+                    }
+                }
+            });
+        }
+    });
+
+    *task = Some(handle);
+    info!("🎬 Started vMix data server on port {}", port);
+    Ok(format!("vMix data server started on port {}", port))
+}
+
+#[tauri::command]
+pub async fn stop_vmix_server() -> Result<String, AppError> {
+    let mut task = VMIX_SERVER_TASK.lock().await;
+
+    if let Some(handle) = task.take() {
+        handle.abort();
+        VMIX_SERVER_STATUS.lock().await.is_running = false;
+        info!("🛑 Stopped vMix data server");
+        Ok("vMix data server stopped".to_string())
+    } else {
+        Err(AppError::conflict("vMix data server is not running"))
+    }
+}
+
+#[tauri::command]
+pub async fn get_vmix_server_status() -> Result<VmixServerStatus, AppError> {
+    Ok(VMIX_SERVER_STATUS.lock().await.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value<'a>(fields: &'a [(String, String)], name: &str) -> &'a str {
+        fields.iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+            .unwrap_or_else(|| panic!("missing field {}", name))
+    }
+
+    #[test]
+    fn flattens_a_basketball_game() {
+        let game_state: GameState = serde_json::from_value(serde_json::json!({
+            "home_team": { "id": "h", "name": "Hawks", "abbreviation": "HAW", "logo_url": null, "primary_color": null, "secondary_color": null },
+            "away_team": { "id": "a", "name": "Bears", "abbreviation": null, "logo_url": null, "primary_color": null, "secondary_color": null },
+            "home_score": 58,
+            "away_score": 61,
+            "period": 3,
+            "time_remaining": "04:12",
+            "is_game_active": true,
+            "sport": "basketball",
+            "metadata": {},
+        })).unwrap();
+        let fields = flatten_game_state(&game_state);
+
+        let names: Vec<&str> = fields.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["homeName", "homeAbbr", "awayName", "awayAbbr", "homeScore", "awayScore", "clock", "period", "active", "sport"]);
+        assert_eq!(value(&fields, "homeAbbr"), "HAW");
+        assert_eq!(value(&fields, "awayAbbr"), "");
+        assert_eq!(value(&fields, "awayScore"), "61");
+        assert_eq!(value(&fields, "clock"), "04:12");
+        assert_eq!(value(&fields, "active"), "1");
+    }
+
+    #[test]
+    fn flattens_a_tennis_match() {
+        let match_data = serde_json::json!({
+            "matchId": "M-12",
+            "matchStatus": "IN_PROGRESS",
+            "matchType": "SINGLES",
+            "sides": [
+                { "players": [{ "participant": { "last_name": "Smith" } }] },
+                { "players": [{ "participant": { "last_name": "Garcia" } }] },
+            ],
+            "score": {
+                "side1PointScore": "40",
+                "side2PointScore": "15",
+                "sets": [
+                    { "side1Score": 6, "side2Score": 4, "isCompleted": true },
+                    { "side1Score": 6, "side2Score": 7, "side1TiebreakScore": 5, "side2TiebreakScore": 7, "isCompleted": true },
+                    { "side1Score": 2, "side2Score": 1, "isCompleted": false },
+                ]
+            }
+        });
+        let fields = flatten_court_data("Court 1", &match_data);
+
+        assert_eq!(value(&fields, "court"), "Court 1");
+        assert_eq!(value(&fields, "matchId"), "M-12");
+        assert_eq!(value(&fields, "p1Name"), "Smith");
+        assert_eq!(value(&fields, "p2Name"), "Garcia");
+        assert_eq!(value(&fields, "p1Sets"), "1");
+        assert_eq!(value(&fields, "p2Sets"), "1");
+        assert_eq!(value(&fields, "p1Games"), "2");
+        assert_eq!(value(&fields, "p2Games"), "1");
+        assert_eq!(value(&fields, "p1Points"), "40");
+        assert_eq!(value(&fields, "p1Tiebreak"), "");
+        assert_eq!(value(&fields, "currentSet"), "3");
+        assert_eq!(value(&fields, "p2Set2"), "7");
+        assert_eq!(value(&fields, "p1Set4"), "");
+        assert_eq!(value(&fields, "p2Set5"), "");
+    }
+
+    #[test]
+    fn xml_escapes_values() {
+        let xml = fields_to_xml("court", &[field("p1Name", "Smith & Jones")]);
+        assert!(xml.contains("<p1Name>Smith &amp; Jones</p1Name>"));
+    }
+}
@@ -0,0 +1,89 @@
+// src-tauri/src/commands/window_updates.rs
+//
+// Per-window holds: a paused scoreboard window stops receiving update events
+// (scores, clocks, resolved bindings) while every other window and the
+// backend state keep moving. On unpause the window is sent the latest game
+// state, and its bindings are resent on the resolver's next tick.
+//
+// Events are held back with `emit_filter`, which only sees listener targets,
+// so a window has to listen on its own target (`getCurrentWebviewWindow().listen`)
+// for the hold to apply; a global `listen()` still receives everything.
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, EventTarget, Manager, State};
+use tracing::info;
+
+use crate::commands::binding_resolver::resend_window_bindings;
+use crate::commands::events::BackendEvent;
+use crate::commands::scoreboard::ScoreboardState;
+use crate::error::AppError;
+
+lazy_static! {
+    static ref PAUSED_WINDOWS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+pub fn is_window_paused(window_id: &str) -> bool {
+    PAUSED_WINDOWS.lock().map(|paused| paused.contains(window_id)).unwrap_or(false)
+}
+
+// `app.emit` for update events, skipping any paused windows
+pub fn emit_to_unpaused<S: Serialize + Clone>(app: &AppHandle, event: BackendEvent, payload: S) -> tauri::Result<()> {
+    let paused = PAUSED_WINDOWS.lock().map(|paused| paused.clone()).unwrap_or_default();
+    if paused.is_empty() {
+        return app.emit(event.as_str(), payload);
+    }
+    app.emit_filter(event.as_str(), payload, |target| match target {
+        EventTarget::Window { label }
+        | EventTarget::Webview { label }
+        | EventTarget::WebviewWindow { label }
+        | EventTarget::AnyLabel { label } => !paused.contains(label),
+        _ => true,
+    })
+}
+
+// Returns the windows paused after the change
+#[tauri::command]
+pub async fn set_window_updates_paused(
+    app: AppHandle,
+    state: State<'_, ScoreboardState>,
+    window_id: String,
+    paused: bool,
+) -> Result<Vec<String>, AppError> {
+    if window_id == "main" {
+        return Err(AppError::validation("window_id", "The main window can't be paused"));
+    }
+    if paused && app.get_webview_window(&window_id).is_none() {
+        return Err(AppError::not_found(format!("Window '{}'", window_id)));
+    }
+
+    let changed = {
+        let mut paused_windows = PAUSED_WINDOWS.lock()?;
+        if paused { paused_windows.insert(window_id.clone()) } else { paused_windows.remove(&window_id) }
+    };
+
+    if changed && !paused {
+        // Catch the window up on everything it missed while held
+        let game_state = state.game_state.lock()?.clone();
+        if let Some(game_state) = game_state {
+            app.emit_to(window_id.as_str(), BackendEvent::GameStateUpdated.as_str(), &game_state)?;
+        }
+        resend_window_bindings(&window_id).await;
+    }
+    if changed {
+        info!("⏸️ Updates to window {} {}", window_id, if paused { "paused" } else { "resumed" });
+    }
+
+    let mut windows: Vec<String> = PAUSED_WINDOWS.lock()?.iter().cloned().collect();
+    windows.sort();
+    Ok(windows)
+}
+
+#[tauri::command]
+pub async fn get_paused_windows() -> Result<Vec<String>, AppError> {
+    let mut windows: Vec<String> = PAUSED_WINDOWS.lock()?.iter().cloned().collect();
+    windows.sort();
+    Ok(windows)
+}
@@ -0,0 +1,166 @@
+// src-tauri/src/error.rs
+//
+// Error type for commands. It serializes as `{ code, message, details }`:
+// `message` keeps the text commands used to return as a plain string, and
+// `code` is stable so the frontend can branch on it (and localize) without
+// matching on text.
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("{0}")]
+    Io(String),
+
+    #[error("{what} not found")]
+    NotFound { what: String },
+
+    #[error("{message}")]
+    Validation { field: String, message: String },
+
+    #[error("{0}")]
+    Conflict(String),
+
+    #[error("{message}")]
+    External { service: String, message: String },
+
+    #[error("{0}")]
+    Lock(String),
+
+    #[error("File content is {detected} (claimed {claimed}), which is not an allowed upload type")]
+    UnsupportedMediaType { detected: String, claimed: String },
+
+    #[error("Not enough disk space: {needed} bytes needed, {available} available")]
+    InsufficientSpace { needed: u64, available: u64 },
+
+    #[error("{action} is disabled while the event lock is on")]
+    LockedByEventMode { action: String },
+}
+
+impl AppError {
+    pub fn io(message: impl Into<String>) -> Self {
+        AppError::Io(message.into())
+    }
+
+    pub fn not_found(what: impl Into<String>) -> Self {
+        AppError::NotFound { what: what.into() }
+    }
+
+    pub fn validation(field: impl Into<String>, message: impl Into<String>) -> Self {
+        AppError::Validation { field: field.into(), message: message.into() }
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        AppError::Conflict(message.into())
+    }
+
+    pub fn external(service: impl Into<String>, message: impl Into<String>) -> Self {
+        AppError::External { service: service.into(), message: message.into() }
+    }
+
+    pub fn unsupported_media_type(detected: impl Into<String>, claimed: impl Into<String>) -> Self {
+        AppError::UnsupportedMediaType { detected: detected.into(), claimed: claimed.into() }
+    }
+
+    pub fn insufficient_space(needed: u64, available: u64) -> Self {
+        AppError::InsufficientSpace { needed, available }
+    }
+
+    pub fn locked_by_event_mode(action: impl Into<String>) -> Self {
+        AppError::LockedByEventMode { action: action.into() }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::Io(_) => "io",
+            AppError::NotFound { .. } => "not_found",
+            AppError::Validation { .. } => "validation",
+            AppError::Conflict(_) => "conflict",
+            AppError::External { .. } => "external",
+            AppError::Lock(_) => "lock",
+            AppError::UnsupportedMediaType { .. } => "unsupported_media_type",
+            AppError::InsufficientSpace { .. } => "insufficient_space",
+            AppError::LockedByEventMode { .. } => "locked_by_event_mode",
+        }
+    }
+
+    fn details(&self) -> Option<serde_json::Value> {
+        match self {
+            AppError::NotFound { what } => Some(serde_json::json!({ "what": what })),
+            AppError::Validation { field, .. } => Some(serde_json::json!({ "field": field })),
+            AppError::External { service, .. } => Some(serde_json::json!({ "service": service })),
+            AppError::UnsupportedMediaType { detected, claimed } => {
+                Some(serde_json::json!({ "detected": detected, "claimed": claimed }))
+            }
+            AppError::InsufficientSpace { needed, available } => {
+                Some(serde_json::json!({ "needed": needed, "available": available }))
+            }
+            AppError::LockedByEventMode { action } => Some(serde_json::json!({ "action": action })),
+            AppError::Io(_) | AppError::Conflict(_) | AppError::Lock(_) => None,
+        }
+    }
+}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 3)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("details", &self.details())?;
+        state.end()
+    }
+}
+
+// Modules still on `Result<T, String>` can call converted ones with `?`
+impl From<AppError> for String {
+    fn from(error: AppError) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(error: std::io::Error) -> Self {
+        AppError::Io(error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(error: serde_json::Error) -> Self {
+        if error.is_io() {
+            AppError::Io(error.to_string())
+        } else {
+            AppError::validation("json", error.to_string())
+        }
+    }
+}
+
+impl<T> From<std::sync::PoisonError<T>> for AppError {
+    fn from(error: std::sync::PoisonError<T>) -> Self {
+        AppError::Lock(error.to_string())
+    }
+}
+
+impl From<tauri::Error> for AppError {
+    fn from(error: tauri::Error) -> Self {
+        AppError::external("tauri", error.to_string())
+    }
+}
+
+impl From<tokio::task::JoinError> for AppError {
+    fn from(error: tokio::task::JoinError) -> Self {
+        AppError::Io(format!("Background task failed: {}", error))
+    }
+}
+
+impl From<zip::result::ZipError> for AppError {
+    fn from(error: zip::result::ZipError) -> Self {
+        AppError::Io(error.to_string())
+    }
+}
+
+impl From<base64::DecodeError> for AppError {
+    fn from(error: base64::DecodeError) -> Self {
+        AppError::validation("data", error.to_string())
+    }
+}
@@ -1,19 +1,35 @@
 // src-tauri/src/lib.rs
 mod commands;
+mod error;
+mod state;
+mod state_sync;
+mod storage;
+mod tray;
 
 use commands::*;
+use state_sync::*;
+use std::sync::Mutex;
 use tauri::Manager;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        // Must be registered first so a second launch hands its link to this instance
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| deep_link::focus_main_window(app)))
+        .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_window_state::Builder::default().build())
+        .plugin(tauri_plugin_updater::Builder::new().build())
         .manage(ScoreboardState::default())
         .manage(monitor::ScoreboardInstanceStore::default())
+        .manage(monitor::MonitorSimulationStore::default())
+        .manage(monitor::ClickThroughStore::default())
+        .manage(monitor::WindowOrderStore::default())
+        .manage(media_library::MediaLibraryLocks::default())
+        .manage(launch::LaunchState::new(launch::parse_launch_args(std::env::args())))
         .invoke_handler(tauri::generate_handler![
             // Monitor commands
             get_available_monitors,
@@ -21,11 +37,24 @@ pub fn run() {
             close_scoreboard_window,
             close_all_scoreboard_windows,
             list_scoreboard_windows,
+            get_scoreboard_windows_with_monitors,
+            check_window_placement,
+            apply_scoreboard_monitor_mapping,
+            restore_window_order,
             get_scoreboard_instance_data,
             update_scoreboard_window_position,
             update_scoreboard_window_size,
             toggle_scoreboard_fullscreen,
             set_scoreboard_fullscreen,
+            set_scoreboard_window_click_through,
+            get_scoreboard_window_click_through,
+            set_scoreboard_frame_pacing,
+            set_monitor_simulation_mode,
+            set_simulated_monitors,
+            clear_simulated_monitors,
+            get_monitor_simulation,
+            blank_all_displays,
+            restore_all_displays,
             // Storage commands
             save_scoreboard,
             load_scoreboard,
@@ -33,25 +62,35 @@ pub fn run() {
             delete_scoreboard,
             export_scoreboard,
             import_scoreboard,
+            import_scoreboard_from_url,
             // Scoreboard commands
             update_game_state,
             get_game_state,
             update_score,
             update_time,
+            validate_clock_string,
             update_period,
             toggle_game_active,
             reset_game,
             update_team_info,
+            set_team_logo,
+            get_clock_direction,
+            set_clock_direction,
             // Image commands
             upload_image,
             get_stored_images,
             delete_image,
             get_image_data,
+            get_images_data,
+            extract_image_palette,
+            migrate_embedded_images,
             // Video commands
             upload_video,
             get_stored_videos,
             delete_video,
             get_video_data,
+            check_video_playability,
+            get_video_thumbnail_strip,
             // Live data commands
             fetch_live_data,
             test_api_connection,
@@ -65,6 +104,25 @@ pub fn run() {
             send_websocket_message,
             start_websocket_listener,
             stop_websocket_listener,
+            restart_listener,
+            set_connection_priority,
+            configure_reconnect_policy,
+            set_preferred_connection_for_court,
+            get_court_connections,
+            set_connection_update_mode,
+            set_connection_message_types,
+            get_latest_message_by_type,
+            set_websocket_frame_capture,
+            get_websocket_frame_errors,
+            get_websocket_close_info,
+            persist_court_data,
+            get_stale_courts,
+            set_connection_buffering,
+            get_buffered_court_data,
+            merge_courts,
+            set_court_aliases,
+            get_court_aliases,
+            get_court_debug_pair,
             get_latest_ioncourt_data,
             get_latest_ioncourt_data_by_court,
             get_active_court_data,
@@ -74,26 +132,321 @@ pub fn run() {
             delete_live_data_connections,
             // Export/Import commands
             export_scoreboard_as_zip,
+            export_all_scoreboards_to_zip,
+            export_scoreboard_as_svg,
+            preview_scoreboard_at_resolution,
+            estimate_scoreboard_memory,
+            set_scoreboard_target_resolution,
+            get_scoreboard_render_scale,
             import_scoreboard_from_zip,
+            import_scoreboard_zips,
+            validate_scoreboard_zip,
+            set_import_watch_folder,
+            // Data directory commands
+            set_data_directory,
+            purge_old_data_directory,
+            get_media_storage_stats,
+            get_disk_space_status,
+            // Integrity check commands
+            run_integrity_check,
+            apply_integrity_fixes,
             // Court data sync commands
             start_court_data_sync,
             stop_court_data_sync,
             trigger_manual_sync,
             get_court_sync_status,
             is_court_sync_running,
+            set_court_sync_watchdog,
+            set_court_data_flush_interval,
             // Tennis processor commands
             process_tennis_data,
             process_tennis_data_batch,
             validate_tennis_data,
+            validate_tennis_data_strict,
+            compute_critical_points,
+            // Sport detection commands
+            detect_sport,
+            // UDP broadcast commands
+            start_udp_broadcast,
+            stop_udp_broadcast,
+            get_udp_broadcast_status,
+            // Message transform commands
+            set_connection_transform,
+            get_connection_transform,
+            test_transform,
+            // vMix data server commands
+            start_vmix_server,
+            stop_vmix_server,
+            get_vmix_server_status,
+            // REST polling commands
+            start_rest_polling_connection,
+            stop_rest_polling_connection,
+            get_rest_polling_status,
+            // Upload limit commands
+            get_upload_limits,
+            set_upload_limits,
+            // Undo history commands
+            save_undo_history,
+            load_undo_history,
+            clear_undo_history,
+            // Binding resolver commands
+            set_component_bindings,
+            resolve_component_bindings,
+            validate_all_bindings,
+            set_component_binding_priority,
+            subscribe_binding_updates,
+            unsubscribe_binding_updates,
+            // Event discovery commands
+            list_backend_events,
+            // Launch commands
+            get_launch_options,
+            // Deep link commands
+            confirm_deep_link,
+            dismiss_deep_link,
+            get_deep_link_settings,
+            set_deep_link_settings,
+            // Updater commands
+            check_for_updates,
+            download_and_install_update,
+            get_update_status,
+            get_update_channel,
+            set_update_channel,
+            // Logging commands
+            set_log_level,
+            get_recent_logs,
+            export_diagnostics_bundle,
+            reset_sample_content,
+            // Session recorder commands
+            start_session_recording,
+            stop_session_recording,
+            get_session_recording_status,
+            replay_session,
+            // Scheduler commands
+            add_scheduled_action,
+            list_scheduled_actions,
+            remove_scheduled_action,
+            // Component CSV commands
+            export_components_csv,
+            import_components_csv,
+            // Theme commands
+            apply_theme_to_scoreboard,
+            check_text_contrast,
+            find_low_contrast_components,
+            // Config commands
+            get_app_config,
+            update_app_config,
+            // Provider commands
+            get_live_data_providers,
+            validate_connection_intervals,
+            // QR code commands
+            generate_scoreboard_qr,
+            // Type schema commands
+            export_type_schema,
+            // Match simulator commands
+            start_match_simulation,
+            stop_match_simulation,
+            list_running_simulations,
+            // Match timer commands
+            start_match_timer,
+            stop_match_timer,
+            get_match_elapsed,
+            reset_match_timer,
+            // Secrets commands
+            secrets_status,
+            // Event lock commands
+            enable_event_lock,
+            disable_event_lock,
+            get_event_lock_status,
+            // Window update holds
+            set_window_updates_paused,
+            get_paused_windows,
+            // Conditional visibility commands
+            set_component_visibility_rules,
+            set_component_visibility_override,
+            get_component_visibility,
+            // Serial output commands
+            list_serial_ports,
+            connect_serial_scoreboard,
+            disconnect_serial_scoreboard,
+            get_serial_output_status,
+            // State persistence commands
+            save_app_state,
+            load_app_state,
+            save_canvas_state,
+            load_canvas_state,
+            save_image_state,
+            load_image_state,
+            save_video_state,
+            load_video_state,
+            save_live_data_state,
+            load_live_data_state,
+            save_scoreboard_state,
+            load_scoreboard_state,
+            save_all_states,
+            load_all_states,
+            create_state_backup,
+            restore_state_backup,
+            list_state_backups,
+            clear_old_state_backups,
+            has_unsaved_changes,
+            diff_state_vs_disk,
+            // App state commands
+            get_app_state,
+            update_app_theme,
+            toggle_sidebar,
+            set_sidebar_open,
+            toggle_property_panel,
+            set_property_panel_open,
+            toggle_toolbar_compact,
+            set_monitors,
+            select_monitor,
+            add_scoreboard_instance,
+            remove_scoreboard_instance,
+            update_scoreboard_instance_position,
+            update_scoreboard_instance_size,
+            set_app_error,
+            update_app_settings,
+            // Canvas state commands
+            get_canvas_state,
+            get_canvas_view,
+            set_canvas_size,
+            set_canvas_zoom,
+            set_canvas_pan,
+            toggle_canvas_grid,
+            set_canvas_grid_size,
+            toggle_canvas_snap_to_grid,
+            toggle_alignment_snapping,
+            configure_canvas_grid,
+            select_canvas_components,
+            clear_canvas_selection,
+            set_canvas_hovered_component,
+            start_canvas_drag,
+            end_canvas_drag,
+            start_canvas_resize,
+            end_canvas_resize,
+            set_canvas_viewport_bounds,
+            zoom_canvas_in,
+            zoom_canvas_out,
+            zoom_canvas_to_fit,
+            reset_canvas_view,
+            set_canvas_alignment_guides,
+            clear_canvas_alignment_guides,
+            set_canvas_clipboard,
+            clear_canvas_clipboard,
+            // Image state commands
+            get_image_state,
+            set_image_loading,
+            add_image,
+            remove_image,
+            set_image_error,
+            // Video state commands
+            get_video_state,
+            set_video_loading,
+            add_video,
+            remove_video,
+            set_video_error,
+            // Live data state commands
+            get_live_data_state,
+            get_live_data_connection,
+            add_live_data_connection,
+            update_live_data_connection,
+            remove_live_data_connection,
+            update_live_data,
+            add_live_data_component_binding,
+            remove_live_data_component_binding,
+            set_live_data_polling,
+            set_live_data_error,
+            set_tennis_api_connected,
+            set_tennis_api_scoreboards,
+            // Scoreboard state commands
+            get_scoreboard_state,
+            get_scoreboard_component,
+            get_scoreboard_components_meta,
+            set_scoreboard_config,
+            add_scoreboard_component,
+            remove_scoreboard_component,
+            update_scoreboard_component,
+            update_scoreboard_component_position,
+            update_scoreboard_component_size,
+            update_scoreboard_component_style,
+            update_scoreboard_component_data,
+            bring_scoreboard_component_to_front,
+            send_scoreboard_component_to_back,
+            lock_scoreboard_component,
+            toggle_scoreboard_component_visibility,
+            set_scoreboard_component_tags,
+            set_components_visibility_by_filter,
+            set_scoreboard_game_state,
+            update_scoreboard_score,
+            update_scoreboard_time,
+            update_scoreboard_period,
+            toggle_scoreboard_game_active,
+            reset_scoreboard_game,
+            mark_scoreboard_dirty,
+            mark_scoreboard_saved,
+            clear_scoreboard,
+            update_components,
+            find_overlapping_components,
+            auto_distribute_overlaps,
+            // State sync commands
+            subscribe_to_state_updates,
+            unsubscribe_from_state_updates,
+            get_state_subscription,
         ])
+        .on_window_event(|window, event| {
+            // Only the designer holds unsaved work; display windows always close
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                if window.label() == "main" {
+                    storage_commands::handle_close_requested(window, api);
+                }
+            }
+        })
         .setup(|app| {
+            logging::init_logging(app.handle());
+            data_dir::init_data_dir()?;
+            // Seeded from disk so a fresh launch has nothing unsaved
+            let state_storage = storage::StateStorage::new(app.handle())?;
+            let (app_state, canvas_state, image_state, video_state, live_data_state, scoreboard_state) =
+                state_storage.load_all_states()?;
+            app.manage(state::ManagedAppState(Mutex::new(app_state)));
+            app.manage(state::ManagedCanvasState(Mutex::new(canvas_state)));
+            app.manage(state::ManagedImageState(Mutex::new(image_state)));
+            app.manage(state::ManagedVideoState(Mutex::new(video_state)));
+            app.manage(state::ManagedLiveDataState(Mutex::new(live_data_state)));
+            app.manage(state::ManagedScoreboardState(Mutex::new(scoreboard_state)));
+            app.manage(ManagedStateStorage(state_storage));
+            app.manage(state_sync::ManagedStateSync(Mutex::new(state_sync::StateSyncManager::new(app.handle().clone()))));
+            config::load_app_config(app.handle());
+            event_lock::load_event_lock(app.handle());
+            monitor::load_window_order(app.handle());
+            samples::seed_first_run(app.handle());
             #[cfg(debug_assertions)]
             {
                 let window = app.get_webview_window("main").unwrap();
                 window.open_devtools();
             }
+            live_data::start_court_data_persistence(app.handle());
+            tray::build_tray(app.handle())?;
+            launch::run_launch_actions(app.handle());
+            scheduler::start_scheduler(app.handle());
+            import_watch::start_import_watch(app.handle());
+            disk_space::start_disk_space_monitor(app.handle());
+            match_timer::start_match_timers(app.handle());
+            conditional_visibility::start_visibility_resolver(app.handle());
+            deep_link::register_deep_links(app.handle())?;
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| match event {
+            tauri::RunEvent::WindowEvent { label, event: tauri::WindowEvent::Focused(true), .. } => {
+                monitor::record_window_focus(app_handle, &label);
+            }
+            tauri::RunEvent::Exit => {
+                serial_output::shutdown_serial_output(app_handle);
+                live_data::persist_court_data_on_exit(app_handle);
+                court_data_sync::flush_court_data_sync_on_exit();
+            }
+            _ => {}
+        });
 }
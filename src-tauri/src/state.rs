@@ -2,7 +2,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Mutex;
-use tauri::State;
 
 // ==================== APP STATE ====================
 
@@ -142,6 +141,13 @@ pub struct CanvasGrid {
     pub show_grid: bool,
 }
 
+// All grid-related canvas settings, applied and returned together
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GridConfig {
+    pub grid: CanvasGrid,
+    pub alignment_snapping: bool,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CanvasState {
     pub canvas_size: Size,
@@ -161,6 +167,14 @@ pub struct CanvasState {
     pub alignment_snapping: bool,
 }
 
+// The view-only slice of CanvasState, for callers polling zoom and pan
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CanvasView {
+    pub zoom: f64,
+    pub pan: Position2D,
+    pub grid: CanvasGrid,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Position2D {
     pub x: f64,
@@ -175,6 +189,22 @@ pub struct DOMRect {
     pub height: f64,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ComponentOverlap {
+    pub first_id: String,
+    pub second_id: String,
+    pub overlap_area: f64,
+    // Overlap as a fraction of the smaller component's area
+    pub overlap_ratio: f64,
+}
+
+// Components connected by overlaps above the threshold
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OverlapGroup {
+    pub component_ids: Vec<String>,
+    pub overlaps: Vec<ComponentOverlap>,
+}
+
 // ==================== IMAGE STATE ====================
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -189,7 +219,7 @@ pub struct StoredImage {
     pub thumbnail: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct ImageState {
     pub images: Vec<StoredImage>,
     pub is_loading: bool,
@@ -211,7 +241,7 @@ pub struct StoredVideo {
     pub thumbnail: Option<String>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct VideoState {
     pub videos: Vec<StoredVideo>,
     pub is_loading: bool,
@@ -290,7 +320,7 @@ pub struct ScoreboardInfo {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct LiveDataState {
     pub connections: Vec<LiveDataConnection>,
     pub active_data: HashMap<String, TennisLiveData>,
@@ -303,25 +333,9 @@ pub struct LiveDataState {
     pub tennis_api_scoreboards: Vec<ScoreboardInfo>,
 }
 
-impl Default for LiveDataState {
-    fn default() -> Self {
-        Self {
-            connections: Vec::new(),
-            active_data: HashMap::new(),
-            component_bindings: Vec::new(),
-            is_polling: false,
-            polling_intervals: HashMap::new(),
-            is_loaded: false,
-            last_error: None,
-            tennis_api_connected: false,
-            tennis_api_scoreboards: Vec::new(),
-        }
-    }
-}
-
 // ==================== SCOREBOARD STATE ====================
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub enum ComponentType {
     Background,
     Logo,
@@ -356,6 +370,48 @@ pub struct ScoreboardComponent {
     pub locked: bool,
     pub visible: bool,
     pub z_index: i32,
+    // Free-form group names (e.g. "sponsor") for showing and hiding components together
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+// Selects components for bulk changes
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "by", content = "value", rename_all = "snake_case")]
+pub enum ComponentFilter {
+    Type(ComponentType),
+    Tag(String),
+}
+
+// Enough of a component to list or order layers without its style and data
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScoreboardComponentMeta {
+    pub id: String,
+    pub component_type: ComponentType,
+    pub z_index: i32,
+    pub visible: bool,
+    pub locked: bool,
+}
+
+// A partial change to one component; fields left out are kept as they are
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ComponentUpdate {
+    pub position: Option<Position2D>,
+    pub size: Option<Size>,
+    pub rotation: Option<f64>,
+    pub style: Option<ComponentStyle>,
+    pub data: Option<ComponentData>,
+    pub locked: Option<bool>,
+    pub visible: Option<bool>,
+    pub z_index: Option<i32>,
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ComponentUpdateResult {
+    pub component_id: String,
+    pub applied: bool,
+    pub error: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -421,7 +477,7 @@ pub struct GridSettings {
     pub snap_to_grid: bool,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct ScoreboardState {
     pub config: Option<ScoreboardConfig>,
     pub components: Vec<ScoreboardComponent>,
@@ -440,10 +496,6 @@ pub struct ManagedVideoState(pub Mutex<VideoState>);
 pub struct ManagedLiveDataState(pub Mutex<LiveDataState>);
 pub struct ManagedScoreboardState(pub Mutex<ScoreboardState>);
 
-// Import ScoreboardInstanceStore from commands
-use crate::commands::monitor::ScoreboardInstanceStore;
-pub struct ManagedScoreboardInstanceStore(pub Mutex<ScoreboardInstanceStore>);
-
 // ==================== IMPLEMENTATIONS ====================
 
 impl Default for CanvasState {
@@ -476,35 +528,173 @@ impl Default for CanvasState {
     }
 }
 
-impl Default for ImageState {
-    fn default() -> Self {
-        Self {
-            images: Vec::new(),
-            is_loading: false,
-            last_error: None,
-        }
+// ==================== LAYOUT GEOMETRY ====================
+
+impl DOMRect {
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    pub fn area(&self) -> f64 {
+        self.width.max(0.0) * self.height.max(0.0)
+    }
+
+    pub fn intersection(&self, other: &DOMRect) -> Option<DOMRect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let width = self.right().min(other.right()) - x;
+        let height = self.bottom().min(other.bottom()) - y;
+        (width > 0.0 && height > 0.0).then_some(DOMRect { x, y, width, height })
     }
 }
 
-impl Default for VideoState {
-    fn default() -> Self {
-        Self {
-            videos: Vec::new(),
-            is_loading: false,
-            last_error: None,
+impl ScoreboardComponent {
+    pub fn matches(&self, filter: &ComponentFilter) -> bool {
+        match filter {
+            ComponentFilter::Type(component_type) => self.component_type == *component_type,
+            ComponentFilter::Tag(tag) => self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)),
+        }
+    }
+
+    pub fn apply_update(&mut self, update: ComponentUpdate) {
+        if let Some(position) = update.position { self.position = position; }
+        if let Some(size) = update.size { self.size = size; }
+        if let Some(rotation) = update.rotation { self.rotation = rotation; }
+        if let Some(style) = update.style { self.style = style; }
+        if let Some(data) = update.data { self.data = data; }
+        if let Some(locked) = update.locked { self.locked = locked; }
+        if let Some(visible) = update.visible { self.visible = visible; }
+        if let Some(z_index) = update.z_index { self.z_index = z_index; }
+        if let Some(tags) = update.tags { self.tags = tags; }
+    }
+
+    pub fn bounds(&self) -> DOMRect {
+        DOMRect {
+            x: self.position.x,
+            y: self.position.y,
+            width: self.size.width as f64,
+            height: self.size.height as f64,
         }
     }
 }
 
-impl Default for ScoreboardState {
-    fn default() -> Self {
-        Self {
-            config: None,
-            components: Vec::new(),
-            game_state: None,
-            selected_template: None,
-            is_dirty: false,
-            last_saved: None,
+// Overlap as a fraction of the smaller rect's area
+fn overlap_ratio(a: &DOMRect, b: &DOMRect) -> f64 {
+    let smaller = a.area().min(b.area());
+    match a.intersection(b) {
+        Some(overlap) if smaller > 0.0 => overlap.area() / smaller,
+        _ => 0.0,
+    }
+}
+
+fn overlap_between(a: &ScoreboardComponent, b: &ScoreboardComponent) -> Option<ComponentOverlap> {
+    let (a_bounds, b_bounds) = (a.bounds(), b.bounds());
+    let overlap_area = a_bounds.intersection(&b_bounds)?.area();
+    Some(ComponentOverlap {
+        first_id: a.id.clone(),
+        second_id: b.id.clone(),
+        overlap_area,
+        overlap_ratio: overlap_ratio(&a_bounds, &b_bounds),
+    })
+}
+
+// Hidden components are ignored; they can't hide anything
+pub fn find_overlap_groups(components: &[ScoreboardComponent], threshold: f64) -> Vec<OverlapGroup> {
+    let visible: Vec<&ScoreboardComponent> = components.iter().filter(|c| c.visible).collect();
+    let mut group_of: Vec<usize> = (0..visible.len()).collect();
+    let mut overlaps = Vec::new();
+
+    fn root(group_of: &mut [usize], mut i: usize) -> usize {
+        while group_of[i] != i {
+            group_of[i] = group_of[group_of[i]];
+            i = group_of[i];
+        }
+        i
+    }
+
+    for i in 0..visible.len() {
+        for j in (i + 1)..visible.len() {
+            if let Some(overlap) = overlap_between(visible[i], visible[j]) {
+                if overlap.overlap_ratio > threshold {
+                    let (ri, rj) = (root(&mut group_of, i), root(&mut group_of, j));
+                    group_of[rj] = ri;
+                    overlaps.push((i, overlap));
+                }
+            }
+        }
+    }
+
+    let mut groups: Vec<(usize, OverlapGroup)> = Vec::new();
+    for (i, overlap) in overlaps {
+        let group_root = root(&mut group_of, i);
+        let index = match groups.iter().position(|(r, _)| *r == group_root) {
+            Some(index) => index,
+            None => {
+                groups.push((group_root, OverlapGroup { component_ids: Vec::new(), overlaps: Vec::new() }));
+                groups.len() - 1
+            }
+        };
+        let group = &mut groups[index].1;
+        for id in [&overlap.first_id, &overlap.second_id] {
+            if !group.component_ids.contains(id) {
+                group.component_ids.push(id.clone());
+            }
+        }
+        group.overlaps.push(overlap);
+    }
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+// Places components one at a time in z-order (locked ones first, since they
+// never move), sliding each overlapping component to the nearest free spot
+// beside something already placed. Components with no free spot on the canvas
+// stay where they are. Returns the IDs of components that were moved.
+pub fn distribute_overlaps(
+    components: &mut [ScoreboardComponent],
+    threshold: f64,
+    canvas: Option<&Size>,
+) -> Vec<String> {
+    let mut order: Vec<usize> = (0..components.len()).filter(|&i| components[i].visible).collect();
+    order.sort_by_key(|&i| (!components[i].locked, components[i].z_index, i));
+
+    let fits = |rect: &DOMRect| {
+        rect.x >= 0.0 && rect.y >= 0.0 && canvas.is_none_or(|c| {
+            rect.right() <= c.width as f64 && rect.bottom() <= c.height as f64
+        })
+    };
+
+    let mut moved = Vec::new();
+    for (placed_count, &i) in order.iter().enumerate() {
+        if components[i].locked {
+            continue;
+        }
+        let placed: Vec<DOMRect> = order[..placed_count].iter().map(|&p| components[p].bounds()).collect();
+        let clashes = |rect: &DOMRect| placed.iter().any(|p| overlap_ratio(p, rect) > threshold);
+        let current = components[i].bounds();
+        if !clashes(&current) {
+            continue;
+        }
+
+        let distance = |rect: &DOMRect| (rect.x - current.x).abs() + (rect.y - current.y).abs();
+        let best = placed.iter()
+            .flat_map(|p| [
+                (p.right(), current.y),
+                (p.x - current.width, current.y),
+                (current.x, p.bottom()),
+                (current.x, p.y - current.height),
+            ])
+            .map(|(x, y)| DOMRect { x, y, width: current.width, height: current.height })
+            .filter(|rect| fits(rect) && !clashes(rect))
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)));
+
+        if let Some(rect) = best {
+            components[i].position = Position2D { x: rect.x, y: rect.y };
+            moved.push(components[i].id.clone());
         }
     }
+    moved
 }
@@ -3,11 +3,13 @@ use crate::state::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Mutex;
-use tauri::{AppHandle, Manager, Emitter, State};
+use tauri::{AppHandle, Emitter, State};
 
 // ==================== SYNC EVENT TYPES ====================
 
+// Variant names are the serialized event tags the frontend receives
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum StateUpdateEvent {
     AppStateUpdate(AppState),
     CanvasStateUpdate(CanvasState),
@@ -111,22 +113,6 @@ impl StateSyncManager {
         self.emit_state_update(StateUpdateEvent::AppStateUpdate(state.clone()))
     }
 
-    pub fn notify_canvas_state_change(&self, state: &CanvasState) -> Result<(), String> {
-        self.emit_state_update(StateUpdateEvent::CanvasStateUpdate(state.clone()))
-    }
-
-    pub fn notify_image_state_change(&self, state: &ImageState) -> Result<(), String> {
-        self.emit_state_update(StateUpdateEvent::ImageStateUpdate(state.clone()))
-    }
-
-    pub fn notify_video_state_change(&self, state: &VideoState) -> Result<(), String> {
-        self.emit_state_update(StateUpdateEvent::VideoStateUpdate(state.clone()))
-    }
-
-    pub fn notify_live_data_state_change(&self, state: &LiveDataState) -> Result<(), String> {
-        self.emit_state_update(StateUpdateEvent::LiveDataStateUpdate(state.clone()))
-    }
-
     pub fn notify_scoreboard_state_change(&self, state: &ScoreboardState) -> Result<(), String> {
         self.emit_state_update(StateUpdateEvent::ScoreboardStateUpdate(state.clone()))
     }
@@ -167,42 +153,3 @@ pub async fn get_state_subscription(
         .map_err(|e| format!("Failed to lock state sync: {}", e))?;
     sync_manager.get_subscription(&subscription_id)
 }
-
-// ==================== STATE CHANGE NOTIFIERS ====================
-
-pub fn setup_state_change_notifications(app: &AppHandle) -> Result<(), String> {
-    // This function sets up listeners that will notify subscribers when state changes
-    // In a real implementation, you'd modify the state command functions to call these
-    // after successful state mutations
-
-    // For example, you could modify the state_commands.rs functions to emit notifications
-    // after successful operations, or set up reactive subscriptions
-
-    Ok(())
-}
-
-// ==================== AUTO-NOTIFICATION HELPERS ====================
-
-pub fn notify_all_state_changes(
-    app: &AppHandle,
-    app_state: &AppState,
-    canvas_state: &CanvasState,
-    image_state: &ImageState,
-    video_state: &VideoState,
-    live_data_state: &LiveDataState,
-    scoreboard_state: &ScoreboardState,
-) -> Result<(), String> {
-    if let Some(sync) = app.try_state::<ManagedStateSync>() {
-        let sync_manager = sync.0.lock()
-            .map_err(|e| format!("Failed to lock state sync: {}", e))?;
-
-        sync_manager.notify_app_state_change(app_state)?;
-        sync_manager.notify_canvas_state_change(canvas_state)?;
-        sync_manager.notify_image_state_change(image_state)?;
-        sync_manager.notify_video_state_change(video_state)?;
-        sync_manager.notify_live_data_state_change(live_data_state)?;
-        sync_manager.notify_scoreboard_state_change(scoreboard_state)?;
-    }
-
-    Ok(())
-}
@@ -2,8 +2,9 @@
 use crate::state::{LiveDataState, ScoreboardState, ImageState, VideoState, CanvasState, AppState};
 use std::fs;
 use std::path::PathBuf;
-use tauri::{AppHandle, Manager};
+use tauri::AppHandle;
 use std::default::Default;
+use crate::commands::data_dir::data_dir;
 
 #[derive(Clone)]
 pub struct StateStorage {
@@ -12,9 +13,7 @@ pub struct StateStorage {
 
 impl StateStorage {
     pub fn new(app_handle: &AppHandle) -> Result<Self, String> {
-        let app_data_dir = app_handle
-            .path()
-            .app_data_dir()
+        let app_data_dir = data_dir(app_handle)
             .map_err(|e| format!("Failed to get app data directory: {}", e))?;
 
         // Create the directory if it doesn't exist
@@ -211,8 +210,6 @@ impl StateStorage {
                 .map_err(|e| format!("Failed to create backup directory: {}", e))?;
         }
 
-        let backup_path = backup_dir.join(format!("{}.zip", backup_name));
-
         // For now, just copy the state files to a backup directory
         // In a real implementation, you'd want to create a proper ZIP archive
         let backup_state_dir = backup_dir.join(backup_name);
@@ -288,8 +285,7 @@ impl StateStorage {
         }
 
         let to_delete = backups.len() - keep_last_n;
-        for i in 0..to_delete {
-            let backup_name = &backups[i];
+        for backup_name in backups.iter().take(to_delete) {
             let backup_path = self.app_data_dir.join("backups").join(backup_name);
             if backup_path.is_dir() {
                 fs::remove_dir_all(backup_path)
@@ -300,7 +296,3 @@ impl StateStorage {
         Ok(())
     }
 }
-
-// ==================== MANAGED STATE WRAPPERS ====================
-
-pub struct ManagedStateStorage(pub StateStorage);
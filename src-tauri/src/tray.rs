@@ -0,0 +1,104 @@
+// src-tauri/src/tray.rs
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager};
+use tracing::{info, warn};
+
+use crate::commands::live_data::connection_summary;
+use crate::commands::monitor::{scoreboard_window_counts, set_scoreboard_windows_visible};
+
+// Connection and window changes have no single notification point, so the
+// status lines are refreshed on a short interval and only updated when they change
+const STATUS_REFRESH_SECS: u64 = 2;
+
+pub fn build_tray(app: &AppHandle) -> tauri::Result<()> {
+    let toggle_main = MenuItem::with_id(app, "toggle_main", "Show/Hide Designer", true, None::<&str>)?;
+    let blank = MenuItem::with_id(app, "blank_displays", "Blank All Displays", false, None::<&str>)?;
+    let restore = MenuItem::with_id(app, "restore_displays", "Restore Displays", false, None::<&str>)?;
+    let connection_status = MenuItem::with_id(app, "connection_status", "Live data: not connected", false, None::<&str>)?;
+    let display_status = MenuItem::with_id(app, "display_status", "Displays: none open", false, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+
+    let menu = Menu::with_items(app, &[
+        &toggle_main,
+        &PredefinedMenuItem::separator(app)?,
+        &blank,
+        &restore,
+        &PredefinedMenuItem::separator(app)?,
+        &connection_status,
+        &display_status,
+        &PredefinedMenuItem::separator(app)?,
+        &quit,
+    ])?;
+
+    let mut builder = TrayIconBuilder::with_id("main")
+        .tooltip("Tempuz Scoreboard")
+        .menu(&menu)
+        .show_menu_on_left_click(true)
+        .on_menu_event(|app, event| handle_menu_event(app, event.id().as_ref()));
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+
+    let refresh_app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut last_connection_text = String::new();
+        let mut last_display_text = String::new();
+        let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(STATUS_REFRESH_SECS));
+
+        loop {
+            ticker.tick().await;
+
+            let connection_text = connection_summary().await;
+            if connection_text != last_connection_text {
+                if let Err(e) = connection_status.set_text(&connection_text) {
+                    warn!("Failed to update tray status: {}", e);
+                }
+                last_connection_text = connection_text;
+            }
+
+            let (open, visible) = scoreboard_window_counts(&refresh_app);
+            let display_text = match open {
+                0 => "Displays: none open".to_string(),
+                _ => format!("Displays: {} open, {} visible", open, visible),
+            };
+            if display_text != last_display_text {
+                let _ = display_status.set_text(&display_text);
+                let _ = blank.set_enabled(visible > 0);
+                let _ = restore.set_enabled(visible < open);
+                last_display_text = display_text;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+fn handle_menu_event(app: &AppHandle, id: &str) {
+    match id {
+        "toggle_main" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let result = if window.is_visible().unwrap_or(false) {
+                    window.hide()
+                } else {
+                    window.show().and_then(|_| window.unminimize()).and_then(|_| window.set_focus())
+                };
+                if let Err(e) = result {
+                    warn!("Failed to toggle main window: {}", e);
+                }
+            }
+        }
+        "blank_displays" => match set_scoreboard_windows_visible(app, false) {
+            Ok(count) => info!("⬛ Blanked {} scoreboard display(s) from tray", count),
+            Err(e) => warn!("Failed to blank displays: {}", e),
+        },
+        "restore_displays" => match set_scoreboard_windows_visible(app, true) {
+            Ok(count) => info!("🖥️ Restored {} scoreboard display(s) from tray", count),
+            Err(e) => warn!("Failed to restore displays: {}", e),
+        },
+        // Exiting raises RunEvent::Exit, which runs the shutdown hooks in lib.rs
+        "quit" => app.exit(0),
+        _ => {}
+    }
+}
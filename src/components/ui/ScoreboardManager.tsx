@@ -1,5 +1,5 @@
 import React, { useState, useEffect } from 'react';
-import { TauriAPI, TauriScoreboardConfig } from '../../lib/tauri';
+import { TauriAPI, TauriScoreboardConfig, isAppError } from '../../lib/tauri';
 
 interface ScoreboardManagerProps {
   isOpen: boolean;
@@ -51,7 +51,7 @@ export const ScoreboardManager: React.FC<ScoreboardManagerProps> = ({
       console.error('❌ Failed to delete scoreboard:', error);
       
       // If file not found, it's a ghost entry - remove it from the UI
-      if ((error as Error).toString().includes('Scoreboard file not found')) {
+      if (isAppError(error) && error.code === 'not_found') {
         console.log('🧹 Removing ghost scoreboard from UI list');
         // Filter out the ghost entry from the current list
         setScoreboards(prevScoreboards => 
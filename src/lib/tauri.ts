@@ -2,7 +2,8 @@
 import { invoke } from '@tauri-apps/api/core';
 import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
 import { MonitorInfo } from '../types/tauri';
-import { GameState } from '../types/scoreboard';
+import { BindingPriority, GameState } from '../types/scoreboard';
+import type { VideoThumbnailStrip } from '../stores/useVideoStore';
 
 export interface TauriScoreboardConfig {
   id: string;
@@ -13,6 +14,17 @@ export interface TauriScoreboardConfig {
   updated_at: string;
 }
 
+// Error returned by converted backend commands; `message` matches the old string errors
+export interface AppError {
+  code: 'io' | 'not_found' | 'validation' | 'conflict' | 'external' | 'lock' | 'unsupported_media_type' | 'insufficient_space' | 'locked_by_event_mode';
+  message: string;
+  details: Record<string, string | number> | null;
+}
+
+export function isAppError(error: unknown): error is AppError {
+  return typeof error === 'object' && error !== null && 'code' in error && 'message' in error;
+}
+
 export class TauriAPI {
   static async getMonitors(): Promise<MonitorInfo[]> {
     try {
@@ -131,6 +143,34 @@ export class TauriAPI {
     }
   }
 
+  static async setScoreboardWindowClickThrough(windowId: string, enabled: boolean): Promise<void> {
+    return await invoke('set_scoreboard_window_click_through', { windowId, enabled });
+  }
+
+  static async getScoreboardWindowClickThrough(windowId: string): Promise<boolean> {
+    return await invoke('get_scoreboard_window_click_through', { windowId });
+  }
+
+  // Reports supported: false (with a reason) for modes the platform can't apply
+  static async setScoreboardFramePacing(windowId: string, mode: FramePacingMode): Promise<FramePacingResult> {
+    return await invoke('set_scoreboard_frame_pacing', { windowId, mode });
+  }
+
+  // Pre-show check; one warning per monitor covered by more than one fullscreen scoreboard
+  static async checkWindowPlacement(): Promise<WindowPlacementWarning[]> {
+    return await invoke('check_window_placement');
+  }
+
+  // monitor_match is a monitor id or (part of) its name
+  static async applyScoreboardMonitorMapping(mapping: ScoreboardMonitorMapping[]): Promise<MonitorMappingReport> {
+    return await invoke('apply_scoreboard_monitor_mapping', { mapping });
+  }
+
+  // Re-stacks open windows in the order they were last focused; call after recreating them
+  static async restoreWindowOrder(): Promise<string[]> {
+    return await invoke('restore_window_order');
+  }
+
   // Storage Commands
   static async saveScoreboard(name: string, config: any): Promise<string> {
     try {
@@ -186,6 +226,11 @@ export class TauriAPI {
     }
   }
 
+  // Accepts an exported .json or .zip over http(s)
+  static async importScoreboardFromUrl(url: string): Promise<TauriScoreboardConfig> {
+    return await invoke('import_scoreboard_from_url', { url });
+  }
+
   // Game State Commands
   static async updateGameState(gameState: GameState): Promise<void> {
     try {
@@ -223,6 +268,10 @@ export class TauriAPI {
     }
   }
 
+  static async validateClockString(timeRemaining: string): Promise<string> {
+    return await invoke('validate_clock_string', { timeRemaining });
+  }
+
   static async updatePeriod(period: number): Promise<void> {
     try {
       await invoke('update_period', { period });
@@ -273,14 +322,27 @@ export class TauriAPI {
     }
   }
 
-  static async checkWebSocketStatus(): Promise<string> {
+  static async checkWebSocketStatus(connectionId: string): Promise<WebSocketConnectionStatus> {
     try {
-      return await invoke('check_websocket_status');
+      return await invoke('check_websocket_status', { connectionId });
     } catch (error) {
       throw error;
     }
   }
 
+  // Omitted values keep the current setting; applies from the next disconnect
+  static async configureReconnectPolicy(
+    connectionId: string,
+    policy: Partial<ReconnectPolicy>
+  ): Promise<ReconnectPolicy> {
+    return await invoke('configure_reconnect_policy', {
+      connectionId,
+      initialDelayMs: policy.initial_delay_ms,
+      maxDelayMs: policy.max_delay_ms,
+      maxAttempts: policy.max_attempts,
+    });
+  }
+
   static async testWebSocketConnection(wsUrl: string): Promise<string> {
     try {
       return await invoke('test_websocket_connection', { wsUrl });
@@ -289,9 +351,9 @@ export class TauriAPI {
     }
   }
 
-  static async connectWebSocket(wsUrl: string, connectionId: string, courtFilter?: string): Promise<string> {
+  static async connectWebSocket(wsUrl: string, connectionId: string, courtFilter?: string, replace?: boolean): Promise<string> {
     try {
-      return await invoke('connect_websocket', { wsUrl, connectionId, courtFilter });
+      return await invoke('connect_websocket', { wsUrl, connectionId, courtFilter, replace });
     } catch (error) {
       throw error;
     }
@@ -345,6 +407,41 @@ export class TauriAPI {
     }
   }
 
+  // Pass null to go back to connection priorities alone
+  static async setPreferredConnectionForCourt(courtName: string, connectionId: string | null): Promise<CourtConnectionInfo> {
+    return await invoke('set_preferred_connection_for_court', { courtName, connectionId });
+  }
+
+  static async getCourtConnections(): Promise<CourtConnectionInfo[]> {
+    return await invoke('get_court_connections');
+  }
+
+  // Keeps the connection's courts on last-known data through outages
+  static async setConnectionBuffering(connectionId: string, enabled: boolean): Promise<boolean> {
+    return await invoke('set_connection_buffering', { connectionId, enabled });
+  }
+
+  static async getBufferedCourtData(courtName: string): Promise<BufferedCourtData> {
+    return await invoke('get_buffered_court_data', { courtName });
+  }
+
+  // MATCH is always processed; pass [] to go back to MATCH only
+  static async setConnectionMessageTypes(connectionId: string, messageTypes: string[]): Promise<string[]> {
+    return await invoke('set_connection_message_types', { connectionId, messageTypes });
+  }
+
+  static async getLatestMessageByType(connectionId: string, messageType: string): Promise<TypedMessage | null> {
+    return await invoke('get_latest_message_by_type', { connectionId, messageType });
+  }
+
+  static async setWebSocketFrameCapture(connectionId: string, enabled: boolean): Promise<void> {
+    return await invoke('set_websocket_frame_capture', { connectionId, enabled });
+  }
+
+  static async getWebSocketFrameErrors(connectionId: string): Promise<WebSocketFrameErrorReport> {
+    return await invoke('get_websocket_frame_errors', { connectionId });
+  }
+
   static async getAllCourtData(): Promise<{[courtName: string]: any}> {
     try {
       // Note: This now only returns active courts (updated within last hour)
@@ -412,6 +509,11 @@ export class TauriAPI {
     }
   }
 
+  // Streams every board and its media to disk; progress arrives as zip_export_progress events
+  static async exportAllScoreboardsToZip(exportPath: string): Promise<ZipExportSummary> {
+    return await invoke('export_all_scoreboards_to_zip', { exportPath });
+  }
+
   static async importScoreboardFromZip(zipData: number[]): Promise<any> {
     try {
       return await invoke('import_scoreboard_from_zip', { zipData });
@@ -420,6 +522,441 @@ export class TauriAPI {
       throw error;
     }
   }
+
+  // Results are in the same order as `zips`; failed archives carry their error
+  static async importScoreboardZips(zips: number[][]): Promise<ZipBatchImportResult[]> {
+    return await invoke('import_scoreboard_zips', { zips });
+  }
+
+  static async validateScoreboardZip(zipData: number[]): Promise<ZipImportSummary> {
+    return await invoke('validate_scoreboard_zip', { zipData });
+  }
+
+  // Progress arrives as integrity_check_progress events
+  static async runIntegrityCheck(): Promise<IntegrityReport> {
+    return await invoke('run_integrity_check');
+  }
+
+  // Rescans first; ids whose problem is gone come back with a not_found error
+  static async applyIntegrityFixes(fixIds: string[]): Promise<IntegrityFixOutcome[]> {
+    return await invoke('apply_integrity_fixes', { fixIds });
+  }
+
+  // Logs, state, settings and status for support; never includes media or credentials
+  static async exportDiagnosticsBundle(path: string, redactLevel?: RedactLevel): Promise<DiagnosticsBundleSummary> {
+    return await invoke('export_diagnostics_bundle', { path, redactLevel });
+  }
+
+  // Re-adds missing samples and restores sample boards; never touches the user's own content
+  static async resetSampleContent(): Promise<SampleContentReport> {
+    return await invoke('reset_sample_content');
+  }
+
+  // WCAG ratio checked against the minTextContrastRatio setting
+  static async checkTextContrast(foreground: string, background: string): Promise<ContrastCheck> {
+    return await invoke('check_text_contrast', { foreground, background });
+  }
+
+  static async findLowContrastComponents(filename: string): Promise<ContrastReport> {
+    return await invoke('find_low_contrast_components', { filename });
+  }
+
+  // Same payload as the low_disk_space event, for showing the banner on startup
+  static async getDiskSpaceStatus(): Promise<DiskSpaceStatus> {
+    return await invoke('get_disk_space_status');
+  }
+
+  // Data URLs for many image components in one round trip, keyed by image id
+  static async getImagesData(imageIds: string[]): Promise<Record<string, ImageDataResult>> {
+    return await invoke('get_images_data', { imageIds });
+  }
+
+  // Takes effect on the next launch; the old copy stays until purgeOldDataDirectory
+  static async setDataDirectory(newPath: string, moveExisting: boolean): Promise<DataDirectoryChange> {
+    return await invoke('set_data_directory', { newPath, moveExisting });
+  }
+
+  static async purgeOldDataDirectory(): Promise<number> {
+    return await invoke('purge_old_data_directory');
+  }
+
+  // `count` evenly spaced frames (1-24); falls back to the poster without ffmpeg
+  static async getVideoThumbnailStrip(videoId: string, count: number): Promise<VideoThumbnailStrip> {
+    return await invoke('get_video_thumbnail_strip', { videoId, count });
+  }
+
+  static async getMediaStorageStats(): Promise<MediaStorageStats> {
+    return await invoke('get_media_storage_stats');
+  }
+
+  // JSON Schemas of the backend structs, keyed by type name, for generating these types
+  static async exportTypeSchema(): Promise<Record<string, unknown>> {
+    return await invoke('export_type_schema');
+  }
+
+  // Rehearsal matches written to a court as if they came from the live feed
+  static async startMatchSimulation(courtName: string, options?: MatchSimulationOptions): Promise<MatchSimulationStatus> {
+    return await invoke('start_match_simulation', { courtName, options });
+  }
+
+  static async stopMatchSimulation(courtName: string): Promise<string> {
+    return await invoke('stop_match_simulation', { courtName });
+  }
+
+  static async listRunningSimulations(): Promise<MatchSimulationStatus[]> {
+    return await invoke('list_running_simulations');
+  }
+
+  // Starts or resumes; running timers also report via match_elapsed events every second
+  static async startMatchTimer(matchId: string): Promise<MatchElapsedEvent> {
+    return await invoke('start_match_timer', { matchId });
+  }
+
+  static async stopMatchTimer(matchId: string): Promise<MatchElapsedEvent> {
+    return await invoke('stop_match_timer', { matchId });
+  }
+
+  static async getMatchElapsed(matchId: string): Promise<MatchElapsedEvent> {
+    return await invoke('get_match_elapsed', { matchId });
+  }
+
+  static async resetMatchTimer(matchId: string): Promise<void> {
+    return await invoke('reset_match_timer', { matchId });
+  }
+
+  static async secretsStatus(): Promise<SecretsStatus> {
+    return await invoke('secrets_status');
+  }
+
+  // Editing commands fail with code 'locked_by_event_mode' while the lock is on
+  static async enableEventLock(pin?: string): Promise<EventLockStatus> {
+    return await invoke('enable_event_lock', { pin: pin ?? null });
+  }
+
+  static async disableEventLock(pin?: string): Promise<EventLockStatus> {
+    return await invoke('disable_event_lock', { pin: pin ?? null });
+  }
+
+  static async getEventLockStatus(): Promise<EventLockStatus> {
+    return await invoke('get_event_lock_status');
+  }
+
+  // Holds update events for one window; it must listen on its own target for this to apply
+  static async setWindowUpdatesPaused(windowId: string, paused: boolean): Promise<string[]> {
+    return await invoke('set_window_updates_paused', { windowId, paused });
+  }
+
+  static async getPausedWindows(): Promise<string[]> {
+    return await invoke('get_paused_windows');
+  }
+
+  // Replaces every rule; displays get component_visibility_changed as values flip
+  static async setComponentVisibilityRules(rules: ComponentVisibilityRule[]): Promise<Record<string, boolean>> {
+    return await invoke('set_component_visibility_rules', { rules });
+  }
+
+  // null hands the component back to its rule
+  static async setComponentVisibilityOverride(componentId: string, visible: boolean | null): Promise<Record<string, boolean>> {
+    return await invoke('set_component_visibility_override', { componentId, visible });
+  }
+
+  static async getComponentVisibility(): Promise<Record<string, boolean>> {
+    return await invoke('get_component_visibility');
+  }
+}
+
+export interface MatchSimulationOptions {
+  speed?: number;
+  sets_to_play?: 1 | 3 | 5;
+  seed?: number;
+}
+
+export interface MatchSimulationStatus {
+  court_name: string;
+  connection_id: string;
+  seed: number;
+  speed: number;
+  sets_to_play: number;
+  points_played: number;
+  score: string;
+  is_completed: boolean;
+  started_at: string;
+}
+
+export interface MatchElapsedEvent {
+  matchId: string;
+  elapsedMs: number;
+  running: boolean;
+}
+
+export interface SecretsStatus {
+  keychainAvailable: boolean;
+  encryptedAtRest: boolean;
+  plaintextTokens: number;
+  warning: string | null;
+}
+
+export type VisibilityCondition =
+  | { op: 'compare'; field: string; cmp: 'eq' | 'ne' | 'gt' | 'gte' | 'lt' | 'lte'; value: any }
+  | { op: 'exists'; field: string }
+  | { op: 'all'; conditions: VisibilityCondition[] }
+  | { op: 'any'; conditions: VisibilityCondition[] }
+  | { op: 'not'; condition: VisibilityCondition };
+
+export interface ComponentVisibilityRule {
+  componentId: string;
+  // Fields are dotted paths into { game, court }, e.g. "game.period"
+  showWhen: VisibilityCondition;
+  court?: string | null;
+  visible?: boolean | null;
+}
+
+export interface ComponentVisibilityEvent {
+  visibility: Record<string, boolean>;
+  changed: string[];
+}
+
+export interface EventLockStatus {
+  enabled: boolean;
+  enabledAt: string | null;
+  pinRequired: boolean;
+}
+
+export interface ZipBatchImportResult {
+  index: number;
+  scoreboard: TauriScoreboardConfig | null;
+  error: AppError | null;
+}
+
+export type FramePacingMode =
+  | { mode: 'vsync_on' }
+  | { mode: 'vsync_off' }
+  | { mode: 'target_frame_rate'; fps: number };
+
+export interface FramePacingResult {
+  windowId: string;
+  mode: FramePacingMode;
+  supported: boolean;
+  reason: string | null;
+}
+
+export interface TypedMessage {
+  connection_id: string;
+  message_type: string;
+  message: any;
+  received_at: string;
+}
+
+export interface WebSocketParseError {
+  connection_id: string;
+  kind: 'decode' | 'parse';
+  error: string;
+  excerpt: string;
+  hex: string | null;
+  at: string;
+}
+
+export interface WebSocketFrameErrorReport {
+  connection_id: string;
+  messages_received: number;
+  counts: { decode_errors: number; parse_errors: number };
+  capture_hex: boolean;
+  recent: WebSocketParseError[];
+}
+
+export interface WebSocketConnectionStatus {
+  connection_id: string;
+  active: boolean;
+  url: string | null; // What a reconnect will use, credentials redacted
+  court_filter: string | null;
+  connected_at: string | null;
+  reconnect_policy: ReconnectPolicy;
+  reconnect: ReconnectState | null; // null while connected with no failed reconnects
+}
+
+export interface ReconnectPolicy {
+  initial_delay_ms: number;
+  max_delay_ms: number;
+  max_attempts: number;
+}
+
+export interface ReconnectState {
+  attempts: number;
+  max_attempts: number;
+  next_attempt_at: string | null;
+  last_error: string | null;
+  failed: boolean; // The listener gave up; connection_failed was emitted
+}
+
+export interface BufferedCourtData {
+  court_name: string;
+  data: any | null;
+  freshness: 'live' | 'buffered';
+  source_connection: string | null;
+  age_secs: number | null;
+}
+
+export interface CourtConnectionInfo {
+  court_name: string;
+  preferred_connection: string | null;
+  active_connection: string | null;
+}
+
+export interface WindowPlacementWarning {
+  monitorId: number;
+  monitorName: string;
+  windowIds: string[];
+  message: string;
+}
+
+export interface ScoreboardMonitorMapping {
+  scoreboard_filename: string;
+  monitor_match: string;
+}
+
+export interface MonitorMappingReport {
+  created: { scoreboard_filename: string; window_id: string; monitor_id: number; monitor_name: string }[];
+  unmatched: { scoreboard_filename: string; monitor_match: string; reason: string }[];
+}
+
+export type IntegrityFix =
+  | { kind: 'correct_scoreboard_filename'; file: string }
+  | { kind: 'prune_image_metadata'; imageId: string }
+  | { kind: 'prune_video_metadata'; videoId: string }
+  | { kind: 'adopt_image_file'; file: string }
+  | { kind: 'adopt_video_file'; file: string }
+  | { kind: 'strip_broken_references'; file: string }
+  | { kind: 'quarantine_state_file'; path: string };
+
+export interface IntegrityIssue {
+  id: string;
+  severity: 'error' | 'warning';
+  category: string;
+  subject: string;
+  message: string;
+  suggestedFix: string | null;
+  fix: IntegrityFix | null;
+}
+
+export interface IntegrityReport {
+  checkedAt: string;
+  errors: IntegrityIssue[];
+  warnings: IntegrityIssue[];
+}
+
+export interface IntegrityProgressEvent {
+  phase: string;
+  checked: number;
+  total: number;
+}
+
+export interface ZipExportProgressEvent {
+  file: string;
+  addedFiles: number;
+  totalFiles: number;
+}
+
+export interface ZipExportSummary {
+  path: string;
+  files: number;
+  bytes: number;
+}
+
+// 'names' also replaces court and player names with placeholders
+export type RedactLevel = 'secrets' | 'names';
+
+export interface DiagnosticsManifestEntry {
+  name: string;
+  bytes: number;
+  description: string;
+}
+
+// Also the payload of the first_run_seeded event
+export interface SampleContentReport {
+  scoreboards: string[];
+  images: string[];
+  connections: string[];
+  skipped: string[];
+}
+
+export interface DiagnosticsBundleSummary {
+  path: string;
+  bytes: number;
+  redactLevel: RedactLevel;
+  manifest: DiagnosticsManifestEntry[];
+}
+
+export interface IntegrityFixOutcome {
+  fixId: string;
+  error: AppError | null;
+}
+
+export interface ContrastCheck {
+  ratio: number;
+  threshold: number;
+  passes: boolean;
+}
+
+export interface LowContrastComponent {
+  componentId: string;
+  componentType: string;
+  foreground: string;
+  background: string;
+  ratio: number;
+}
+
+export interface ContrastReport {
+  threshold: number;
+  components: LowContrastComponent[];
+}
+
+export interface ImageDataResult {
+  data: string | null;
+  error: AppError | null;
+}
+
+export interface DataDirectoryProgressEvent {
+  file: string;
+  copiedFiles: number;
+  totalFiles: number;
+  copiedBytes: number;
+  totalBytes: number;
+}
+
+export interface DataDirectoryChange {
+  previousPath: string;
+  newPath: string;
+  copiedFiles: number;
+  copiedBytes: number;
+  restartRequired: boolean;
+}
+
+export interface MediaStorageStats {
+  dataDirectory: string;
+  defaultDataDirectory: string;
+  portable: boolean;
+  pendingDataDirectory: string | null;
+  previousDataDirectory: string | null;
+  scoreboardsBytes: number;
+  imagesBytes: number;
+  videosBytes: number;
+  liveDataBytes: number;
+  totalBytes: number;
+  availableBytes: number | null;
+  lowDiskSpaceThresholdBytes: number;
+  lowDiskSpace: boolean;
+}
+
+export interface DiskSpaceStatus {
+  availableBytes: number | null;
+  thresholdBytes: number;
+  low: boolean;
+}
+
+export interface ZipImportSummary {
+  entries: number;
+  totalUncompressedBytes: number;
+  largestEntryBytes: number;
 }
 
 export interface ScoreboardInfo {
@@ -454,6 +991,7 @@ export interface LiveDataBinding {
   connectionId: string;
   dataPath: string;
   updateInterval?: number;
+  priority?: BindingPriority;
 }
 
 export interface LiveDataState {
@@ -7,7 +7,9 @@ import {
   ProcessedTennisMatch,
   ProcessTennisDataResponse,
   ProcessBatchResponse,
-  ValidateTennisDataResponse
+  ValidateTennisDataResponse,
+  TennisValidationWarning,
+  CriticalPoints
 } from '../types/tennisProcessor';
 
 // Re-export ProcessedTennisMatch for backwards compatibility
@@ -96,6 +98,26 @@ export class RustTennisProcessor {
     }
   }
 
+  /**
+   * Game, break, set and match point for the current point, e.g. for a "MATCH POINT" banner
+   */
+  async computeCriticalPoints(tennisMatch: ProcessedTennisMatch, bestOf?: number): Promise<CriticalPoints> {
+    return await invoke<CriticalPoints>('compute_critical_points', {
+      tennisMatch: tennisMatch as any,
+      bestOf
+    });
+  }
+
+  /**
+   * Check tennis data for impossible match states (bad point values,
+   * out-of-range server, set totals that don't add up); empty means valid
+   */
+  async validateDataStrict(rawData: RawTennisData): Promise<TennisValidationWarning[]> {
+    return await invoke<TennisValidationWarning[]>('validate_tennis_data_strict', {
+      rawData: rawData as any
+    });
+  }
+
 
   /**
    * Update processor configuration
@@ -6,6 +6,26 @@ import { subscribeWithSelector } from 'zustand/middleware';
 
 // ==================== TYPES ====================
 
+export interface ScoreboardComponentMeta {
+  id: string;
+  component_type: string;
+  z_index: number;
+  visible: boolean;
+  locked: boolean;
+}
+
+export interface CanvasView {
+  zoom: number;
+  pan: { x: number; y: number };
+  grid: { enabled: boolean; size: number; snap_to_grid: boolean; show_grid: boolean };
+}
+
+export interface UnsavedChanges {
+  hasUnsavedChanges: boolean;
+  scoreboardDirty: boolean;
+  changedStates: string[];
+}
+
 export interface TauriStoreState {
   // Connection state
   isConnected: boolean;
@@ -32,6 +52,15 @@ export interface TauriStoreActions {
   getState: <T>(stateType: string) => Promise<T>;
   updateState: (stateType: string, updates: any) => Promise<void>;
 
+  // Scoped getters, for polling one slice without transferring the whole state
+  getScoreboardComponent: <T>(componentId: string) => Promise<T>;
+  getScoreboardComponentsMeta: () => Promise<ScoreboardComponentMeta[]>;
+  getLiveDataConnection: <T>(connectionId: string) => Promise<T>;
+  getCanvasView: () => Promise<CanvasView>;
+
+  // Also sent as the `unsaved_changes_on_close` event when a close is held back
+  hasUnsavedChanges: () => Promise<UnsavedChanges>;
+
   // Subscription management
   subscribeToStateUpdates: (stateType: string, callback: (data: any) => void) => Promise<string>;
   unsubscribeFromStateUpdates: (subscriptionId: string) => Promise<void>;
@@ -70,8 +99,8 @@ export const useTauriStore = create<TauriStoreState & TauriStoreActions>()(
       try {
         set({ connectionError: null });
 
-        // Test connection by calling a simple command
-        await invoke('get_app_state');
+        // Test connection with the smallest getter rather than a whole state
+        await invoke('get_canvas_view');
         set({ isConnected: true });
 
         // Load initial states
@@ -137,6 +166,13 @@ export const useTauriStore = create<TauriStoreState & TauriStoreActions>()(
           case 'scoreboard':
             result = await invoke('get_scoreboard_state');
             break;
+          // Scoped slices for callers that poll
+          case 'canvasView':
+            result = await invoke('get_canvas_view');
+            break;
+          case 'scoreboardMeta':
+            result = await invoke('get_scoreboard_components_meta');
+            break;
           default:
             throw new Error(`Unknown state type: ${stateType}`);
         }
@@ -150,6 +186,26 @@ export const useTauriStore = create<TauriStoreState & TauriStoreActions>()(
       }
     },
 
+    getScoreboardComponent: async <T>(componentId: string): Promise<T> => {
+      return await invoke('get_scoreboard_component', { componentId });
+    },
+
+    getScoreboardComponentsMeta: async () => {
+      return await invoke('get_scoreboard_components_meta');
+    },
+
+    getLiveDataConnection: async <T>(connectionId: string): Promise<T> => {
+      return await invoke('get_live_data_connection', { connectionId });
+    },
+
+    getCanvasView: async () => {
+      return await invoke('get_canvas_view');
+    },
+
+    hasUnsavedChanges: async () => {
+      return await invoke('has_unsaved_changes');
+    },
+
     updateState: async (stateType: string, updates: any) => {
       const { setLoading, clearLoading } = get();
 
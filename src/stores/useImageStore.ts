@@ -10,7 +10,15 @@ export interface StoredImage {
   size: number;
   type: string;
   uploadedAt: Date;
-  thumbnail?: string; // Base64 thumbnail for quick preview
+  thumbnail?: string; // PNG data URL, at most 256px; absent when the format can't be decoded
+  width?: number | null; // Pixels; null for SVGs and images uploaded before sizes were recorded
+  height?: number | null;
+  palette?: ImagePalette; // Dominant colors as hex strings, most common first
+}
+
+export interface ImagePalette {
+  count: number;
+  colors: string[];
 }
 
 interface ImageState {
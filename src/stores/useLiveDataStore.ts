@@ -460,6 +460,7 @@ export const useLiveDataStore = create<LiveDataStoreState & LiveDataActions>()(
           connectionId: binding.connectionId,
           dataPath: binding.dataPath,
           updateInterval: binding.updateInterval,
+          priority: binding.priority,
         }));
 
         set({
@@ -499,6 +500,7 @@ export const useLiveDataStore = create<LiveDataStoreState & LiveDataActions>()(
             connectionId: binding.connectionId,
             dataPath: binding.dataPath,
             updateInterval: binding.updateInterval,
+            priority: binding.priority,
           })),
         };
 
@@ -548,9 +550,8 @@ export const useLiveDataStore = create<LiveDataStoreState & LiveDataActions>()(
           // Check WebSocket status after a short delay to see if connection succeeded
           setTimeout(async () => {
             try {
-              const status = await import('../lib/tauri').then(m => m.TauriAPI.checkWebSocketStatus());
-              // Parse the status to see if we're connected
-              if (status.includes('WebSocket is CONNECTED and ACTIVE')) {
+              const status = await import('../lib/tauri').then(m => m.TauriAPI.checkWebSocketStatus(finalConnectionId));
+              if (status.active) {
                 // Connection successful - no action needed as state is already set
               } else {
                 // Connection may not be fully established - could update state here if needed
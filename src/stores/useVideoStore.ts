@@ -12,6 +12,23 @@ export interface StoredVideo {
   duration?: number; // in seconds
   uploadedAt: Date;
   thumbnail?: string; // Base64 thumbnail for quick preview
+  playability?: VideoPlayability; // Set when ffprobe could inspect the file
+  thumbnailStrip?: VideoThumbnailStrip; // Cached by getVideoThumbnailStrip
+}
+
+export interface VideoPlayability {
+  playable: boolean;
+  container?: string;
+  videoCodec?: string;
+  audioCodec?: string;
+  reason?: string;
+  checkedAt: string;
+}
+
+export interface VideoThumbnailStrip {
+  timestamps: number[]; // seconds
+  frames: string[]; // Base64 PNGs
+  posterFallback: boolean; // ffmpeg unavailable; frames holds the upload thumbnail
 }
 
 interface VideoState {
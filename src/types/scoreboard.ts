@@ -11,8 +11,12 @@ export interface ScoreboardComponent {
   readonly visible: boolean;
   zIndex: number;
   constraints?: ComponentConstraints;
+  // Theme role; apply_theme_to_scoreboard recolors tagged components
+  role?: ComponentRole;
 }
 
+export type ComponentRole = 'background' | 'accent' | 'text';
+
 export const enum ComponentType {
   BACKGROUND = 'background',
   LOGO = 'logo',
@@ -339,9 +343,13 @@ export interface LiveDataComponentBinding {
   componentId: string;
   connectionId: string;
   dataPath: string;
-  updateInterval?: number;
+  updateInterval?: number; // ms between refreshes; overrides priority
+  priority?: BindingPriority;
 }
 
+// high/normal refresh every resolver tick, low every few seconds
+export type BindingPriority = 'high' | 'normal' | 'low';
+
 // IonCourt WebSocket payload types
 export interface IonCourtMatchMessage {
   type: "MATCH";
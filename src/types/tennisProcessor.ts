@@ -24,6 +24,8 @@ export interface RawPlayerData {
   name?: string;
   country?: string;
   seed?: number;
+  // Doubles partner; marks the match as doubles
+  partner?: RawPlayerData;
 }
 
 export interface RawScoreData {
@@ -57,11 +59,25 @@ export interface ProcessedTennisMatch {
   current_set: number;
   is_tiebreak: boolean;
   match_status: string;
+  match_type: 'singles' | 'doubles';
+  doubles_players: ProcessedDoublesPlayers | null;
   // Legacy properties for compatibility
   servingPlayer: number;
   currentSet: number;
   isTiebreak: boolean;
   matchStatus: string;
+  matchType: 'singles' | 'doubles';
+  doublesPlayers: ProcessedDoublesPlayers | null;
+}
+
+export interface ProcessedTeamPlayers {
+  player1: ProcessedPlayerData;
+  player2: ProcessedPlayerData;
+}
+
+export interface ProcessedDoublesPlayers {
+  team1: ProcessedTeamPlayers;
+  team2: ProcessedTeamPlayers;
 }
 
 export interface ProcessedPlayerData {
@@ -103,10 +119,14 @@ export interface ProcessTennisDataResponse {
   current_set: number;
   is_tiebreak: boolean;
   match_status: string;
+  match_type: 'singles' | 'doubles';
+  doubles_players: ProcessedDoublesPlayers | null;
   servingPlayer: number;
   currentSet: number;
   isTiebreak: boolean;
   matchStatus: string;
+  matchType: 'singles' | 'doubles';
+  doublesPlayers: ProcessedDoublesPlayers | null;
 }
 
 export interface ProcessBatchResponse {
@@ -116,3 +136,18 @@ export interface ProcessBatchResponse {
 export interface ValidateTennisDataResponse {
   isValid: boolean;
 }
+
+// Side (1 or 2) that would win the game/set/match by taking the current point
+export interface CriticalPoints {
+  gamePoint: number | null;
+  breakPoint: number | null;
+  setPoint: number | null;
+  matchPoint: number | null;
+  deuce: boolean;
+}
+
+// One problem found by validate_tennis_data_strict
+export interface TennisValidationWarning {
+  field: string;
+  message: string;
+}